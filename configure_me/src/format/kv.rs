@@ -0,0 +1,349 @@
+//! Flat `key=value` configuration files like `bitcoin.conf` and INI files.
+//!
+//! Each line contains a single `key=value` pair, whitespace around keys and values is ignored,
+//! as are empty lines and lines starting with `#` or `;`. Keys of sections are either written as
+//! `section.key=value` or below a `[section]` header. Repeating a key creates a list of values
+//! for parameters accepting multiple values, the last value is used otherwise.
+//!
+//! ```
+//! #[macro_use]
+//! extern crate serde_derive;
+//! extern crate configure_me;
+//!
+//! #[derive(Deserialize)]
+//! #[serde(crate = "configure_me::serde")]
+//! struct Config {
+//!     port: u16,
+//!     connect: Vec<String>,
+//! }
+//!
+//! fn main() {
+//!     let config: Config = configure_me::format::kv::from_str("port=8333\nconnect=a\nconnect=b").unwrap();
+//!     assert_eq!(config.port, 8333);
+//!     assert_eq!(config.connect, ["a", "b"]);
+//! }
+//! ```
+
+use std::collections::{btree_map, BTreeMap};
+use std::fmt;
+use std::vec;
+use serde::forward_to_deserialize_any;
+use serde::de::{self, DeserializeOwned, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, Unexpected, Visitor};
+
+/// Error returned when a `key=value` file is invalid.
+#[derive(Debug)]
+pub struct Error {
+    line: Option<usize>,
+    message: String,
+}
+
+impl Error {
+    fn syntax(line: usize, message: impl Into<String>) -> Self {
+        Error { line: Some(line), message: message.into() }
+    }
+
+    /// Sets the line of the error unless it's already known.
+    fn at(mut self, line: usize) -> Self {
+        if self.line.is_none() {
+            self.line = Some(line);
+        }
+        self
+    }
+
+    /// Number of the line causing the error starting at 1.
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {}: {}", line, self.message),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error { line: None, message: msg.to_string() }
+    }
+}
+
+/// Deserializes `T` from the contents of a `key=value` file.
+pub fn from_slice<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, Error> {
+    let input = std::str::from_utf8(bytes).map_err(|error| Error { line: None, message: error.to_string() })?;
+    from_str(input)
+}
+
+/// Deserializes `T` from a `key=value` string.
+pub fn from_str<T: DeserializeOwned>(input: &str) -> Result<T, Error> {
+    T::deserialize(Value::Table(0, parse(input)?))
+}
+
+/// A single value together with the number of the line it was found on.
+struct Entry {
+    line: usize,
+    value: String,
+}
+
+enum Value {
+    /// All values of a repeated key, never empty.
+    Entries(Vec<Entry>),
+    /// Keys of the whole file or of a section with the line of the section header.
+    Table(usize, BTreeMap<String, Value>),
+}
+
+fn parse(input: &str) -> Result<BTreeMap<String, Value>, Error> {
+    let mut root = BTreeMap::new();
+    let mut section = None;
+    for (line_number, line) in input.lines().enumerate() {
+        let line_number = line_number + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        if line.starts_with('[') {
+            if !line.ends_with(']') {
+                return Err(Error::syntax(line_number, "unclosed section header"));
+            }
+            let name = line[1..(line.len() - 1)].trim();
+            if name.is_empty() {
+                return Err(Error::syntax(line_number, "empty section name"));
+            }
+            section = Some(name.to_owned());
+            table(&mut root, name, line_number)?;
+            continue;
+        }
+
+        let mut parts = line.splitn(2, '=');
+        let key = parts.next().expect("splitn returns at least one item").trim();
+        let value = parts.next().ok_or_else(|| Error::syntax(line_number, format!("expected `{}=value`", key)))?.trim();
+        let (table, key) = match (key.find('.'), &section) {
+            (Some(dot), None) => (table(&mut root, &key[..dot], line_number)?, &key[(dot + 1)..]),
+            (None, Some(section)) => (table(&mut root, section, line_number)?, key),
+            (None, None) => (&mut root, key),
+            (Some(_), Some(section)) => return Err(Error::syntax(line_number, format!("the key `{}` in the section `{}` contains a dot", key, section))),
+        };
+        if key.is_empty() {
+            return Err(Error::syntax(line_number, "empty key"));
+        }
+        let entry = Entry { line: line_number, value: value.to_owned() };
+        match table.entry(key.to_owned()).or_insert_with(|| Value::Entries(Vec::new())) {
+            Value::Entries(entries) => entries.push(entry),
+            Value::Table(..) => return Err(Error::syntax(line_number, format!("`{}` is a section", key))),
+        }
+    }
+    Ok(root)
+}
+
+/// Returns the table of the section `name`, creating it if needed.
+fn table<'a>(root: &'a mut BTreeMap<String, Value>, name: &str, line: usize) -> Result<&'a mut BTreeMap<String, Value>, Error> {
+    match root.entry(name.to_owned()).or_insert_with(|| Value::Table(line, BTreeMap::new())) {
+        Value::Table(_, table) => Ok(table),
+        Value::Entries(_) => Err(Error::syntax(line, format!("`{}` is a key, not a section", name))),
+    }
+}
+
+impl Value {
+    fn line(&self) -> usize {
+        match self {
+            Value::Entries(entries) => entries.last().map(|entry| entry.line).unwrap_or(0),
+            Value::Table(line, _) => *line,
+        }
+    }
+
+    /// Returns the last value of a key, so that later lines override the earlier ones.
+    fn into_entry<'de, V: Visitor<'de>>(self, visitor: &V) -> Result<Entry, Error> {
+        match self {
+            Value::Entries(mut entries) => Ok(entries.pop().expect("entries are never empty")),
+            Value::Table(line, _) => Err(de::Error::invalid_type(Unexpected::Map, visitor)).map_err(|error: Error| error.at(line)),
+        }
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                let entry = self.into_entry(&visitor)?;
+                match entry.value.parse() {
+                    Ok(value) => visitor.$visit(value),
+                    Err(_) => Err(de::Error::invalid_value(Unexpected::Str(&entry.value), &visitor)),
+                }.map_err(|error: Error| error.at(entry.line))
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Value {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self {
+            Value::Entries(ref entries) if entries.len() > 1 => self.deserialize_seq(visitor),
+            Value::Entries(_) => self.deserialize_string(visitor),
+            Value::Table(line, table) => visitor.visit_map(TableAccess { entries: table.into_iter(), value: None }).map_err(|error| error.at(line)),
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let entry = self.into_entry(&visitor)?;
+        match &*entry.value {
+            "1" | "true" => visitor.visit_bool(true),
+            "0" | "false" => visitor.visit_bool(false),
+            value => Err(de::Error::invalid_value(Unexpected::Str(value), &visitor)),
+        }.map_err(|error: Error| error.at(entry.line))
+    }
+
+    deserialize_parsed! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let entry = self.into_entry(&visitor)?;
+        let line = entry.line;
+        visitor.visit_string(entry.value).map_err(|error: Error| error.at(line))
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self {
+            Value::Entries(entries) => visitor.visit_seq(EntriesAccess(entries.into_iter())),
+            Value::Table(line, _) => Err(de::Error::invalid_type(Unexpected::Map, &visitor)).map_err(|error: Error| error.at(line)),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(self, _name: &'static str, _variants: &'static [&'static str], visitor: V) -> Result<V::Value, Error> {
+        let entry = self.into_entry(&visitor)?;
+        let line = entry.line;
+        visitor.visit_enum(IntoDeserializer::<Error>::into_deserializer(entry.value)).map_err(|error| error.at(line))
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        i128 u128 bytes byte_buf unit unit_struct tuple tuple_struct map struct identifier
+    }
+}
+
+struct TableAccess {
+    entries: btree_map::IntoIter<String, Value>,
+    value: Option<Value>,
+}
+
+impl<'de> MapAccess<'de> for TableAccess {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, Error> {
+        match self.entries.next() {
+            Some((key, value)) => {
+                let line = value.line();
+                self.value = Some(value);
+                seed.deserialize(key.into_deserializer()).map(Some).map_err(|error: Error| error.at(line))
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<S: DeserializeSeed<'de>>(&mut self, seed: S) -> Result<S::Value, Error> {
+        let value = self.value.take().expect("next_value_seed called before next_key_seed");
+        let line = value.line();
+        seed.deserialize(value).map_err(|error| error.at(line))
+    }
+}
+
+struct EntriesAccess(vec::IntoIter<Entry>);
+
+impl<'de> SeqAccess<'de> for EntriesAccess {
+    type Error = Error;
+
+    fn next_element_seed<S: DeserializeSeed<'de>>(&mut self, seed: S) -> Result<Option<S::Value>, Error> {
+        self.0
+            .next()
+            .map(|entry| seed.deserialize(Value::Entries(vec![entry])))
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use super::from_str;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Network {
+        port: u16,
+        ipv6: Option<bool>,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Config {
+        name: Option<String>,
+        #[serde(default)]
+        connect: Vec<String>,
+        network: Option<Network>,
+    }
+
+    #[test]
+    fn parse_values() {
+        let config = from_str::<Config>("# comment\n; comment\n\n name = foo bar \nconnect=a\nconnect=b\nnetwork.port=8333\n").unwrap();
+        assert_eq!(config.name.as_deref(), Some("foo bar"));
+        assert_eq!(config.connect, ["a", "b"]);
+        assert_eq!(config.network, Some(Network { port: 8333, ipv6: None }));
+    }
+
+    #[test]
+    fn parse_sections() {
+        let config = from_str::<Config>("name=foo\n[network]\nport=1\nipv6=1\nport=2\n").unwrap();
+        assert_eq!(config.name.as_deref(), Some("foo"));
+        assert_eq!(config.network, Some(Network { port: 2, ipv6: Some(true) }));
+    }
+
+    #[test]
+    fn parse_map() {
+        let map = from_str::<HashMap<String, String>>("a=1\nb=\n").unwrap();
+        assert_eq!(map["a"], "1");
+        assert_eq!(map["b"], "");
+    }
+
+    #[test]
+    fn errors() {
+        fn error(input: &str) -> String {
+            from_str::<Config>(input).unwrap_err().to_string()
+        }
+
+        assert_eq!(error("name=foo\nconnect"), "line 2: expected `connect=value`");
+        assert_eq!(error("=foo"), "line 1: empty key");
+        assert_eq!(error("[network\n"), "line 1: unclosed section header");
+        assert_eq!(error("network=1\nnetwork.port=1"), "line 2: `network` is a key, not a section");
+        assert_eq!(error("[network]\nport=x"), "line 2: invalid value: string \"x\", expected u16");
+        assert_eq!(error("[network]\nport=1\nipv6=yes"), "line 3: invalid value: string \"yes\", expected a boolean");
+    }
+}
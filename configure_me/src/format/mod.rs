@@ -0,0 +1,134 @@
+//! Formats of configuration files.
+//!
+//! TOML is always available, the other formats are enabled using cargo features of this crate:
+//! `json`, `yaml` and `kv` (flat `key=value` files like `bitcoin.conf` and INI files). The
+//! generated code uses this module if the specification sets `conf_format` in the `general`
+//! section.
+
+#[cfg(feature = "kv")]
+pub mod kv;
+
+use std::fmt;
+use std::path::Path;
+use serde::de::DeserializeOwned;
+
+/// Format of a configuration file.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Format {
+    Toml,
+    #[cfg(feature = "json")]
+    Json,
+    #[cfg(feature = "yaml")]
+    Yaml,
+    #[cfg(feature = "kv")]
+    KeyValue,
+    #[cfg(feature = "kv")]
+    Ini,
+}
+
+impl Format {
+    /// File name extensions of the format without the leading dot.
+    pub fn extensions(self) -> &'static [&'static str] {
+        match self {
+            Format::Toml => &["toml"],
+            #[cfg(feature = "json")]
+            Format::Json => &["json"],
+            #[cfg(feature = "yaml")]
+            Format::Yaml => &["yaml", "yml"],
+            #[cfg(feature = "kv")]
+            Format::KeyValue => &["conf"],
+            #[cfg(feature = "kv")]
+            Format::Ini => &["ini"],
+        }
+    }
+
+    /// Picks the format of the file at `path` among `formats` based on its extension.
+    ///
+    /// The first format is used if the extension doesn't match any of them and TOML is used if
+    /// `formats` is empty.
+    ///
+    /// ```
+    /// use configure_me::format::Format;
+    ///
+    /// assert_eq!(Format::detect("app.toml".as_ref(), &[Format::Toml]), Format::Toml);
+    /// assert_eq!(Format::detect("app.cfg".as_ref(), &[]), Format::Toml);
+    /// ```
+    pub fn detect(path: &Path, formats: &[Format]) -> Format {
+        let extension = path.extension().and_then(|extension| extension.to_str());
+        formats
+            .iter()
+            .find(|format| extension.map(|extension| format.extensions().iter().any(|known| extension.eq_ignore_ascii_case(known))).unwrap_or(false))
+            .or_else(|| formats.first())
+            .cloned()
+            .unwrap_or(Format::Toml)
+    }
+
+    /// Deserializes the contents of a configuration file.
+    pub fn deserialize<T: DeserializeOwned>(self, bytes: &[u8]) -> Result<T, Error> {
+        match self {
+            Format::Toml => ::toml::from_slice(bytes).map_err(Error::Toml),
+            #[cfg(feature = "json")]
+            Format::Json => ::serde_json::from_slice(bytes).map_err(Error::Json),
+            #[cfg(feature = "yaml")]
+            Format::Yaml => ::serde_yaml::from_slice(bytes).map_err(Error::Yaml),
+            #[cfg(feature = "kv")]
+            Format::KeyValue | Format::Ini => kv::from_slice(bytes).map_err(Error::KeyValue),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Format::Toml => f.write_str("TOML"),
+            #[cfg(feature = "json")]
+            Format::Json => f.write_str("JSON"),
+            #[cfg(feature = "yaml")]
+            Format::Yaml => f.write_str("YAML"),
+            #[cfg(feature = "kv")]
+            Format::KeyValue => f.write_str("key=value"),
+            #[cfg(feature = "kv")]
+            Format::Ini => f.write_str("INI"),
+        }
+    }
+}
+
+/// Error returned when a configuration file can't be deserialized.
+#[derive(Debug)]
+pub enum Error {
+    Toml(::toml::de::Error),
+    #[cfg(feature = "json")]
+    Json(::serde_json::Error),
+    #[cfg(feature = "yaml")]
+    Yaml(::serde_yaml::Error),
+    #[cfg(feature = "kv")]
+    KeyValue(kv::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Toml(error) => fmt::Display::fmt(error, f),
+            #[cfg(feature = "json")]
+            Error::Json(error) => fmt::Display::fmt(error, f),
+            #[cfg(feature = "yaml")]
+            Error::Yaml(error) => fmt::Display::fmt(error, f),
+            #[cfg(feature = "kv")]
+            Error::KeyValue(error) => fmt::Display::fmt(error, f),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Toml(error) => Some(error),
+            #[cfg(feature = "json")]
+            Error::Json(error) => Some(error),
+            #[cfg(feature = "yaml")]
+            Error::Yaml(error) => Some(error),
+            #[cfg(feature = "kv")]
+            Error::KeyValue(error) => Some(error),
+        }
+    }
+}
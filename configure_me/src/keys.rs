@@ -0,0 +1,156 @@
+//! Detection of unknown keys in configuration files.
+//!
+//! The generated code deserializes configuration files a second time into `Keys` if the
+//! specification sets `unknown_keys` in the `general` section.
+
+use std::fmt;
+use serde::de::{Deserialize, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use suggest;
+
+/// Keys of a configuration file including the keys of its sections.
+#[derive(Debug, Default)]
+pub struct Keys(Vec<(String, Option<Keys>)>);
+
+/// A key that doesn't belong to any param or switch.
+#[derive(Debug, PartialEq, Eq)]
+pub struct UnknownKey {
+    /// The key prefixed with its section, e.g. `network.bind_adr`.
+    pub key: String,
+    /// The known key closest to `key`.
+    pub suggestion: Option<&'static str>,
+}
+
+impl Keys {
+    /// Returns the keys not listed in `known`.
+    ///
+    /// The keys of sections are written as `section.key` in `known` and in the result.
+    pub fn unknown(&self, known: &[&'static str]) -> Vec<UnknownKey> {
+        let mut unknown = Vec::new();
+        self.collect_unknown("", known, &mut unknown);
+        unknown
+    }
+
+    fn collect_unknown(&self, prefix: &str, known: &[&'static str], unknown: &mut Vec<UnknownKey>) {
+        for (key, nested) in &self.0 {
+            let key = format!("{}{}", prefix, key);
+            if known.contains(&&*key) {
+                continue;
+            }
+            let section = format!("{}.", key);
+            match nested {
+                Some(nested) if known.iter().any(|known| known.starts_with(&section)) => nested.collect_unknown(&section, known, unknown),
+                _ => {
+                    let suggestion = suggest::closest(&key, known);
+                    unknown.push(UnknownKey { key, suggestion });
+                },
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Keys {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.deserialize_any(ValueVisitor)? {
+            Some(keys) => Ok(keys),
+            None => Ok(Keys::default()),
+        }
+    }
+}
+
+/// Collects the keys of maps and ignores other values.
+struct ValueVisitor;
+
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = Option<Keys>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("any value")
+    }
+
+    fn visit_bool<E>(self, _: bool) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_i64<E>(self, _: i64) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_u64<E>(self, _: u64) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_f64<E>(self, _: f64) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_str<E>(self, _: &str) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_bytes<E>(self, _: &[u8]) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(ValueVisitor)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(ValueVisitor)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        while seq.next_element::<IgnoredAny>()?.is_some() {}
+        Ok(None)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut keys = Vec::new();
+        while let Some(key) = map.next_key::<String>()? {
+            let nested = map.next_value_seed(NestedKeys)?;
+            keys.push((key, nested));
+        }
+        Ok(Some(Keys(keys)))
+    }
+}
+
+/// Deserializes a value of a map using `ValueVisitor`.
+struct NestedKeys;
+
+impl<'de> serde::de::DeserializeSeed<'de> for NestedKeys {
+    type Value = Option<Keys>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Keys, UnknownKey};
+
+    #[test]
+    fn unknown_keys() {
+        let keys = ::toml::from_str::<Keys>("port = 1\nbind_adr = \"x\"\npeers = [1, 2]\n[network]\nipv6 = true\nipv4 = true\n[other]\nx = 1\n").unwrap();
+        let unknown = keys.unknown(&["port", "bind_addr", "peers", "network.ipv6"]);
+        assert_eq!(unknown, [
+            UnknownKey { key: "bind_adr".to_owned(), suggestion: Some("bind_addr") },
+            UnknownKey { key: "network.ipv4".to_owned(), suggestion: Some("network.ipv6") },
+            UnknownKey { key: "other".to_owned(), suggestion: None },
+        ]);
+    }
+
+    #[test]
+    fn known_table() {
+        let keys = ::toml::from_str::<Keys>("[limits]\nmax = 1\n").unwrap();
+        assert_eq!(keys.unknown(&["limits"]), []);
+    }
+}
@@ -96,6 +96,19 @@
 pub extern crate serde;
 pub extern crate toml;
 pub extern crate parse_arg;
+/// Used by the generated code to check `pattern` constraints.
+#[cfg(feature = "regex")]
+pub extern crate regex;
+#[cfg(feature = "json")]
+extern crate serde_json;
+#[cfg(feature = "yaml")]
+extern crate serde_yaml;
+
+pub mod format;
+pub mod keys;
+pub mod secret;
+pub mod suggest;
+pub mod types;
 
 #[allow(unused_imports)]
 #[macro_use]
@@ -0,0 +1,79 @@
+//! Support for params marked as `secret` in the specification.
+
+use std::ffi::OsString;
+use std::fmt;
+use std::io;
+use std::ops::{Deref, DerefMut};
+use std::path::Path;
+use serde::{Serialize, Serializer};
+
+/// Value of a secret param.
+///
+/// The value is accessible using `Deref` or `into_inner()` but it's never printed by `Debug` or
+/// serialized so that it doesn't end up in logs or printed configuration by accident.
+///
+/// ```
+/// use configure_me::secret::Secret;
+///
+/// let password = Secret::new("hunter2".to_owned());
+/// assert_eq!(*password, "hunter2");
+/// assert_eq!(format!("{:?}", password), "[redacted]");
+/// ```
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Secret<T>(T);
+
+impl<T> Secret<T> {
+    pub fn new(value: T) -> Self {
+        Secret(value)
+    }
+
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Secret<T> {
+    fn from(value: T) -> Self {
+        Secret(value)
+    }
+}
+
+impl<T> Deref for Secret<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Secret<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> fmt::Debug for Secret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("[redacted]")
+    }
+}
+
+impl<T> Serialize for Secret<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str("[redacted]")
+    }
+}
+
+/// Reads the value of a secret param from a file.
+///
+/// A single trailing newline is removed, so files written by `echo` or text editors work.
+pub fn read_file<P: AsRef<Path>>(path: P) -> io::Result<OsString> {
+    let mut value = std::fs::read_to_string(path)?;
+    if value.ends_with('\n') {
+        value.pop();
+        if value.ends_with('\r') {
+            value.pop();
+        }
+    }
+    Ok(value.into())
+}
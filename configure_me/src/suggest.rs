@@ -0,0 +1,77 @@
+//! Suggestions of known names for mistyped ones.
+//!
+//! Used by the generated code to suggest the intended option for an unknown argument or key in a
+//! configuration file.
+
+/// Returns the number of single-character edits needed to turn `a` into `b`.
+fn distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    let mut current = Vec::with_capacity(previous.len());
+    for (i, a) in a.chars().enumerate() {
+        current.clear();
+        current.push(i + 1);
+        for (j, b) in b.iter().enumerate() {
+            let substitution = previous[j] + if a == *b { 0 } else { 1 };
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+/// Returns the candidate closest to `name` if it's close enough to be a typo.
+///
+/// ```
+/// use configure_me::suggest::closest;
+///
+/// assert_eq!(closest("bind_adr", &["port", "bind_addr"]), Some("bind_addr"));
+/// assert_eq!(closest("color", &["port", "bind_addr"]), None);
+/// ```
+pub fn closest<'a>(name: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    candidates
+        .iter()
+        .map(|candidate| (distance(name, candidate), *candidate))
+        .filter(|(distance, candidate)| *distance <= name.chars().count().max(candidate.chars().count()) / 3)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Returns the known long option closest to the unknown argument `arg`.
+///
+/// The value of arguments like `--prot=42` is ignored.
+///
+/// ```
+/// use configure_me::suggest::closest_argument;
+///
+/// assert_eq!(closest_argument("--prot=42", &["--port", "--verbose"]), Some("--port"));
+/// ```
+pub fn closest_argument<'a>(arg: &str, known: &[&'a str]) -> Option<&'a str> {
+    let name = arg.split('=').next().unwrap_or(arg);
+    closest(name, known)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{closest, distance};
+
+    #[test]
+    fn edit_distance() {
+        assert_eq!(distance("", ""), 0);
+        assert_eq!(distance("port", "port"), 0);
+        assert_eq!(distance("prot", "port"), 2);
+        assert_eq!(distance("bind_adr", "bind_addr"), 1);
+        assert_eq!(distance("verbose", ""), 7);
+        assert_eq!(distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn closest_candidate() {
+        let candidates = ["--port", "--verbose", "--bind-addr"];
+        assert_eq!(closest("--verbos", &candidates), Some("--verbose"));
+        assert_eq!(closest("--bind-adress", &candidates), Some("--bind-addr"));
+        assert_eq!(closest("--quiet", &candidates), None);
+        assert_eq!(closest("x", &["y"]), None);
+        assert_eq!(closest("x", &[]), None);
+    }
+}
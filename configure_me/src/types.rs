@@ -0,0 +1,417 @@
+//! Human-friendly types commonly used in configuration.
+//!
+//! The types implement `ParseArg`, `Deserialize` and `Serialize`, so they can be used as `type` in the
+//! specification directly, e.g. `type = "::configure_me::types::Duration"`.
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+use parse_arg::ParseArgFromStr;
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::{Serialize, Serializer};
+
+/// Splits the string into the leading number and the rest.
+fn split_number(s: &str) -> (&str, &str) {
+    let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    s.split_at(end)
+}
+
+/// Duration parsed from a human-friendly format like `30s`, `5m` or `1h30m`.
+///
+/// The value consists of one or more numbers each followed by a unit: `ms`, `s`, `m`, `h` or
+/// `d`. The values of all parts are added together. Zero can be written without a unit.
+///
+/// ```
+/// use configure_me::types::Duration;
+///
+/// let duration = "1h30m".parse::<Duration>().unwrap();
+/// assert_eq!(*duration, std::time::Duration::from_secs(90 * 60));
+/// assert_eq!(duration.to_string(), "1h30m");
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Duration(std::time::Duration);
+
+impl Duration {
+    pub fn new(duration: std::time::Duration) -> Self {
+        Duration(duration)
+    }
+
+    pub fn into_inner(self) -> std::time::Duration {
+        self.0
+    }
+}
+
+impl From<std::time::Duration> for Duration {
+    fn from(duration: std::time::Duration) -> Self {
+        Duration(duration)
+    }
+}
+
+impl From<Duration> for std::time::Duration {
+    fn from(duration: Duration) -> Self {
+        duration.0
+    }
+}
+
+impl Deref for Duration {
+    type Target = std::time::Duration;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Error returned when parsing `Duration` fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseDurationError {
+    /// The string is empty.
+    Empty,
+    /// A number is missing where it was expected.
+    MissingNumber,
+    /// A number is not followed by a unit.
+    MissingUnit,
+    /// The unit is not known.
+    UnknownUnit(String),
+    /// The duration is too long.
+    Overflow,
+}
+
+impl fmt::Display for ParseDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseDurationError::Empty => write!(f, "the duration is empty"),
+            ParseDurationError::MissingNumber => write!(f, "expected a number"),
+            ParseDurationError::MissingUnit => write!(f, "the unit is missing"),
+            ParseDurationError::UnknownUnit(unit) => write!(f, "unknown unit '{}'", unit),
+            ParseDurationError::Overflow => write!(f, "the duration is too long"),
+        }
+    }
+}
+
+impl std::error::Error for ParseDurationError {}
+
+const DURATION_UNITS: &[(&str, u64)] = &[
+    ("d", 24 * 60 * 60 * 1000),
+    ("h", 60 * 60 * 1000),
+    ("m", 60 * 1000),
+    ("s", 1000),
+    ("ms", 1),
+];
+
+impl FromStr for Duration {
+    type Err = ParseDurationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseDurationError::Empty);
+        }
+        if s.bytes().all(|b| b == b'0') {
+            return Ok(Duration::default());
+        }
+
+        let mut millis = 0u64;
+        let mut rest = s;
+        while !rest.is_empty() {
+            let (number, tail) = split_number(rest);
+            if number.is_empty() {
+                return Err(ParseDurationError::MissingNumber);
+            }
+            let unit_len = tail.find(|c: char| c.is_ascii_digit()).unwrap_or(tail.len());
+            let (unit, tail) = tail.split_at(unit_len);
+            if unit.is_empty() {
+                return Err(ParseDurationError::MissingUnit);
+            }
+            let multiplier = DURATION_UNITS
+                .iter()
+                .find(|(name, _)| *name == unit)
+                .map(|(_, multiplier)| *multiplier)
+                .ok_or_else(|| ParseDurationError::UnknownUnit(unit.to_owned()))?;
+            let number = number.parse::<u64>().map_err(|_| ParseDurationError::Overflow)?;
+            millis = number
+                .checked_mul(multiplier)
+                .and_then(|value| millis.checked_add(value))
+                .ok_or(ParseDurationError::Overflow)?;
+            rest = tail;
+        }
+        Ok(Duration(std::time::Duration::from_millis(millis)))
+    }
+}
+
+/// Writes the duration using the largest units possible, e.g. `1h30m`.
+///
+/// Precision below milliseconds is not displayed.
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut millis = self.0.as_millis();
+        if millis == 0 {
+            return write!(f, "0s");
+        }
+        for (unit, multiplier) in DURATION_UNITS {
+            let multiplier = u128::from(*multiplier);
+            if millis >= multiplier {
+                write!(f, "{}{}", millis / multiplier, unit)?;
+                millis %= multiplier;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ParseArgFromStr for Duration {
+    fn describe_type<W: fmt::Write>(mut writer: W) -> fmt::Result {
+        write!(writer, "a duration such as 30s, 5m or 1h30m (units: ms, s, m, h, d)")
+    }
+}
+
+impl<'de> Deserialize<'de> for Duration {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct DurationVisitor;
+
+        impl<'de> Visitor<'de> for DurationVisitor {
+            type Value = Duration;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                <Duration as ParseArgFromStr>::describe_type(f)
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(DurationVisitor)
+    }
+}
+
+/// Serializes the duration in the same format as `Display`.
+impl Serialize for Duration {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Size in bytes parsed from a human-friendly format like `64MiB` or `1G`.
+///
+/// The value is a number optionally followed by a unit. Units ending with `iB` (`KiB`, `MiB`,
+/// `GiB`, `TiB`) are powers of 1024, units ending with `B` (`kB`, `MB`, `GB`, `TB`) are powers
+/// of 1000 and single letters (`K`, `M`, `G`, `T`) are powers of 1024. A number without a unit
+/// or with the unit `B` is in bytes. Units are case-insensitive.
+///
+/// ```
+/// use configure_me::types::ByteSize;
+///
+/// let size = "64MiB".parse::<ByteSize>().unwrap();
+/// assert_eq!(size.bytes(), 64 * 1024 * 1024);
+/// assert_eq!("1G".parse::<ByteSize>().unwrap().to_string(), "1GiB");
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByteSize(u64);
+
+impl ByteSize {
+    pub fn new(bytes: u64) -> Self {
+        ByteSize(bytes)
+    }
+
+    pub fn bytes(self) -> u64 {
+        self.0
+    }
+}
+
+impl From<u64> for ByteSize {
+    fn from(bytes: u64) -> Self {
+        ByteSize(bytes)
+    }
+}
+
+impl From<ByteSize> for u64 {
+    fn from(size: ByteSize) -> Self {
+        size.0
+    }
+}
+
+/// Error returned when parsing `ByteSize` fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseByteSizeError {
+    /// The string doesn't start with a number.
+    MissingNumber,
+    /// The unit is not known.
+    UnknownUnit(String),
+    /// The size doesn't fit into `u64`.
+    Overflow,
+}
+
+impl fmt::Display for ParseByteSizeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseByteSizeError::MissingNumber => write!(f, "expected a number"),
+            ParseByteSizeError::UnknownUnit(unit) => write!(f, "unknown unit '{}'", unit),
+            ParseByteSizeError::Overflow => write!(f, "the size is too large"),
+        }
+    }
+}
+
+impl std::error::Error for ParseByteSizeError {}
+
+const BINARY_UNITS: &[(&str, u64)] = &[
+    ("TiB", 1 << 40),
+    ("GiB", 1 << 30),
+    ("MiB", 1 << 20),
+    ("KiB", 1 << 10),
+];
+
+const DECIMAL_UNITS: &[(&str, u64)] = &[
+    ("TB", 1_000_000_000_000),
+    ("GB", 1_000_000_000),
+    ("MB", 1_000_000),
+    ("kB", 1_000),
+];
+
+fn byte_size_multiplier(unit: &str) -> Option<u64> {
+    let unit = unit.to_ascii_lowercase();
+    let multiplier = match &*unit {
+        "" | "b" => 1,
+        "k" | "kib" => 1 << 10,
+        "m" | "mib" => 1 << 20,
+        "g" | "gib" => 1 << 30,
+        "t" | "tib" => 1 << 40,
+        "kb" => 1_000,
+        "mb" => 1_000_000,
+        "gb" => 1_000_000_000,
+        "tb" => 1_000_000_000_000,
+        _ => return None,
+    };
+    Some(multiplier)
+}
+
+impl FromStr for ByteSize {
+    type Err = ParseByteSizeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (number, unit) = split_number(s);
+        if number.is_empty() {
+            return Err(ParseByteSizeError::MissingNumber);
+        }
+        let multiplier = byte_size_multiplier(unit).ok_or_else(|| ParseByteSizeError::UnknownUnit(unit.to_owned()))?;
+        number
+            .parse::<u64>()
+            .ok()
+            .and_then(|number| number.checked_mul(multiplier))
+            .map(ByteSize)
+            .ok_or(ParseByteSizeError::Overflow)
+    }
+}
+
+/// Writes the size using the largest unit that represents it exactly, e.g. `64MiB` or `1kB`.
+impl fmt::Display for ByteSize {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0 != 0 {
+            let unit = BINARY_UNITS
+                .iter()
+                .chain(DECIMAL_UNITS)
+                .find(|(_, multiplier)| self.0 / multiplier * multiplier == self.0);
+            if let Some((unit, multiplier)) = unit {
+                return write!(f, "{}{}", self.0 / multiplier, unit);
+            }
+        }
+        write!(f, "{}B", self.0)
+    }
+}
+
+impl ParseArgFromStr for ByteSize {
+    fn describe_type<W: fmt::Write>(mut writer: W) -> fmt::Result {
+        write!(writer, "a size such as 4096, 512KiB, 64MiB or 1G (units: B, K, KiB, kB, M, MiB, MB, G, GiB, GB, T, TiB, TB)")
+    }
+}
+
+impl<'de> Deserialize<'de> for ByteSize {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ByteSizeVisitor;
+
+        impl<'de> Visitor<'de> for ByteSizeVisitor {
+            type Value = ByteSize;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                <ByteSize as ParseArgFromStr>::describe_type(f)
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                value.parse().map_err(E::custom)
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+                Ok(ByteSize(value))
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+                u64::try_from(value).map(ByteSize).map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+            }
+        }
+
+        deserializer.deserialize_any(ByteSizeVisitor)
+    }
+}
+
+/// Serializes the size in the same format as `Display`.
+impl Serialize for ByteSize {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ByteSize, Duration, ParseByteSizeError, ParseDurationError};
+
+    fn duration(s: &str) -> Result<u128, ParseDurationError> {
+        s.parse::<Duration>().map(|duration| duration.as_millis())
+    }
+
+    #[test]
+    fn parse_duration() {
+        assert_eq!(duration("30s"), Ok(30_000));
+        assert_eq!(duration("5m"), Ok(300_000));
+        assert_eq!(duration("1h30m"), Ok(5_400_000));
+        assert_eq!(duration("1d2h3m4s5ms"), Ok(93_784_005));
+        assert_eq!(duration("250ms"), Ok(250));
+        assert_eq!(duration("0"), Ok(0));
+        assert_eq!(duration(""), Err(ParseDurationError::Empty));
+        assert_eq!(duration("30"), Err(ParseDurationError::MissingUnit));
+        assert_eq!(duration("s"), Err(ParseDurationError::MissingNumber));
+        assert_eq!(duration("5y"), Err(ParseDurationError::UnknownUnit("y".to_owned())));
+        assert_eq!(duration("99999999999999999999s"), Err(ParseDurationError::Overflow));
+    }
+
+    #[test]
+    fn display_duration() {
+        assert_eq!("1h30m".parse::<Duration>().unwrap().to_string(), "1h30m");
+        assert_eq!("90m".parse::<Duration>().unwrap().to_string(), "1h30m");
+        assert_eq!("1500ms".parse::<Duration>().unwrap().to_string(), "1s500ms");
+        assert_eq!("0".parse::<Duration>().unwrap().to_string(), "0s");
+    }
+
+    fn size(s: &str) -> Result<u64, ParseByteSizeError> {
+        s.parse::<ByteSize>().map(ByteSize::bytes)
+    }
+
+    #[test]
+    fn parse_byte_size() {
+        assert_eq!(size("4096"), Ok(4096));
+        assert_eq!(size("42B"), Ok(42));
+        assert_eq!(size("64MiB"), Ok(64 << 20));
+        assert_eq!(size("1G"), Ok(1 << 30));
+        assert_eq!(size("1gb"), Ok(1_000_000_000));
+        assert_eq!(size("2kB"), Ok(2_000));
+        assert_eq!(size("MiB"), Err(ParseByteSizeError::MissingNumber));
+        assert_eq!(size("1PiB"), Err(ParseByteSizeError::UnknownUnit("PiB".to_owned())));
+        assert_eq!(size("99999999T"), Err(ParseByteSizeError::Overflow));
+    }
+
+    #[test]
+    fn display_byte_size() {
+        assert_eq!(ByteSize::new(64 << 20).to_string(), "64MiB");
+        assert_eq!(ByteSize::new(1_000).to_string(), "1kB");
+        assert_eq!(ByteSize::new(1_500).to_string(), "1500B");
+        assert_eq!(ByteSize::new(0).to_string(), "0B");
+    }
+}
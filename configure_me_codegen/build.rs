@@ -27,7 +27,7 @@ fn main() {
     let out_dir_expected_outputs = out_dir.join("expected_outputs");
     std::fs::create_dir_all(&out_dir_expected_outputs).unwrap();
 
-    let tests = ["empty", "single_optional_param", "single_mandatory_param", "single_default_param", "single_switch", "multiple_params", "convert_into", "no_arg", "short_switches", "conf_files", "with_custom_merge", "optional_program_name", "required_program_name"];
+    let tests = ["empty", "single_optional_param", "single_mandatory_param", "single_default_param", "single_switch", "multiple_params", "convert_into", "no_arg", "short_switches", "conf_files", "conf_dir", "conf_dir_unfiltered", "with_custom_merge", "optional_program_name", "required_program_name", "subcommands", "positionals", "multiple_values", "choice_values", "sections", "constraints", "value_constraints", "validate_fn", "aliases", "secret", "overrides", "types", "hidden", "negatable", "optional_value", "conf_format", "unknown_keys", "unknown_keys_warn", "print_config", "reload", "reload_subcommands"];
 
     for test in &tests {
         process_template(test, &out_dir_expected_outputs);
@@ -1,5 +1,6 @@
+use std::borrow::Cow;
 use std::fmt::{self, Write};
-use ::config::{Config, Optionality, ProgramName};
+use ::config::{Config, Optionality, ProgramName, UnknownKeys};
 use ::unicode_segmentation::UnicodeSegmentation;
 
 mod visitor {
@@ -49,16 +50,37 @@ empty!(::config::General, RawConfigDecl);
 
 impl VisitWrite<visitor::RawConfigDecl> for ::config::Param {
     fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
-        writeln!(output, "        {}: Option<{}>,", self.name.as_snake_case(), self.ty)
+        if let Some(file_key) = &self.file_key {
+            writeln!(output, "        #[serde(rename = {:?})]", file_key)?;
+        }
+        if self.multiple {
+            writeln!(output, "        {}: Option<Vec<{}>>,", self.field.as_snake_case(), self.ty)
+        } else {
+            writeln!(output, "        {}: Option<{}>,", self.field.as_snake_case(), self.ty)
+        }
     }
 }
 
 impl VisitWrite<visitor::RawConfigDecl> for ::config::Switch {
     fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
+        if let Some(file_key) = &self.file_key {
+            writeln!(output, "        #[serde(rename = {:?})]", file_key)?;
+        }
         if self.is_count() {
-            writeln!(output, "        {}: Option<u32>,", self.name.as_snake_case())
+            writeln!(output, "        {}: Option<u32>,", self.field.as_snake_case())
+        } else {
+            writeln!(output, "        {}: Option<bool>,", self.field.as_snake_case())
+        }
+    }
+}
+
+impl VisitWrite<visitor::RawConfigDecl> for ::config::Positional {
+    fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
+        writeln!(output, "        #[serde(skip)]")?;
+        if self.multiple {
+            writeln!(output, "        {}: Vec<{}>,", self.name.as_snake_case(), self.ty)
         } else {
-            writeln!(output, "        {}: Option<bool>,", self.name.as_snake_case())
+            writeln!(output, "        {}: Option<{}>,", self.name.as_snake_case(), self.ty)
         }
     }
 }
@@ -77,6 +99,12 @@ impl VisitWrite<visitor::ArgParseErrorDecl> for ::config::Param {
 
 empty!(::config::Switch, ArgParseErrorDecl);
 
+impl VisitWrite<visitor::ArgParseErrorDecl> for ::config::Positional {
+    fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
+        writeln!(output, "    Field{}(<{} as ::configure_me::parse_arg::ParseArg>::Error),", self.name.as_pascal_case(), self.ty)
+    }
+}
+
 empty!(::config::General, EnvParseErrorDecl);
 
 impl VisitWrite<visitor::EnvParseErrorDecl> for ::config::Param {
@@ -89,6 +117,8 @@ impl VisitWrite<visitor::EnvParseErrorDecl> for ::config::Param {
     }
 }
 
+empty!(::config::Positional, EnvParseErrorDecl);
+
 impl VisitWrite<visitor::EnvParseErrorDecl> for ::config::Switch {
     fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
         if self.env_var {
@@ -105,9 +135,15 @@ impl VisitWrite<visitor::EnvParseErrorDecl> for ::config::Switch {
 
 impl VisitWrite<visitor::ConfigFinal> for ::config::Param {
     fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
+        let ty = if self.secret {
+            Cow::Owned(format!("::configure_me::secret::Secret<{}>", self.convert_into))
+        } else {
+            Cow::Borrowed(&*self.convert_into)
+        };
         match self.optionality {
-            Optionality::Optional => writeln!(output, "    pub {}: Option<{}>,", self.name.as_snake_case(), self.convert_into),
-            _ => writeln!(output, "    pub {}: {},", self.name.as_snake_case(), self.convert_into),
+            _ if self.multiple => writeln!(output, "    pub {}: Vec<{}>,", self.field.as_snake_case(), ty),
+            Optionality::Optional => writeln!(output, "    pub {}: Option<{}>,", self.field.as_snake_case(), ty),
+            _ => writeln!(output, "    pub {}: {},", self.field.as_snake_case(), ty),
         }
     }
 }
@@ -115,9 +151,19 @@ impl VisitWrite<visitor::ConfigFinal> for ::config::Param {
 impl VisitWrite<visitor::ConfigFinal> for ::config::Switch {
     fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
         if self.is_count() {
-            writeln!(output, "    pub {}: u32,", self.name.as_snake_case())
+            writeln!(output, "    pub {}: u32,", self.field.as_snake_case())
         } else {
-            writeln!(output, "    pub {}: bool,", self.name.as_snake_case())
+            writeln!(output, "    pub {}: bool,", self.field.as_snake_case())
+        }
+    }
+}
+
+impl VisitWrite<visitor::ConfigFinal> for ::config::Positional {
+    fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
+        match (self.multiple, self.optional) {
+            (true, _) => writeln!(output, "    pub {}: Vec<{}>,", self.name.as_snake_case(), self.ty),
+            (false, true) => writeln!(output, "    pub {}: Option<{}>,", self.name.as_snake_case(), self.ty),
+            (false, false) => writeln!(output, "    pub {}: {},", self.name.as_snake_case(), self.ty),
         }
     }
 }
@@ -125,25 +171,56 @@ impl VisitWrite<visitor::ConfigFinal> for ::config::Switch {
 impl VisitWrite<visitor::Validate> for ::config::Param {
     fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
         match self.optionality {
-            Optionality::Optional => writeln!(output, "            let {} = self.{};", self.name.as_snake_case(), self.name.as_snake_case()),
-            Optionality::Mandatory => writeln!(output, "            let {} = self.{}.ok_or(ValidationError::MissingField(\"{}\"))?;", self.name.as_snake_case(), self.name.as_snake_case(), self.name.as_snake_case()),
-            Optionality::DefaultValue(ref val) => writeln!(output, "            let {} = self.{}.unwrap_or_else(|| {{ {} }});", self.name.as_snake_case(), self.name.as_snake_case(), val),
-        }
+            Optionality::Optional if self.multiple => writeln!(output, "            let {} = self.{}.unwrap_or_default();", self.field.as_snake_case(), self.field.as_snake_case()),
+            Optionality::Optional => writeln!(output, "            let {} = self.{};", self.field.as_snake_case(), self.field.as_snake_case()),
+            Optionality::Mandatory => writeln!(output, "            let {} = self.{}.ok_or(ValidationError::MissingField(\"{}\"))?;", self.field.as_snake_case(), self.field.as_snake_case(), self.field_path()),
+            Optionality::DefaultValue(ref val) => writeln!(output, "            let {} = self.{}.unwrap_or_else(|| {{ {} }});", self.field.as_snake_case(), self.field.as_snake_case(), val),
+        }?;
+        gen_value_checks(self, output)
     }
 }
 
 empty!(::config::Switch, Validate);
 
+impl VisitWrite<visitor::Validate> for ::config::Positional {
+    fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
+        match (self.multiple, self.optional) {
+            (true, false) => {
+                writeln!(output, "            if self.{}.is_empty() {{", self.name.as_snake_case())?;
+                writeln!(output, "                return Err(ValidationError::MissingPositional(\"{}\"));", self.name.as_upper_case())?;
+                writeln!(output, "            }}")?;
+                writeln!(output, "            let {} = self.{};", self.name.as_snake_case(), self.name.as_snake_case())
+            },
+            (false, false) => writeln!(output, "            let {} = self.{}.ok_or(ValidationError::MissingPositional(\"{}\"))?;", self.name.as_snake_case(), self.name.as_snake_case(), self.name.as_upper_case()),
+            (_, true) => writeln!(output, "            let {} = self.{};", self.name.as_snake_case(), self.name.as_snake_case()),
+        }
+    }
+}
+
 impl VisitWrite<visitor::ConstructConfig> for ::config::Param {
     fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
-        if self.needs_conversion {
-            if let Optionality::Optional = self.optionality {
-                writeln!(output, "                {}: {}.map(Into::into),", self.name.as_snake_case(), self.name.as_snake_case())
+        if self.secret {
+            let wrap = "::configure_me::secret::Secret::new";
+            let convert = if self.needs_conversion { ".map(Into::into)" } else { "" };
+            if self.multiple {
+                writeln!(output, "                {}: {}.into_iter(){}.map({}).collect(),", self.field.as_snake_case(), self.field.as_snake_case(), convert, wrap)
+            } else if let Optionality::Optional = self.optionality {
+                writeln!(output, "                {}: {}{}.map({}),", self.field.as_snake_case(), self.field.as_snake_case(), convert, wrap)
+            } else if self.needs_conversion {
+                writeln!(output, "                {}: {}({}.into()),", self.field.as_snake_case(), wrap, self.field.as_snake_case())
             } else {
-                writeln!(output, "                {}: {}.into(),", self.name.as_snake_case(), self.name.as_snake_case())
+                writeln!(output, "                {}: {}({}),", self.field.as_snake_case(), wrap, self.field.as_snake_case())
+            }
+        } else if self.needs_conversion {
+            if self.multiple {
+                writeln!(output, "                {}: {}.into_iter().map(Into::into).collect(),", self.field.as_snake_case(), self.field.as_snake_case())
+            } else if let Optionality::Optional = self.optionality {
+                writeln!(output, "                {}: {}.map(Into::into),", self.field.as_snake_case(), self.field.as_snake_case())
+            } else {
+                writeln!(output, "                {}: {}.into(),", self.field.as_snake_case(), self.field.as_snake_case())
             }
         } else {
-                writeln!(output, "                {},", self.name.as_snake_case())
+                writeln!(output, "                {},", self.field.as_snake_case())
         }
     }
 }
@@ -157,31 +234,58 @@ impl VisitWrite<visitor::ConstructConfig> for ::config::Switch {
         } else {
             "false"
         };
-        writeln!(output, "                {}: self.{}.unwrap_or({}),", self.name.as_snake_case(), self.name.as_snake_case(), default_value)
+        writeln!(output, "                {}: self.{}.unwrap_or({}),", self.field.as_snake_case(), self.field.as_snake_case(), default_value)
+    }
+}
+
+impl VisitWrite<visitor::ConstructConfig> for ::config::Positional {
+    fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
+        writeln!(output, "                {},", self.name.as_snake_case())
     }
 }
 
 impl VisitWrite<visitor::MergeIn> for ::config::Param {
     fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
         if let Some(merge_fn) = &self.merge_fn {
-            writeln!(output, "            if let Some({}) = other.{} {{", self.name.as_snake_case(), self.name.as_snake_case())?;
-            writeln!(output, "                if let Some({}_old) = &mut self.{} {{", self.name.as_snake_case(), self.name.as_snake_case())?;
-            writeln!(output, "                    {}({}_old, {});", merge_fn, self.name.as_snake_case(), self.name.as_snake_case())?;
+            writeln!(output, "            if let Some({}) = other.{} {{", self.field.as_snake_case(), self.field.as_snake_case())?;
+            writeln!(output, "                if let Some({}_old) = &mut self.{} {{", self.field.as_snake_case(), self.field.as_snake_case())?;
+            writeln!(output, "                    {}({}_old, {});", merge_fn, self.field.as_snake_case(), self.field.as_snake_case())?;
             writeln!(output, "                }} else {{")?;
-            writeln!(output, "                    self.{} = Some({});", self.name.as_snake_case(), self.name.as_snake_case())?;
+            writeln!(output, "                    self.{} = Some({});", self.field.as_snake_case(), self.field.as_snake_case())?;
             writeln!(output, "                }}")?;
             writeln!(output, "            }}")
         } else {
-            writeln!(output, "            if other.{}.is_some() {{", self.name.as_snake_case())?;
-            writeln!(output, "                self.{} = other.{};", self.name.as_snake_case(), self.name.as_snake_case())?;
+            writeln!(output, "            if other.{}.is_some() {{", self.field.as_snake_case())?;
+            writeln!(output, "                self.{} = other.{};", self.field.as_snake_case(), self.field.as_snake_case())?;
             writeln!(output, "            }}")
-        }
+        }?;
+        gen_merge_in_source(&self.field, output)
     }
 }
 
 impl VisitWrite<visitor::MergeIn> for ::config::Switch {
     fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
-        writeln!(output, "            if other.{}.is_some() {{", self.name.as_snake_case())?;
+        writeln!(output, "            if other.{}.is_some() {{", self.field.as_snake_case())?;
+        writeln!(output, "                self.{} = other.{};", self.field.as_snake_case(), self.field.as_snake_case())?;
+        writeln!(output, "            }}")?;
+        gen_merge_in_source(&self.field, output)
+    }
+}
+
+/// Writes overwriting of the source of the field if the other config contains its value.
+fn gen_merge_in_source<W: fmt::Write>(field: &::config::Ident, mut output: W) -> fmt::Result {
+    writeln!(output, "            if other.{}_source.is_some() {{", field.as_snake_case())?;
+    writeln!(output, "                self.{}_source = other.{}_source;", field.as_snake_case(), field.as_snake_case())?;
+    writeln!(output, "            }}")
+}
+
+impl VisitWrite<visitor::MergeIn> for ::config::Positional {
+    fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
+        if self.multiple {
+            writeln!(output, "            if !other.{}.is_empty() {{", self.name.as_snake_case())?;
+        } else {
+            writeln!(output, "            if other.{}.is_some() {{", self.name.as_snake_case())?;
+        }
         writeln!(output, "                self.{} = other.{};", self.name.as_snake_case(), self.name.as_snake_case())?;
         writeln!(output, "            }}")
     }
@@ -189,39 +293,64 @@ impl VisitWrite<visitor::MergeIn> for ::config::Switch {
 
 impl VisitWrite<visitor::MergeArgs> for ::config::Param {
     fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
-        if self.argument {
-            writeln!(output, "                }} else if let Some(value) = ::configure_me::parse_arg::match_arg(\"--{}\", &arg, &mut iter) {{", self.name.as_hypenated())?;
-            writeln!(output, "                    let {} = value.map_err(|err| err.map_or(ArgParseError::MissingArgument(\"--{}\"), ArgParseError::Field{}))?;", self.name.as_snake_case(), self.name.as_hypenated(), self.name.as_pascal_case())?;
+        if !self.argument {
+            return Ok(());
+        }
+
+        let aliases = self.aliases.iter().map(|alias| (format!("--{}", self.alias_name(alias).as_hypenated()), Some(self.alias_path(alias)), false));
+        let secret_file = if self.secret { Some((format!("--{}-file", self.long_name()), None, true)) } else { None };
+        for (arg, alias, from_file) in Some((format!("--{}", self.long_name()), None, false)).into_iter().chain(secret_file).chain(aliases) {
+            // An optional value can only be attached using `=` so the next argument is never taken
+            let next = if self.optional_value.is_some() && !from_file { "::std::iter::empty::<&str>()" } else { "&mut iter" };
+            writeln!(output, "                }} else if let Some(value) = ::configure_me::parse_arg::match_arg(\"{}\", &arg, {}) {{", arg, next)?;
+            if from_file {
+                writeln!(output, "                    let file_path: std::path::PathBuf = value.map_err(|err| err.map_or(ArgParseError::MissingArgument(\"{}\"), |never| match never {{}}))?;", arg)?;
+                writeln!(output, "                    let {} = ::configure_me::secret::read_file(&file_path).map_err(|err| ArgParseError::ReadSecretFile(err, file_path))?;", self.name.as_snake_case())?;
+                writeln!(output, "                    let {} = <{} as ::configure_me::parse_arg::ParseArg>::parse_owned_arg({}).map_err(ArgParseError::Field{})?;", self.name.as_snake_case(), self.ty, self.name.as_snake_case(), self.name.as_pascal_case())?;
+            } else if let Some(optional_value) = &self.optional_value {
+                writeln!(output, "                    let {} = value.or_else(|err| err.map_or(Ok({}), |err| Err(ArgParseError::Field{}(err))))?;", self.name.as_snake_case(), optional_value, self.name.as_pascal_case())?;
+            } else {
+                writeln!(output, "                    let {} = value.map_err(|err| err.map_or(ArgParseError::MissingArgument(\"{}\"), ArgParseError::Field{}))?;", self.name.as_snake_case(), arg, self.name.as_pascal_case())?;
+            }
             writeln!(output)?;
-            if let Some(merge_fn) = &self.merge_fn {
-                writeln!(output, "                    if let Some({}_old) = &mut self.{} {{", self.name.as_snake_case(), self.name.as_snake_case())?;
+            write_warnings(&mut output, "                    ", "self.warnings", &self.field_path(), alias.as_deref(), &self.deprecated, &format!("super::Source::Argument(\"{}\")", arg))?;
+            if self.multiple {
+                writeln!(output, "                    self.{}.get_or_insert_with(Vec::new).push({});", self.field_path(), self.name.as_snake_case())
+            } else if let Some(merge_fn) = &self.merge_fn {
+                writeln!(output, "                    if let Some({}_old) = &mut self.{} {{", self.name.as_snake_case(), self.field_path())?;
                 writeln!(output, "                        {}({}_old, {});", merge_fn, self.name.as_snake_case(), self.name.as_snake_case())?;
                 writeln!(output, "                    }} else {{")?;
-                writeln!(output, "                        self.{} = Some({});", self.name.as_snake_case(), self.name.as_snake_case())?;
+                writeln!(output, "                        self.{} = Some({});", self.field_path(), self.name.as_snake_case())?;
                 writeln!(output, "                    }}")
             } else {
-                writeln!(output, "                    self.{} = Some({});", self.name.as_snake_case(), self.name.as_snake_case())
-            }
-        } else {
-            Ok(())
+                writeln!(output, "                    self.{} = Some({});", self.field_path(), self.name.as_snake_case())
+            }?;
+            writeln!(output, "                    self.{}_source = Some(super::Source::Argument(\"{}\"));", self.field_path(), arg)?;
         }
+        Ok(())
     }
 }
 
 impl VisitWrite<visitor::MergeArgs> for ::config::Switch {
     fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
-        if self.is_inverted() {
-            writeln!(output, "                }} else if arg == *\"--no-{}\" {{", self.name.as_hypenated())?;
-            writeln!(output, "                    self.{} = Some(false);", self.name.as_snake_case())
-        } else {
-            writeln!(output, "                }} else if arg == *\"--{}\" {{", self.name.as_hypenated())?;
-
-            if self.is_count() {
-                writeln!(output, "                    *(self.{}.get_or_insert(0)) += 1;", self.name.as_snake_case())
-            } else {
-                writeln!(output, "                    self.{} = Some(true);", self.name.as_snake_case())
+        let aliases = self.aliases.iter().map(|alias| (self.alias_name(alias).as_hypenated().to_string(), Some(self.alias_path(alias))));
+        for (name, alias) in Some((self.long_name(), None)).into_iter().chain(aliases) {
+            // Negatable switches accept both forms, whichever comes later wins
+            let enable = if self.negatable || !self.is_inverted() { Some((format!("--{}", name), true)) } else { None };
+            let disable = if self.negatable || self.is_inverted() { Some((format!("--no-{}", name), false)) } else { None };
+            for (arg, value) in enable.into_iter().chain(disable) {
+                writeln!(output, "                }} else if arg == *\"{}\" {{", arg)?;
+                write_warnings(&mut output, "                    ", "self.warnings", &self.field_path(), alias.as_deref(), &self.deprecated, &format!("super::Source::Argument(\"{}\")", arg))?;
+
+                if self.is_count() {
+                    writeln!(output, "                    *(self.{}.get_or_insert(0)) += 1;", self.field_path())?;
+                } else {
+                    writeln!(output, "                    self.{} = Some({});", self.field_path(), value)?;
+                }
+                writeln!(output, "                    self.{}_source = Some(super::Source::Argument(\"{}\"));", self.field_path(), arg)?;
             }
         }
+        Ok(())
     }
 }
 
@@ -243,13 +372,32 @@ impl VisitWrite<visitor::MergeArgs> for ::config::General {
             writeln!(output, "                        Err(err) => return Err(ArgParseError::OpenConfDir(err, dir_path).into()),")?;
             writeln!(output, "                    }};")?;
             writeln!(output)?;
+            writeln!(output, "                    let mut files = Vec::new();")?;
             writeln!(output, "                    for file in dir {{")?;
             writeln!(output, "                        let file = match file {{")?;
             writeln!(output, "                            Ok(file) => file,")?;
             writeln!(output, "                            Err(err) => return Err(ArgParseError::ReadConfDir(err, dir_path).into()),")?;
             writeln!(output, "                        }};")?;
             writeln!(output)?;
-            writeln!(output, "                        let config = Config::load(file.path())?;")?;
+            writeln!(output, "                        let path = file.path();")?;
+            writeln!(output, "                        let name = file.file_name();")?;
+            writeln!(output, "                        let name = name.to_string_lossy();")?;
+            writeln!(output, "                        // Skips hidden files, backups, leftovers of package upgrades and anything else that isn't a file")?;
+            writeln!(output, "                        if name.starts_with('.') || name.ends_with('~') || name.contains(\".dpkg-\") || !path.is_file() {{")?;
+            writeln!(output, "                            continue;")?;
+            writeln!(output, "                        }}")?;
+            if let Some(extension) = &self.conf_dir_extension {
+                writeln!(output, "                        if path.extension() != Some(::std::ffi::OsStr::new({:?})) {{", extension)?;
+                writeln!(output, "                            continue;")?;
+                writeln!(output, "                        }}")?;
+            }
+            writeln!(output, "                        files.push(path);")?;
+            writeln!(output, "                    }}")?;
+            writeln!(output, "                    // Sorted so that later files override earlier ones regardless of the filesystem")?;
+            writeln!(output, "                    files.sort();")?;
+            writeln!(output)?;
+            writeln!(output, "                    for file in files {{")?;
+            writeln!(output, "                        let config = Config::load(file)?;")?;
             writeln!(output, "                        self.merge_in(config);")?;
             writeln!(output, "                    }}")?;
         }
@@ -258,16 +406,36 @@ impl VisitWrite<visitor::MergeArgs> for ::config::General {
             writeln!(output, "                }} else if arg == \"--{}\" {{", skip_conf.as_hypenated())?;
             writeln!(output, "                    *skip_default_conf_files = true;")?;
         }
+
+        if let Some(print_config) = &self.print_config_switch {
+            writeln!(output, "                }} else if arg == \"--{}\" {{", print_config.as_hypenated())?;
+            writeln!(output, "                    self.print_config = true;")?;
+        }
+
+        if let Some(check_config) = &self.check_config_switch {
+            writeln!(output, "                }} else if arg == \"--{}\" {{", check_config.as_hypenated())?;
+            writeln!(output, "                    self.check_config = true;")?;
+        }
         Ok(())
     }
 }
 
 impl VisitWrite<visitor::MergeShort> for ::config::Param {
     fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
-        // TODO remove invalid case (false, Some(_))
+        // Short options of params that aren't arguments are reported by the lint pass
         if let (true, Some(short) )= (self.argument, self.abbr) {
             writeln!(output, "                        }} else if short == '{}' {{", short)?;
-            writeln!(output, "                            self.{} = Some(shorts.parse_remaining(&mut iter).map_err(|err| err.map_or(ArgParseError::MissingArgument(\"-{}\"), ArgParseError::Field{}))?);", &self.name.as_snake_case(), short, self.name.as_pascal_case())?;
+            let value = match &self.optional_value {
+                Some(optional_value) => format!("shorts.parse_remaining(::std::iter::empty::<&str>()).or_else(|err| err.map_or(Ok({}), |err| Err(ArgParseError::Field{}(err))))?", optional_value, self.name.as_pascal_case()),
+                None => format!("shorts.parse_remaining(&mut iter).map_err(|err| err.map_or(ArgParseError::MissingArgument(\"-{}\"), ArgParseError::Field{}))?", short, self.name.as_pascal_case()),
+            };
+            if self.multiple {
+                writeln!(output, "                            self.{}.get_or_insert_with(Vec::new).push({});", self.field_path(), value)?;
+            } else {
+                writeln!(output, "                            self.{} = Some({});", self.field_path(), value)?;
+            }
+            writeln!(output, "                            self.{}_source = Some(super::Source::Argument(\"-{}\"));", self.field_path(), short)?;
+            write_warnings(&mut output, "                            ", "self.warnings", &self.field_path(), None, &self.deprecated, &format!("super::Source::Argument(\"-{}\")", short))?;
             writeln!(output, "                            break;")
         } else {
             Ok(())
@@ -281,18 +449,22 @@ impl VisitWrite<visitor::MergeShort> for ::config::Switch {
 
         if let SwitchKind::Normal { abbr: Some(abbr), count } = &self.kind {
             writeln!(output, "                        }} else if short == '{}' {{", abbr)?;
+            write_warnings(&mut output, "                            ", "self.warnings", &self.field_path(), None, &self.deprecated, &format!("super::Source::Argument(\"-{}\")", abbr))?;
 
             if *count {
-                writeln!(output, "                            *(self.{}.get_or_insert(0)) += 1;", self.name.as_snake_case())
+                writeln!(output, "                            *(self.{}.get_or_insert(0)) += 1;", self.field_path())?;
             } else {
-                writeln!(output, "                            self.{} = Some(true);", self.name.as_snake_case())
+                writeln!(output, "                            self.{} = Some(true);", self.field_path())?;
             }
+            writeln!(output, "                            self.{}_source = Some(super::Source::Argument(\"-{}\"));", self.field_path(), abbr)
         } else {
             Ok(())
         }
     }
 }
 
+empty!(::config::Positional, MergeArgs);
+empty!(::config::Positional, MergeShort);
 empty!(::config::General, MergeShort);
 
 pub(crate) fn param_long_raw(param: &str) -> String {
@@ -305,21 +477,175 @@ pub(crate) fn param_long_raw(param: &str) -> String {
 }
 
 pub(crate) fn param_long(param: &::config::Param) -> String {
-    param_long_raw(&param.name.as_snake_case())
+    format!("--{}", param.long_name())
+}
+
+/// Returns the long option of the param as shown in the help, e.g. `color[=COLOR]` if the value
+/// is optional.
+fn param_help_name(param: &::config::Param) -> String {
+    match &param.optional_value {
+        Some(_) => format!("{}[={}]", param.long_name(), param.name.as_upper_case()),
+        None => param.long_name(),
+    }
 }
 
 pub(crate) fn switch_long(switch: &::config::Switch) -> String {
-    if switch.is_inverted() {
-        let mut res = String::with_capacity(switch.name.as_snake_case().len() + 5);
-                                            // Writing to String never fails
-        write!(res, "--no-{}", switch.name.as_snake_case()).unwrap();
-        res
+    if switch.negatable {
+        format!("--[no-]{}", switch.long_name())
+    } else if switch.is_inverted() {
+        format!("--no-{}", switch.long_name())
     } else {
-        let mut res = String::with_capacity(switch.name.as_snake_case().len() + 2);
-                                            // Writing to String never fails
-        write!(res, "--{}", switch.name.as_snake_case()).unwrap();
-        res
+        format!("--{}", switch.long_name())
+    }
+}
+
+/// Returns the representation of the positional argument used in usage lines.
+pub(crate) fn positional_usage(positional: &::config::Positional) -> String {
+    match (positional.optional, positional.multiple) {
+        (false, false) => format!("{}", positional.name.as_upper_case()),
+        (true, false) => format!("[{}]", positional.name.as_upper_case()),
+        (false, true) => format!("{}...", positional.name.as_upper_case()),
+        (true, true) => format!("[{}...]", positional.name.as_upper_case()),
+    }
+}
+
+/// Returns the syntax of the value if the param has one of the types from
+/// `configure_me::types`.
+pub(crate) fn unit_syntax(param: &::config::Param) -> Option<&'static str> {
+    let ty = param.ty.split_whitespace().collect::<String>();
+    match ty.trim_start_matches("::") {
+        "configure_me::types::Duration" => Some("a duration with units ms, s, m, h or d, e.g. 1h30m"),
+        "configure_me::types::ByteSize" => Some("a size with units B, K/KiB, kB, M/MiB, MB, G/GiB, GB, T/TiB or TB, e.g. 64MiB"),
+        _ => None,
+    }
+}
+
+/// Returns the documentation of the param as shown in `--help`, including the allowed values
+/// and whether the param is secret.
+fn param_help_doc(param: &::config::Param) -> Option<Cow<'_, str>> {
+    let mut notes = Vec::new();
+    if let Some(syntax) = unit_syntax(param) {
+        notes.push(syntax.to_owned());
+    }
+    match &param.values {
+        Some(values) => notes.push(format!("possible values: {}", values.join(", "))),
+        None => {
+            let constraints = param.value_constraints.descriptions();
+            if !constraints.is_empty() {
+                notes.push(format!("must be {}", constraints.join(", ")));
+            }
+        },
+    }
+    if param.secret {
+        notes.push(format!("sensitive, can be read from a file using {}-file", param_long(param)));
+    }
+    if notes.is_empty() {
+        return param.doc.as_ref().map(|doc| Cow::Borrowed(&**doc));
+    }
+
+    let notes = notes.join("; ");
+    match &param.doc {
+        Some(doc) => Some(format!("{} ({})", doc, notes).into()),
+        None => {
+            let mut chars = notes.chars();
+            chars.next().map(|first| format!("{}{}", first.to_uppercase(), chars.as_str()).into())
+        },
+    }
+}
+
+/// Returns `true` if a secret param of the config or its sections can be read from a file
+/// specified by an argument.
+fn has_secret_file_arg(config: &Config) -> bool {
+    all_params(config).any(|param| param.secret && param.argument)
+}
+
+/// Returns `true` if a secret param of the config or its sections can be read from a file
+/// specified by an env var.
+fn has_secret_file_env(config: &Config) -> bool {
+    all_params(config).any(|param| param.secret && param.env_var)
+}
+
+/// Returns `true` if a param or switch of the config or its sections or an unknown key in a
+/// config file can cause a warning.
+fn uses_warnings(config: &Config) -> bool {
+    all_params(config).any(|param| !param.aliases.is_empty() || param.deprecated.is_some())
+        || all_switches(config).any(|switch| !switch.aliases.is_empty() || switch.deprecated.is_some())
+        || config.general.unknown_keys == UnknownKeys::Warn
+}
+
+/// Returns `true` if the `Warning` enum is needed by the config or any of its subcommands.
+fn has_warnings(config: &Config) -> bool {
+    uses_warnings(config) || config.subcommands.iter().any(|subcommand| uses_warnings(&subcommand.config))
+}
+
+/// Writes pushing of the warnings caused by specifying the param or switch called `name`.
+///
+/// `alias` is the old name if it was used instead of `name`, `warnings` and `source` are the
+/// expressions of the vector the warnings are pushed into and of the source of the value.
+fn write_warnings<W: Write>(mut output: W, indent: &str, warnings: &str, name: &str, alias: Option<&str>, deprecated: &Option<String>, source: &str) -> fmt::Result {
+    if let Some(alias) = alias {
+        writeln!(output, "{}{}.push(super::Warning::Alias {{ alias: \"{}\", name: \"{}\", source: {} }});", indent, warnings, alias, name, source)?;
+    }
+    if let Some(message) = deprecated {
+        writeln!(output, "{}{}.push(super::Warning::Deprecated {{ name: \"{}\", source: {}, message: {:?} }});", indent, warnings, name, source, message)?;
+    }
+    Ok(())
+}
+
+/// Writes checks of `min`, `max`, `min_len`, `max_len`, `pattern` and the call of `validate_fn`
+/// after the value was validated.
+fn gen_value_checks<W: Write>(param: &::config::Param, mut output: W) -> fmt::Result {
+    let constraints = &param.value_constraints;
+    if constraints.is_empty() && param.validate_fn.is_none() {
+        return Ok(());
+    }
+
+    let name = param.field.as_snake_case();
+    // Values that may be missing or repeated are checked through a reference
+    let wrapped = param.multiple || matches!(param.optionality, Optionality::Optional);
+    let (indent, value, value_ref) = if wrapped {
+        if param.multiple {
+            writeln!(output, "            for {} in &{} {{", name, name)?;
+        } else {
+            writeln!(output, "            if let Some({}) = &{} {{", name, name)?;
+        }
+        ("    ", format!("*{}", name), name.to_owned())
+    } else {
+        ("", name.to_owned(), format!("&{}", name))
+    };
+    let source = format!("self.{}_source.unwrap_or(super::Source::Default)", name);
+    let mut write_check = |condition: String, error: String| -> fmt::Result {
+        writeln!(output, "{}            if {} {{", indent, condition)?;
+        writeln!(output, "{}                return Err(ValidationError::{});", indent, error)?;
+        writeln!(output, "{}            }}", indent)
+    };
+    let out_of_range = |constraint: String| format!("OutOfRange {{ field: \"{}\", source: {}, constraint: {:?} }}", param.field_path(), source, constraint);
+
+    if let Some(min) = &constraints.min {
+        write_check(format!("{} < {}", value, min.literal(&param.ty)), out_of_range(format!("at least {}", min)))?;
+    }
+    if let Some(max) = &constraints.max {
+        write_check(format!("{} > {}", value, max.literal(&param.ty)), out_of_range(format!("at most {}", max)))?;
+    }
+    if let Some(min_len) = &constraints.min_len {
+        write_check(format!("{}.chars().count() < {}", name, min_len), out_of_range(format!("at least {} characters long", min_len)))?;
     }
+    if let Some(max_len) = &constraints.max_len {
+        write_check(format!("{}.chars().count() > {}", name, max_len), out_of_range(format!("at most {} characters long", max_len)))?;
+    }
+    if let Some(pattern) = &constraints.pattern {
+        let condition = format!("!::configure_me::regex::Regex::new({:?}).expect(\"the pattern was checked by configure_me_codegen\").is_match({})", pattern, value_ref);
+        write_check(condition, format!("PatternMismatch {{ field: \"{}\", source: {}, pattern: {:?} }}", param.field_path(), source, pattern))?;
+    }
+    if let Some(validate_fn) = &param.validate_fn {
+        writeln!(output, "{}            if let Err(error) = {}({}) {{", indent, validate_fn, value_ref)?;
+        writeln!(output, "{}                return Err(ValidationError::Custom {{ field: Some(\"{}\"), source: Some({}), message: error.to_string() }});", indent, param.field_path(), source)?;
+        writeln!(output, "{}            }}", indent)?;
+    }
+    if wrapped {
+        writeln!(output, "            }}")?;
+    }
+    Ok(())
 }
 
 pub(crate) fn param_short(param: &::config::Param) -> Option<String> {
@@ -341,54 +667,241 @@ pub(crate) fn switch_short(switch: &::config::Switch) -> Option<String> {
     }
 }
 
-fn upper_case<W: Write>(mut output: W, string: &str) -> fmt::Result {
-    for ch in string.chars().flat_map(char::to_uppercase) {
-        write!(output, "{}", ch)?;
-    }
+fn write_params_and_switches<T, W: Write>(config: &Config, mut output: W) -> fmt::Result where ::config::Param: VisitWrite<T>, ::config::Switch: VisitWrite<T>, ::config::Positional: VisitWrite<T> {
+    visitor::iter::<T, _, _>(&config.params, &mut output)?;
+    visitor::iter::<T, _, _>(&config.switches, &mut output)?;
+    visitor::iter::<T, _, _>(&config.positionals, &mut output)?;
     Ok(())
 }
 
-fn write_params_and_switches<T, W: Write>(config: &Config, mut output: W) -> fmt::Result where ::config::Param: VisitWrite<T>, ::config::Switch: VisitWrite<T> {
-    visitor::iter::<T, _, _>(&config.params, &mut output)?;
-    visitor::iter::<T, _, _>(&config.switches, &mut output)?;
+/// Iterates over params of the config and all its sections.
+fn all_params(config: &Config) -> impl Iterator<Item=&::config::Param> {
+    config.params.iter().chain(config.sections.iter().flat_map(|section| &section.config.params))
+}
+
+/// Iterates over switches of the config and all its sections.
+fn all_switches(config: &Config) -> impl Iterator<Item=&::config::Switch> {
+    config.switches.iter().chain(config.sections.iter().flat_map(|section| &section.config.switches))
+}
+
+/// Writes items of params and switches declared in sections.
+///
+/// Used for visitors generating code that handles all arguments in one place, such as parsing.
+fn write_section_params_and_switches<T, W: Write>(config: &Config, mut output: W) -> fmt::Result where ::config::Param: VisitWrite<T>, ::config::Switch: VisitWrite<T> {
+    for section in &config.sections {
+        visitor::iter::<T, _, _>(&section.config.params, &mut output)?;
+        visitor::iter::<T, _, _>(&section.config.switches, &mut output)?;
+    }
     Ok(())
 }
 
-fn write_config<T, W: Write>(config: &Config, mut output: W) -> fmt::Result where ::config::Param: VisitWrite<T>, ::config::Switch: VisitWrite<T>, ::config::General: VisitWrite<T> {
+fn write_config<T, W: Write>(config: &Config, mut output: W) -> fmt::Result where ::config::Param: VisitWrite<T>, ::config::Switch: VisitWrite<T>, ::config::Positional: VisitWrite<T>, ::config::General: VisitWrite<T> {
     VisitWrite::<T>::visit_write(&config.general, &mut output)?;
     write_params_and_switches::<T, _>(config, &mut output)
 }
 
 fn gen_raw_config<W: Write>(config: &Config, output: W) -> fmt::Result {
-    write_params_and_switches::<visitor::RawConfigDecl, _>(config, output)
+    gen_raw_fields(config, true, output)
+}
+
+/// Writes the fields of a raw config.
+///
+/// Configs that aren't `deserialized` (those of subcommands) don't need fields of aliases, which
+/// are only read from config files, and can't use serde attributes.
+fn gen_raw_fields<W: Write>(config: &Config, deserialized: bool, mut output: W) -> fmt::Result {
+    write_params_and_switches::<visitor::RawConfigDecl, _>(config, &mut output)?;
+    if deserialized {
+        for param in &config.params {
+            for alias in &param.aliases {
+                if param.multiple {
+                    writeln!(output, "        {}: Option<Vec<{}>>,", alias.as_snake_case(), param.ty)?;
+                } else {
+                    writeln!(output, "        {}: Option<{}>,", alias.as_snake_case(), param.ty)?;
+                }
+            }
+        }
+        for switch in &config.switches {
+            for alias in &switch.aliases {
+                writeln!(output, "        {}: Option<{}>,", alias.as_snake_case(), if switch.is_count() { "u32" } else { "bool" })?;
+            }
+        }
+    }
+    let fields = config.params.iter().map(|param| &param.field).chain(config.switches.iter().map(|switch| &switch.field));
+    for field in fields {
+        if deserialized {
+            writeln!(output, "        #[serde(skip)]")?;
+        }
+        writeln!(output, "        pub {}_source: Option<super::Source>,", field.as_snake_case())?;
+    }
+    for section in &config.sections {
+        writeln!(output, "        #[serde(default)]")?;
+        writeln!(output, "        pub {}: {}Section,", section.name.as_snake_case(), section.name.as_pascal_case())?;
+    }
+    if !config.subcommands.is_empty() {
+        writeln!(output, "        #[serde(skip)]")?;
+        writeln!(output, "        pub subcommand: Option<Subcommand>,")?;
+    }
+    Ok(())
 }
 
 fn gen_arg_parse_error<W: Write>(config: &Config, mut output: W) -> fmt::Result {
     write_params_and_switches::<visitor::ArgParseErrorDecl, _>(config, &mut output)?;
+    write_section_params_and_switches::<visitor::ArgParseErrorDecl, _>(config, &mut output)?;
+    if has_secret_file_arg(config) {
+        writeln!(output, "    ReadSecretFile(std::io::Error, std::path::PathBuf),")?;
+    }
     if config.general.conf_dir_param.is_some() {
         writeln!(output, "    OpenConfDir(std::io::Error, std::path::PathBuf),")?;
         writeln!(output, "    ReadConfDir(std::io::Error, std::path::PathBuf),")?;
     }
+    if !config.subcommands.is_empty() {
+        writeln!(output, "    UnknownSubcommand(String),")?;
+    }
+    for subcommand in &config.subcommands {
+        writeln!(output, "    Subcommand{}({}ArgParseError),", subcommand.name.as_pascal_case(), subcommand.name.as_pascal_case())?;
+    }
     Ok(())
 }
 
-fn gen_env_parse_error<W: Write>(config: &Config, output: W) -> fmt::Result {
-    write_params_and_switches::<visitor::EnvParseErrorDecl, _>(config, output)
+fn gen_env_parse_error<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    write_params_and_switches::<visitor::EnvParseErrorDecl, _>(config, &mut output)?;
+    write_section_params_and_switches::<visitor::EnvParseErrorDecl, _>(config, &mut output)?;
+    if has_secret_file_env(config) {
+        writeln!(output, "    ReadSecretFile(std::io::Error, std::path::PathBuf),")?;
+    }
+    Ok(())
 }
 
-fn gen_display_arg_parse_error<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+/// An argument listed in the help message, the name is the long option without the dashes.
+type HelpArgument<'a> = (String, Option<Cow<'a, str>>, ::config::SwitchKind);
+
+/// Returns the params and switches of `category` listed in the help message in the order they are
+/// displayed.
+fn help_arguments<'a>(config: &'a Config, category: Option<&'a str>) -> impl Iterator<Item=HelpArgument<'a>> {
     use ::config::SwitchKind;
 
-    let sum_arg_len = config
+    let params = config
         .params
         .iter()
-        .filter(|param| param.argument)
-        .map(|param| param.name.as_snake_case().len() * 2 + 6)
-        .sum::<usize>()
-        + config
+        .filter(|param| param.argument && !param.hidden)
+        .filter(move |param| param.category.as_ref().map(AsRef::as_ref) == category)
+        .map(|param| (param_help_name(param), param_help_doc(param), SwitchKind::Normal { abbr: param.abbr, count: false }));
+    let switches = config
         .switches
         .iter()
-        .map(|switch| switch.name.as_snake_case().len() + if switch.is_inverted() { 8 } else { 5 })
+        .filter(|switch| !switch.hidden)
+        .filter(move |switch| switch.category.as_ref().map(AsRef::as_ref) == category)
+        .map(|switch| {
+            let doc = switch.doc.as_ref().map(|doc| Cow::Borrowed(&**doc));
+            match switch.kind {
+                _ if !switch.negatable => (switch.long_name(), doc, switch.kind),
+                SwitchKind::Normal { abbr, .. } => (format!("[no-]{}", switch.long_name()), doc, SwitchKind::Normal { abbr, count: false }),
+                SwitchKind::Inverted => (format!("[no-]{}", switch.long_name()), doc, SwitchKind::Normal { abbr: None, count: false }),
+            }
+        });
+
+    params.chain(switches)
+}
+
+/// Returns the categories of the params and switches shown in the help message in the order of
+/// their first occurrence.
+pub(crate) fn help_categories(config: &Config) -> Vec<&str> {
+    let params = all_params(config)
+        .filter(|param| param.argument && !param.hidden)
+        .map(|param| &param.category);
+    let switches = all_switches(config)
+        .filter(|switch| !switch.hidden)
+        .map(|switch| &switch.category);
+
+    let mut categories = Vec::new();
+    for category in params.chain(switches).flatten() {
+        if !categories.contains(&&**category) {
+            categories.push(&**category);
+        }
+    }
+    categories
+}
+
+/// Writes the arguments of the help message, aligning their docs to `max_arg_len`.
+///
+/// If `list_all` is `true` the arguments are listed even if they have no documentation.
+fn write_help_arguments<'a, W: Write, I: Iterator<Item=HelpArgument<'a>>>(mut output: W, arguments: I, max_arg_len: usize, list_all: bool) -> fmt::Result {
+    use ::config::SwitchKind;
+
+    let doc_start = 8 + 2 + max_arg_len + 4;
+    for (name, doc, switch_kind) in arguments {
+        if let Some(doc) = doc {
+            if doc.len() > 0 || list_all {
+                let name_len = match switch_kind {
+                    SwitchKind::Normal { abbr: Some(abbr), .. } => {
+                        write!(output, "\\n        -{}, --{}", abbr, name)?;
+                        name.len() + 4
+                    },
+                    SwitchKind::Normal { abbr: None, .. } => {
+                        write!(output, "\\n        --{}", name)?;
+                        name.len()
+                    },
+                    SwitchKind::Inverted => {
+                        write!(output, "\\n        --no-{}", name)?;
+                        name.len() + 3
+                    },
+                };
+
+                for _ in 0..(max_arg_len + 4 - name_len) {
+                    output.write_char(' ')?;
+                }
+                let mut pos = doc_start;
+                for word in doc.split_word_bounds() {
+                    let word_len = word.graphemes(true).count();
+                    if word_len + pos > 80 {
+                        write!(output, "\\n          ")?;
+                        for _ in 0..(max_arg_len + 4) {
+                            write!(output, " ")?;
+                        }
+                        pos = doc_start;
+                    }
+
+                    if !(word.trim().len() == 0 && pos ==  doc_start) {
+                        write!(output, "{}", word)?;
+                        pos += word_len;
+                    }
+                }
+            }
+        } else if list_all {
+                match switch_kind {
+                    SwitchKind::Normal { abbr: Some(abbr), .. } => write!(output, "\\n        -{}, --", abbr)?,
+                    SwitchKind::Normal { abbr: None, .. } => write!(output, "\\n        --")?,
+                    SwitchKind::Inverted => write!(output, "no-")?,
+                }
+
+                write!(output, "{}\\n", name)?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes a group of arguments under `heading` unless none of them would be listed.
+fn write_help_group<'a, W: Write, I: Iterator<Item=HelpArgument<'a>>>(mut output: W, heading: &str, arguments: I, max_arg_len: usize, list_all: bool) -> fmt::Result {
+    let mut arguments = arguments
+        .filter(|(_, doc, _)| doc.is_some() || list_all)
+        .peekable();
+    if arguments.peek().is_some() {
+        write!(output, "\\n\\n{}:", heading)?;
+        write_help_arguments(&mut output, arguments, max_arg_len, list_all)?;
+    }
+    Ok(())
+}
+
+fn gen_display_arg_parse_error<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    use ::config::SwitchKind;
+
+    let sum_arg_len = all_params(config)
+        .filter(|param| param.argument && !param.hidden)
+        .map(|param| param.long_name().len() + param.name.as_snake_case().len() + if param.multiple { 9 } else { 6 } + if param.optional_value.is_some() { 2 } else { 0 })
+        .sum::<usize>()
+        + all_switches(config)
+        .filter(|switch| !switch.hidden)
+        .map(|switch| switch.long_name().len() + if switch.negatable { 10 } else if switch.is_inverted() { 8 } else { 5 })
         .sum::<usize>()
         + config
         .general
@@ -401,7 +914,14 @@ fn gen_display_arg_parse_error<W: Write>(config: &Config, mut output: W) -> fmt:
         .conf_dir_param
         .as_ref()
         .map(|param| param.as_snake_case().len() + 6 + 8)
-        .unwrap_or(0);
+        .unwrap_or(0)
+        + config
+        .general
+        .print_config_switch
+        .iter()
+        .chain(&config.general.check_config_switch)
+        .map(|switch| switch.as_snake_case().len() + 5)
+        .sum::<usize>();
 
     write!(output, "        ArgParseError::HelpRequested(program_name) => write!(f, \"Usage: {{}}")?;
     // Standard width of the terminal - "Usage: ".len()
@@ -415,24 +935,36 @@ fn gen_display_arg_parse_error<W: Write>(config: &Config, mut output: W) -> fmt:
         if let Some(skip_default_conf_files_switch) = &config.general.skip_default_conf_files_switch {
             write!(output, " [--{} CONF_DIR]", skip_default_conf_files_switch.as_hypenated())?;
         }
-        for param in config.params.iter().filter(|param| param.argument) {
-            if let Some(abbr) = &param.abbr {
-                write!(output, " [-{} {}|--", abbr, param.name.as_upper_case())?;
+        for switch in config.general.print_config_switch.iter().chain(&config.general.check_config_switch) {
+            write!(output, " [--{}]", switch.as_hypenated())?;
+        }
+        for param in all_params(config).filter(|param| param.argument && !param.hidden) {
+            match (&param.abbr, &param.optional_value) {
+                (Some(abbr), Some(_)) => write!(output, " [-{}[{}]|--", abbr, param.name.as_upper_case())?,
+                (Some(abbr), None) => write!(output, " [-{} {}|--", abbr, param.name.as_upper_case())?,
+                (None, _) => write!(output, " [--")?,
+            }
+            if param.optional_value.is_some() {
+                write!(output, "{}[={}]]", param.long_name(), param.name.as_upper_case())?;
             } else {
-                write!(output, " [--")?;
+                write!(output, "{} {}]", param.long_name(), param.name.as_upper_case())?;
+            }
+            if param.multiple {
+                write!(output, "...")?;
             }
-            write!(output, "{} {}]", param.name.as_hypenated(), param.name.as_upper_case())?;
         }
-        for switch in config.switches.iter() {
+        for switch in all_switches(config).filter(|switch| !switch.hidden) {
             if let SwitchKind::Normal { abbr: Some(abbr), .. } = &switch.kind {
                 write!(output, " [-{}|--", abbr)?;
             } else {
                 write!(output, " [--")?;
             }
-            if switch.is_inverted() {
+            if switch.negatable {
+                write!(output, "[no-]")?;
+            } else if switch.is_inverted() {
                 write!(output, "no-")?;
             }
-            write!(output, "{}", switch.name.as_hypenated())?;
+            write!(output, "{}", switch.long_name())?;
             if switch.is_count() {
                 write!(output, " ...")?;
             }
@@ -441,6 +973,12 @@ fn gen_display_arg_parse_error<W: Write>(config: &Config, mut output: W) -> fmt:
     } else {
         write!(output, " [ARGUMENTS...]")?;
     }
+    for positional in &config.positionals {
+        write!(output, " {}", positional_usage(positional))?;
+    }
+    if !config.subcommands.is_empty() {
+        write!(output, " SUBCOMMAND")?;
+    }
     let conf_files = config
         .general.conf_file_param
         .as_ref()
@@ -448,231 +986,1207 @@ fn gen_display_arg_parse_error<W: Write>(config: &Config, mut output: W) -> fmt:
         .chain(config.general.conf_dir_param.as_ref())
         .map(|arg| arg.as_snake_case().len());
 
-    let max_param_len = config
-        .params
+    let max_len = |config: &Config| {
+        let max_param_len = config
+            .params
+            .iter()
+            .filter(|param| param.argument && !param.hidden)
+            .filter(|param| sum_arg_len > (80 - 7) || param_help_doc(param).is_some())
+            .map(|param| param_help_name(param).len() + if param.abbr.is_some() { 4 } else { 0 })
+            .max()
+            .unwrap_or(0);
+        let max_switch_len = config
+            .switches
+            .iter()
+            .filter(|switch| !switch.hidden)
+            .filter(|switch| sum_arg_len > (80 - 7) || switch.doc.is_some())
+            .map(|switch| {
+                let abbr_len = if let SwitchKind::Normal { abbr: Some(_), .. } = switch.kind { 4 } else { 0 };
+                let negation_len = if switch.negatable { 5 } else if switch.is_inverted() { 3 } else { 0 };
+                switch.long_name().len() + abbr_len + negation_len
+            })
+            .max()
+            .unwrap_or(0);
+        ::std::cmp::max(max_param_len, max_switch_len)
+    };
+    let max_top_level_len = ::std::cmp::max(max_len(config), conf_files.max().unwrap_or(0));
+    // All arguments are aligned the same way, even those in sections
+    let max_arg_len = config
+        .sections
+        .iter()
+        .map(|section| max_len(&section.config))
+        .fold(max_top_level_len, ::std::cmp::max);
+    let list_all = sum_arg_len > (80 - 7);
+    let conf_file = config
+        .general.conf_file_param
+        .as_ref()
+        .map(|arg| (arg.as_hypenated().to_string(), Some("Load configuration from this file.".into()), SwitchKind::Normal { abbr: None, count: false }))
+        .into_iter();
+    let conf_dir = config
+        .general.conf_dir_param
+        .as_ref()
+        .map(|arg| (arg.as_hypenated().to_string(), Some("Load configuration from files in this directory.".into()), SwitchKind::Normal { abbr: None, count: false }))
+        .into_iter();
+    let skip_default_conf_files_switch = config
+        .general.skip_default_conf_files_switch
+        .as_ref()
+        .map(|arg| (arg.as_hypenated().to_string(), Some("Skip loading default configuration files.".into()), SwitchKind::Normal { abbr: None, count: false }))
+        .into_iter();
+    let print_config_switch = config
+        .general.print_config_switch
+        .as_ref()
+        .map(|arg| (arg.as_hypenated().to_string(), Some("Print the configuration and exit.".into()), SwitchKind::Normal { abbr: None, count: false }))
+        .into_iter();
+    let check_config_switch = config
+        .general.check_config_switch
+        .as_ref()
+        .map(|arg| (arg.as_hypenated().to_string(), Some("Check the configuration and exit.".into()), SwitchKind::Normal { abbr: None, count: false }))
+        .into_iter();
+    let arguments = conf_file
+        .chain(conf_dir)
+        .chain(skip_default_conf_files_switch)
+        .chain(print_config_switch)
+        .chain(check_config_switch)
+        .chain(help_arguments(config, None));
+    write_help_group(&mut output, "Arguments", arguments, max_arg_len, list_all)?;
+    for section in &config.sections {
+        let heading = format!("Arguments in section {}", section.name.as_hypenated());
+        write_help_group(&mut output, &heading, help_arguments(&section.config, None), max_arg_len, list_all)?;
+    }
+    for category in help_categories(config) {
+        let section_arguments = config
+            .sections
+            .iter()
+            .flat_map(|section| help_arguments(&section.config, Some(category)));
+        write_help_group(&mut output, category, help_arguments(config, Some(category)).chain(section_arguments), max_arg_len, list_all)?;
+    }
+    let max_positional_len = config
+        .positionals
         .iter()
-        .filter(|param| param.argument)
-        .filter(|param| sum_arg_len > (80 - 7) || param.doc.is_some())
-        .map(|param| param.name.as_snake_case().len() + if param.abbr.is_some() { 4 } else { 0 })
-        .chain(conf_files)
+        .filter(|positional| positional.doc.is_some())
+        .map(|positional| positional.name.as_snake_case().len())
         .max()
         .unwrap_or(0);
-    let max_switch_len = config
-        .switches
+    if max_positional_len > 0 {
+        write!(output, "\\n\\nPositional arguments:")?;
+        for positional in &config.positionals {
+            if let Some(doc) = &positional.doc {
+                write!(output, "\\n        {}", positional.name.as_upper_case())?;
+                for _ in 0..(max_positional_len + 4 - positional.name.as_snake_case().len()) {
+                    output.write_char(' ')?;
+                }
+                write!(output, "{}", doc)?;
+            }
+        }
+    }
+    let max_subcommand_len = config
+        .subcommands
         .iter()
-        .filter(|switch| sum_arg_len > (80 - 7) || switch.doc.is_some())
-        .map(|switch| switch.name.as_snake_case().len() + match switch.kind {
-            SwitchKind::Normal { abbr: Some(_), .. } => 4,
-            SwitchKind::Inverted => 3,
-            _ => 0,
-        })
+        .map(|subcommand| subcommand.name.as_snake_case().len())
         .max()
         .unwrap_or(0);
-    let max_arg_len = ::std::cmp::max(max_param_len, max_switch_len);
-    let doc_start = 8 + 2 + max_arg_len + 4;
-    if max_arg_len > 0 {
-        write!(output, "\\n\\nArguments:")?;
-        let conf_file = config
-            .general.conf_file_param
-            .as_ref()
-            .map(|arg| (arg, Some("Load configuration from this file."), SwitchKind::Normal { abbr: None, count: false }))
-            .into_iter();
-        let conf_dir = config
-            .general.conf_dir_param
-            .as_ref()
-            .map(|arg| (arg, Some("Load configuration from files in this directory."), SwitchKind::Normal { abbr: None, count: false }))
-            .into_iter();
-        let skip_default_conf_files_switch = config
-            .general.skip_default_conf_files_switch
-            .as_ref()
-            .map(|arg| (arg, Some("Skip loading default configuration files."), SwitchKind::Normal { abbr: None, count: false }))
-            .into_iter();
-
-        let params = config
-            .params
-            .iter()
-            .filter(|param| param.argument)
-            .map(|param| (&param.name, param.doc.as_ref().map(AsRef::as_ref), SwitchKind::Normal { abbr: param.abbr, count: false }));
-        let switches = config
-            .switches
-            .iter()
-            .map(|switch| (&switch.name, switch.doc.as_ref().map(AsRef::as_ref), switch.kind));
-
-        for (name, doc, switch_kind) in conf_file.chain(conf_dir).chain(skip_default_conf_files_switch).chain(params).chain(switches) {
-            if let Some(doc) = doc {
-                if doc.len() > 0 || sum_arg_len > (80 - 7) {
-                    let name_len = match switch_kind {
-                        SwitchKind::Normal { abbr: Some(abbr), .. } => {
-                            write!(output, "\\n        -{}, --{}", abbr, name.as_hypenated())?;
-                            name.as_snake_case().len() + 4
-                        },
-                        SwitchKind::Normal { abbr: None, .. } => {
-                            write!(output, "\\n        --{}", name.as_hypenated())?;
-                            name.as_snake_case().len()
-                        },
-                        SwitchKind::Inverted => {
-                            write!(output, "\\n        --no-{}", name.as_hypenated())?;
-                            name.as_snake_case().len() + 3
-                        },
-                    };
-
-                    for _ in 0..(max_arg_len + 4 - name_len) {
-                        output.write_char(' ')?;
-                    }
-                    let mut pos = doc_start;
-                    for word in doc.split_word_bounds() {
-                        let word_len = word.graphemes(true).count();
-                        if word_len + pos > 80 {
-                            write!(output, "\\n          ")?;
-                            for _ in 0..(max_arg_len + 4) {
-                                write!(output, " ")?;
-                            }
-                            pos = doc_start;
-                        }
-
-                        if !(word.trim().len() == 0 && pos ==  doc_start) {
-                            write!(output, "{}", word)?;
-                            pos += word_len;
-                        }
-                    }
+    if max_subcommand_len > 0 {
+        write!(output, "\\n\\nSubcommands:")?;
+        for subcommand in &config.subcommands {
+            write!(output, "\\n        {}", subcommand.name.as_hypenated())?;
+            if let Some(doc) = &subcommand.doc {
+                for _ in 0..(max_subcommand_len + 4 - subcommand.name.as_snake_case().len()) {
+                    output.write_char(' ')?;
                 }
-            } else if sum_arg_len > (80 - 7) {
-                    match switch_kind {
-                        SwitchKind::Normal { abbr: Some(abbr), .. } => write!(output, "\\n        -{}, --", abbr)?,
-                        SwitchKind::Normal { abbr: None, .. } => write!(output, "\\n        --")?,
-                        SwitchKind::Inverted => write!(output, "no-")?,
-                    }
-
-                    write!(output, "{}\\n", name.as_hypenated())?;
+                write!(output, "{}", doc)?;
             }
         }
     }
     writeln!(output, "\", program_name),")?;
-    for param in &config.params {
+    for param in all_params(config) {
         if !param.argument {
             continue;
         }
 
-        writeln!(output, "        ArgParseError::Field{}(err) => {{", param.name.as_pascal_case())?;
-        writeln!(output, "            write!(f, \"Failed to parse argument '--{}': {{}}.\\n\\nHint: the value must be \", err)?;", param.name.as_hypenated())?;
+        // The error may contain the value, so it's not displayed for secret params
+        if param.secret {
+            writeln!(output, "        ArgParseError::Field{}(_) => {{", param.name.as_pascal_case())?;
+            writeln!(output, "            write!(f, \"Failed to parse argument '--{}'.\\n\\nHint: the value must be \")?;", param.long_name())?;
+        } else {
+            writeln!(output, "        ArgParseError::Field{}(err) => {{", param.name.as_pascal_case())?;
+            writeln!(output, "            write!(f, \"Failed to parse argument '--{}': {{}}.\\n\\nHint: the value must be \", err)?;", param.long_name())?;
+        }
         writeln!(output, "            <{} as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;", param.ty)?;
         writeln!(output, "            write!(f, \".\")")?;
         writeln!(output, "        }},")?;
     }
+    for positional in &config.positionals {
+        writeln!(output, "        ArgParseError::Field{}(err) => {{", positional.name.as_pascal_case())?;
+        writeln!(output, "            write!(f, \"Failed to parse positional argument '{}': {{}}.\\n\\nHint: the value must be \", err)?;", positional.name.as_upper_case())?;
+        writeln!(output, "            <{} as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;", positional.ty)?;
+        writeln!(output, "            write!(f, \".\")")?;
+        writeln!(output, "        }},")?;
+    }
+    if has_secret_file_arg(config) {
+        writeln!(output, "        ArgParseError::ReadSecretFile(err, file) => write!(f, \"Failed to read secret file {{}}: {{}}\", file.display(), err),")?;
+    }
     if config.general.conf_dir_param.is_some() {
         writeln!(output, "        ArgParseError::OpenConfDir(err, dir) => write!(f, \"Failed to open configuration directory {{}}: {{}}\", dir.display(), err),")?;
         writeln!(output, "        ArgParseError::ReadConfDir(err, dir) => write!(f, \"Failed to read configuration directory {{}}: {{}}\", dir.display(), err),")?;
     }
+    if !config.subcommands.is_empty() {
+        writeln!(output, "        ArgParseError::UnknownSubcommand(subcommand) => write!(f, \"An unknown subcommand '{{}}' was specified.\", subcommand),")?;
+    }
+    for subcommand in &config.subcommands {
+        writeln!(output, "        ArgParseError::Subcommand{}(err) => ::std::fmt::Display::fmt(err, f),", subcommand.name.as_pascal_case())?;
+    }
     Ok(())
 }
 
 fn gen_display_env_parse_error<W: Write>(config: &Config, mut output: W) -> fmt::Result {
-    for param in &config.params {
+    for param in all_params(config) {
         if !param.env_var {
             continue;
         }
 
-        writeln!(output, "        EnvParseError::Field{}(ref err) => {{", param.name.as_pascal_case())?;
-        write!(output, "            write!(f, \"Failed to parse environment variable '")?;
-        config.general.env_prefix.as_ref().map(|prefix| { upper_case(&mut output, &prefix)?; write!(output, "_") }).unwrap_or(Ok(()))?;
-        writeln!(output, "{}': {{}}.\\n\\nHint: the value must be \", err)?;", param.name.as_upper_case())?;
+        if param.secret {
+            writeln!(output, "        EnvParseError::Field{}(_) => {{", param.name.as_pascal_case())?;
+        } else {
+            writeln!(output, "        EnvParseError::Field{}(ref err) => {{", param.name.as_pascal_case())?;
+        }
+        let var = env_var_names(config, &param.name, &param.env).swap_remove(0);
+        if param.secret {
+            writeln!(output, "            write!(f, \"Failed to parse environment variable '{}'.\\n\\nHint: the value must be \")?;", var)?;
+        } else {
+            writeln!(output, "            write!(f, \"Failed to parse environment variable '{}': {{}}.\\n\\nHint: the value must be \", err)?;", var)?;
+        }
         writeln!(output, "            <{} as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;", param.ty)?;
         writeln!(output, "            write!(f, \".\")")?;
         writeln!(output, "        }},")?;
     }
-    for switch in &config.switches {
+    for switch in all_switches(config) {
         if !switch.env_var {
             continue;
         }
 
         writeln!(output, "        EnvParseError::Field{}(ref err) => {{", switch.name.as_pascal_case())?;
+        let var = env_var_names(config, &switch.name, &switch.env).swap_remove(0);
         if switch.is_count() {
-            write!(output, "            write!(f, \"Invalid value for '")?;
-            config.general.env_prefix.as_ref().map(|prefix| { upper_case(&mut output, &prefix)?; write!(output, "_") }).unwrap_or(Ok(()))?;
-            writeln!(output, "{}': {{}}.\\n\\nHint: the value must be \", err)?;", switch.name.as_upper_case())?;
+            writeln!(output, "            write!(f, \"Invalid value for '{}': {{}}.\\n\\nHint: the value must be \", err)?;", var)?;
             writeln!(output, "            <u32 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;")?;
             writeln!(output, "            write!(f, \".\")")?;
         } else {
-            write!(output, "            write!(f, \"Invalid value '{{:?}}' for '")?;
-            config.general.env_prefix.as_ref().map(|prefix| { upper_case(&mut output, &prefix)?; write!(output, "_") }).unwrap_or(Ok(()))?;
-            writeln!(output, "{}'.\\n\\nHint: the allowed values are 0, false, 1, true.\", err)", switch.name.as_upper_case())?;
+            writeln!(output, "            write!(f, \"Invalid value '{{:?}}' for '{}'.\\n\\nHint: the allowed values are 0, false, 1, true.\", err)", var)?;
         }
         writeln!(output, "        }},")?;
     }
+    if has_secret_file_env(config) {
+        writeln!(output, "        EnvParseError::ReadSecretFile(ref err, ref file) => write!(f, \"Failed to read secret file {{}}: {{}}\", file.display(), err),")?;
+    }
     Ok(())
 }
 
-fn gen_validation_fn<W: Write>(config: &Config, mut output: W) -> fmt::Result {
-    write_params_and_switches::<visitor::Validate, _>(config, &mut output)?;
-    writeln!(output)?;
-    writeln!(output, "            Ok(super::Config {{")?;
-    write_params_and_switches::<visitor::ConstructConfig, _>(config, &mut output)?;
-    writeln!(output, "            }})")?;
-    Ok(())
+/// Returns the expression deserializing `config_content` in the format(s) of config files.
+fn deserialize_config_content(config: &Config) -> String {
+    let formats = &config.general.conf_format;
+    match formats.len() {
+        0 => "::configure_me::toml::from_slice(&config_content)".to_owned(),
+        1 => format!("::configure_me::format::Format::{}.deserialize(&config_content)", formats[0].variant()),
+        _ => {
+            let formats = formats
+                .iter()
+                .map(|format| format!("::configure_me::format::Format::{}", format.variant()))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("::configure_me::format::Format::detect(config_file_name.as_ref(), &[{}]).deserialize(&config_content)", formats)
+        },
+    }
 }
 
-fn underscore_to_hypen<W: Write>(mut output: W, ident: &str) -> fmt::Result {
-    for c in ident.chars() {
-        if c == '_' {
-                write!(output, "-")?;
+/// Returns the keys of params and switches including aliases as written in config files.
+fn known_keys(config: &Config) -> Vec<String> {
+    let params = all_params(config).flat_map(|param| Some(param.file_key_path()).into_iter().chain(param.aliases.iter().map(move |alias| param.alias_path(alias))));
+    let switches = all_switches(config).flat_map(|switch| Some(switch.file_key_path()).into_iter().chain(switch.aliases.iter().map(move |alias| switch.alias_path(alias))));
+    params.chain(switches).collect()
+}
+
+/// Writes the end of `load` parsing the config file and recording it as the source of the values.
+///
+/// Values of aliases are moved to the fields of the current names.
+fn gen_load_config<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    let deserialize = deserialize_config_content(config);
+    let tracked = all_params(config).map(|param| param.field_path()).chain(all_switches(config).map(|switch| switch.field_path())).collect::<Vec<_>>();
+    let unknown_keys = config.general.unknown_keys;
+    if tracked.is_empty() && !uses_warnings(config) && unknown_keys == UnknownKeys::Ignore {
+        return writeln!(output, "            {}.map_err(|error| super::Error::ConfigParsing {{ file: config_file_name.as_ref().into(), error }})", deserialize);
+    }
+
+    writeln!(output, "            let mut config: Self = {}.map_err(|error| super::Error::ConfigParsing {{ file: config_file_name.as_ref().into(), error }})?;", deserialize)?;
+    if unknown_keys != UnknownKeys::Ignore {
+        writeln!(output, "            let keys: ::configure_me::keys::Keys = {}.map_err(|error| super::Error::ConfigParsing {{ file: config_file_name.as_ref().into(), error }})?;", deserialize)?;
+        writeln!(output, "            let unknown_keys = keys.unknown(&{:?});", known_keys(config))?;
+        if unknown_keys == UnknownKeys::Error {
+            writeln!(output, "            if let Some(unknown) = unknown_keys.into_iter().next() {{")?;
+            writeln!(output, "                return Err(super::Error::UnknownKey {{ file: config_file_name.as_ref().into(), key: unknown.key, suggestion: unknown.suggestion }});")?;
         } else {
-                write!(output, "{}", c)?;
+            writeln!(output, "            for unknown in unknown_keys {{")?;
+            writeln!(output, "                config.warnings.push(super::Warning::UnknownKey {{ file: config_file_name.as_ref().into(), key: unknown.key, suggestion: unknown.suggestion }});")?;
         }
+        writeln!(output, "            }}")?;
+    }
+    let source = "super::Source::ConfigFile(config_file_name.as_ref().into())";
+    for param in all_params(config) {
+        for alias in &param.aliases {
+            gen_load_alias(&param.alias_path(alias), &param.field_path(), &mut output)?;
+        }
+    }
+    for switch in all_switches(config) {
+        for alias in &switch.aliases {
+            gen_load_alias(&switch.alias_path(alias), &switch.field_path(), &mut output)?;
+        }
+    }
+    let deprecated = all_params(config)
+        .map(|param| (param.field_path(), &param.deprecated))
+        .chain(all_switches(config).map(|switch| (switch.field_path(), &switch.deprecated)))
+        .filter(|(_, deprecated)| deprecated.is_some());
+    for (path, deprecated) in deprecated {
+        writeln!(output, "            if config.{}.is_some() {{", path)?;
+        write_warnings(&mut output, "                ", "config.warnings", &path, None, deprecated, source)?;
+        writeln!(output, "            }}")?;
+    }
+    for path in tracked {
+        writeln!(output, "            if config.{}.is_some() {{", path)?;
+        writeln!(output, "                config.{}_source = Some({});", path, source)?;
+        writeln!(output, "            }}")?;
+    }
+    writeln!(output, "            Ok(config)")
+}
+
+/// Writes moving of the value of an alias read from a config file to the field at `path`.
+///
+/// The value of the current name takes precedence if both are specified.
+fn gen_load_alias<W: Write>(alias_path: &str, path: &str, mut output: W) -> fmt::Result {
+    writeln!(output, "            if let Some(value) = config.{}.take() {{", alias_path)?;
+    write_warnings(&mut output, "                ", "config.warnings", path, Some(alias_path), &None, "super::Source::ConfigFile(config_file_name.as_ref().into())")?;
+    writeln!(output, "                if config.{}.is_none() {{", path)?;
+    writeln!(output, "                    config.{} = Some(value);", path)?;
+    writeln!(output, "                }}")?;
+    writeln!(output, "            }}")
+}
+
+/// Returns the name of the field of `Sources` holding the source of the field at `path`.
+fn sources_field(path: &str) -> String {
+    path.replace('.', "_")
+}
+
+/// Returns `true` if `Config::reload` reports changes of some params as errors.
+fn has_not_reloadable(config: &Config) -> bool {
+    let subcommand_params = config.subcommands.iter().flat_map(|subcommand| all_params(&subcommand.config));
+    // Switching to another subcommand can't be reloaded either
+    config.general.reload && (config.subcommands.len() > 1 || all_params(config).chain(subcommand_params).any(|param| !param.reloadable))
+}
+
+/// Writes the comparisons of the fields of `old` and `new` config, `prefix` is prepended to the
+/// reported names.
+fn gen_reload_fields<W: Write>(config: &Config, old: &str, new: &str, prefix: &str, indent: &str, mut output: W) -> fmt::Result {
+    let params = all_params(config).map(|param| (param.field_path(), param.reloadable));
+    let switches = all_switches(config).map(|switch| (switch.field_path(), true));
+    for (path, reloadable) in params.chain(switches) {
+        writeln!(output, "{}if {}.{} != {}.{} {{", indent, old, path, new, path)?;
+        if reloadable {
+            writeln!(output, "{}    changed.push(\"{}{}\");", indent, prefix, path)?;
+        } else {
+            writeln!(output, "{}    return Err(Error::NotReloadable(\"{}{}\"));", indent, prefix, path)?;
+        }
+        writeln!(output, "{}}}", indent)?;
+    }
+    Ok(())
+}
+
+/// Writes `Config::reload` and `Config::reload_custom_args` if `reload` is enabled.
+fn gen_reload<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    if !config.general.reload {
+        return Ok(());
+    }
+
+    writeln!(output)?;
+    writeln!(output, "    /// Loads the configuration again using the arguments of the program and returns the names of changed fields.")?;
+    writeln!(output, "    pub fn reload<I>(&self, config_files: I) -> Result<(Self, Vec<&'static str>, Metadata), Error> where I: IntoIterator, I::Item: AsRef<::std::path::Path> {{")?;
+    writeln!(output, "        self.reload_custom_args(::std::env::args_os(), config_files)")?;
+    writeln!(output, "    }}")?;
+    writeln!(output)?;
+    writeln!(output, "    /// Loads the configuration again and returns the names of changed fields.")?;
+    writeln!(output, "    ///")?;
+    writeln!(output, "    /// `args` should be the same as those used to load `self`, so that only config files and env vars can change.")?;
+    writeln!(output, "    pub fn reload_custom_args<A, I>(&self, args: A, config_files: I) -> Result<(Self, Vec<&'static str>, Metadata), Error> where")?;
+    writeln!(output, "        A: IntoIterator, A::Item: Into<::std::ffi::OsString>,")?;
+    writeln!(output, "        I: IntoIterator, I::Item: AsRef<::std::path::Path> {{")?;
+    writeln!(output)?;
+    writeln!(output, "        let (config, _, metadata) = Self::custom_args_and_optional_files(args, config_files)?;")?;
+    writeln!(output, "        let mut changed = Vec::new();")?;
+    gen_reload_fields(config, "self", "config", "", "        ", &mut output)?;
+    if !config.subcommands.is_empty() {
+        writeln!(output, "        match (&self.subcommand, &config.subcommand) {{")?;
+        for subcommand in &config.subcommands {
+            let pascal = subcommand.name.as_pascal_case();
+            if subcommand.config.params.is_empty() && subcommand.config.switches.is_empty() {
+                writeln!(output, "            (Subcommand::{}(_), Subcommand::{}(_)) => (),", pascal, pascal)?;
+                continue;
+            }
+            writeln!(output, "            (Subcommand::{}(old), Subcommand::{}(new)) => {{", pascal, pascal)?;
+            let prefix = format!("{}.", subcommand.name.as_snake_case());
+            gen_reload_fields(&subcommand.config, "old", "new", &prefix, "                ", &mut output)?;
+            writeln!(output, "            }},")?;
+        }
+        if config.subcommands.len() > 1 {
+            writeln!(output, "            _ => return Err(Error::NotReloadable(\"subcommand\")),")?;
+        }
+        writeln!(output, "        }}")?;
+    }
+    writeln!(output, "        Ok((config, changed, metadata))")?;
+    writeln!(output, "    }}")
+}
+
+/// Writes the `Source` enum and the `Sources` struct recording the source of each value.
+fn gen_source<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    writeln!(output, "/// Where the value of a parameter came from.")?;
+    writeln!(output, "#[derive(Debug, Clone)]")?;
+    writeln!(output, "pub enum Source {{")?;
+    writeln!(output, "    Default,")?;
+    writeln!(output, "    ConfigFile(::std::path::PathBuf),")?;
+    writeln!(output, "    EnvVar(&'static str),")?;
+    writeln!(output, "    Argument(&'static str),")?;
+    writeln!(output, "}}")?;
+    writeln!(output)?;
+    writeln!(output, "#[automatically_derived]")?;
+    writeln!(output, "impl ::std::fmt::Display for Source {{")?;
+    writeln!(output, "    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {{")?;
+    writeln!(output, "        match self {{")?;
+    writeln!(output, "            Source::Default => write!(f, \"the default value\"),")?;
+    writeln!(output, "            Source::ConfigFile(file) => write!(f, \"config file {{}}\", file.display()),")?;
+    writeln!(output, "            Source::EnvVar(var) => write!(f, \"env var {{}}\", var),")?;
+    writeln!(output, "            Source::Argument(arg) => write!(f, \"argument {{}}\", arg),")?;
+    writeln!(output, "        }}")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")?;
+    writeln!(output)?;
+    writeln!(output, "/// Where the value of each parameter and switch came from.")?;
+    writeln!(output, "///")?;
+    writeln!(output, "/// The fields of sections are prefixed with the name of the section.")?;
+    writeln!(output, "/// Optional parameters that weren't set have no source.")?;
+    writeln!(output, "#[derive(Debug)]")?;
+    writeln!(output, "pub struct Sources {{")?;
+    gen_sources_fields(config, &mut output)?;
+    if !config.subcommands.is_empty() {
+        writeln!(output, "    pub subcommand: SubcommandSources,")?;
+    }
+    writeln!(output, "}}")?;
+    writeln!(output)?;
+    if config.subcommands.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(output, "/// Where the values of the selected subcommand came from.")?;
+    writeln!(output, "#[derive(Debug)]")?;
+    writeln!(output, "pub enum SubcommandSources {{")?;
+    for subcommand in &config.subcommands {
+        writeln!(output, "    {}({}Sources),", subcommand.name.as_pascal_case(), subcommand.name.as_pascal_case())?;
+    }
+    writeln!(output, "}}")?;
+    writeln!(output)?;
+    for subcommand in &config.subcommands {
+        writeln!(output, "/// Where the value of each parameter and switch of the `{}` subcommand came from.", subcommand.name.as_hypenated())?;
+        writeln!(output, "#[derive(Debug)]")?;
+        writeln!(output, "pub struct {}Sources {{", subcommand.name.as_pascal_case())?;
+        gen_sources_fields(&subcommand.config, &mut output)?;
+        writeln!(output, "}}")?;
+        writeln!(output)?;
+    }
+    Ok(())
+}
+
+/// Returns `true` if the param has no value unless some layer sets it.
+fn is_unset_by_default(param: &::config::Param) -> bool {
+    !param.multiple && matches!(param.optionality, Optionality::Optional)
+}
+
+fn gen_sources_fields<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    for param in all_params(config) {
+        if is_unset_by_default(param) {
+            writeln!(output, "    pub {}: Option<Source>,", sources_field(&param.field_path()))?;
+        } else {
+            writeln!(output, "    pub {}: Source,", sources_field(&param.field_path()))?;
+        }
+    }
+    for switch in all_switches(config) {
+        writeln!(output, "    pub {}: Source,", sources_field(&switch.field_path()))?;
+    }
+    Ok(())
+}
+
+/// Writes the fields of a `Sources` struct literal filled from the raw `config`.
+fn gen_sources_values<W: Write>(config: &Config, indent: &str, mut output: W) -> fmt::Result {
+    for param in all_params(config) {
+        let path = param.field_path();
+        if is_unset_by_default(param) {
+            writeln!(output, "{}{}: config.{}_source.clone(),", indent, sources_field(&path), path)?;
+        } else {
+            writeln!(output, "{}{}: config.{}_source.clone().unwrap_or(Source::Default),", indent, sources_field(&path), path)?;
+        }
+    }
+    for switch in all_switches(config) {
+        let path = switch.field_path();
+        writeln!(output, "{}{}: config.{}_source.clone().unwrap_or(Source::Default),", indent, sources_field(&path), path)?;
+    }
+    Ok(())
+}
+
+/// Writes the `Warning` enum if any param or switch has aliases or is deprecated.
+fn gen_warning<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    if !has_warnings(config) {
+        return Ok(());
+    }
+
+    writeln!(output, "/// A problem with the configuration that doesn't prevent the program from running.")?;
+    writeln!(output, "#[derive(Debug)]")?;
+    writeln!(output, "pub enum Warning {{")?;
+    writeln!(output, "    /// A param or switch was specified using its old name.")?;
+    writeln!(output, "    Alias {{ alias: &'static str, name: &'static str, source: Source }},")?;
+    writeln!(output, "    /// A deprecated param or switch was specified.")?;
+    writeln!(output, "    Deprecated {{ name: &'static str, source: Source, message: &'static str }},")?;
+    if config.general.unknown_keys == UnknownKeys::Warn {
+        writeln!(output, "    /// A config file contains a key that doesn't belong to any param or switch.")?;
+        writeln!(output, "    UnknownKey {{ file: ::std::path::PathBuf, key: String, suggestion: Option<&'static str> }},")?;
+    }
+    writeln!(output, "}}")?;
+    writeln!(output)?;
+    writeln!(output, "#[automatically_derived]")?;
+    writeln!(output, "impl ::std::fmt::Display for Warning {{")?;
+    writeln!(output, "    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {{")?;
+    writeln!(output, "        match self {{")?;
+    writeln!(output, "            Warning::Alias {{ alias, name, source }} => write!(f, \"Configuration parameter '{{}}' from {{}} was renamed to '{{}}'.\", alias, source, name),")?;
+    writeln!(output, "            Warning::Deprecated {{ name, source, message }} => write!(f, \"Configuration parameter '{{}}' from {{}} is deprecated: {{}}\", name, source, message),")?;
+    if config.general.unknown_keys == UnknownKeys::Warn {
+        writeln!(output, "            Warning::UnknownKey {{ file, key, suggestion: Some(suggestion) }} => write!(f, \"Unknown key '{{}}' in configuration file {{}}, did you mean '{{}}'?\", key, file.display(), suggestion),")?;
+        writeln!(output, "            Warning::UnknownKey {{ file, key, suggestion: None }} => write!(f, \"Unknown key '{{}}' in configuration file {{}}.\", key, file.display()),")?;
+    }
+    writeln!(output, "        }}")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")?;
+    writeln!(output)
+}
+
+fn gen_validation_fn<W: Write>(config: &Config, output: W) -> fmt::Result {
+    gen_validation_fn_constructing(config, "Config", output)
+}
+
+/// Returns the path of the param or switch used in error messages and the condition checking
+/// whether it was specified (or not if `specified` is `false`).
+fn specified_condition(config: &Config, field: &::config::Ident, specified: bool) -> (String, String) {
+    if let Some(param) = config.params.iter().find(|param| param.field == *field) {
+        let condition = if specified {
+            format!("self.{}.is_some()", field.as_snake_case())
+        } else {
+            format!("self.{}.is_none()", field.as_snake_case())
+        };
+        return (param.field_path(), condition);
+    }
+
+    let switch = config.switches.iter().find(|switch| switch.field == *field).expect("constraints reference only existing params and switches");
+    let condition = match (switch.is_count(), specified) {
+        (true, true) => format!("self.{}.unwrap_or(0) > 0", field.as_snake_case()),
+        (true, false) => format!("self.{}.unwrap_or(0) == 0", field.as_snake_case()),
+        (false, true) => format!("self.{} == Some({})", field.as_snake_case(), !switch.is_inverted()),
+        (false, false) => format!("self.{} != Some({})", field.as_snake_case(), !switch.is_inverted()),
+    };
+    (switch.field_path(), condition)
+}
+
+/// Writes the runtime checks of `requires`, `conflicts_with` and `required_unless`.
+fn gen_constraints<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    let params = config.params.iter().map(|param| (&param.field, &param.constraints));
+    let switches = config.switches.iter().map(|switch| (&switch.field, &switch.constraints));
+
+    for (field, constraints) in params.chain(switches) {
+        let (path, specified) = specified_condition(config, field, true);
+        for required in &constraints.requires {
+            let (required_path, missing) = specified_condition(config, required, false);
+            writeln!(output, "            if {} && {} {{", specified, missing)?;
+            writeln!(output, "                return Err(ValidationError::Requires {{ field: \"{}\", required: \"{}\" }});", path, required_path)?;
+            writeln!(output, "            }}")?;
+        }
+        for conflicting in &constraints.conflicts_with {
+            let (conflicting_path, conflicting_specified) = specified_condition(config, conflicting, true);
+            writeln!(output, "            if {} && {} {{", specified, conflicting_specified)?;
+            writeln!(output, "                return Err(ValidationError::ConflictsWith {{ field: \"{}\", conflicting: \"{}\" }});", path, conflicting_path)?;
+            writeln!(output, "            }}")?;
+        }
+        if !constraints.required_unless.is_empty() {
+            let (_, missing) = specified_condition(config, field, false);
+            let alternatives = constraints
+                .required_unless
+                .iter()
+                .map(|alternative| specified_condition(config, alternative, false))
+                .collect::<Vec<_>>();
+            write!(output, "            if {}", missing)?;
+            for (_, alternative_missing) in &alternatives {
+                write!(output, " && {}", alternative_missing)?;
+            }
+            writeln!(output, " {{")?;
+            write!(output, "                return Err(ValidationError::RequiredUnless {{ field: \"{}\", alternatives: &[", path)?;
+            for (i, (alternative_path, _)) in alternatives.iter().enumerate() {
+                if i > 0 {
+                    write!(output, ", ")?;
+                }
+                write!(output, "\"{}\"", alternative_path)?;
+            }
+            writeln!(output, "] }});")?;
+            writeln!(output, "            }}")?;
+        }
+    }
+    Ok(())
+}
+
+fn gen_validation_fn_constructing<W: Write>(config: &Config, config_type: &str, mut output: W) -> fmt::Result {
+    gen_constraints(config, &mut output)?;
+    write_params_and_switches::<visitor::Validate, _>(config, &mut output)?;
+    for section in &config.sections {
+        writeln!(output, "            let {} = self.{}.validate()?;", section.name.as_snake_case(), section.name.as_snake_case())?;
+    }
+    if !config.subcommands.is_empty() {
+        writeln!(output, "            let subcommand = self.subcommand.ok_or(ValidationError::MissingSubcommand)?.validate()?;")?;
+    }
+    writeln!(output)?;
+    if config.general.validate_fn.is_some() {
+        writeln!(output, "            let config = super::{} {{", config_type)?;
+    } else {
+        writeln!(output, "            Ok(super::{} {{", config_type)?;
+    }
+    write_params_and_switches::<visitor::ConstructConfig, _>(config, &mut output)?;
+    for section in &config.sections {
+        writeln!(output, "                {},", section.name.as_snake_case())?;
+    }
+    if !config.subcommands.is_empty() {
+        writeln!(output, "                subcommand,")?;
+    }
+    if let Some(validate_fn) = &config.general.validate_fn {
+        writeln!(output, "            }};")?;
+        writeln!(output, "            {}(&config).map_err(|error| ValidationError::Custom {{ field: None, source: None, message: error.to_string() }})?;", validate_fn)?;
+        writeln!(output)?;
+        writeln!(output, "            Ok(config)")?;
+    } else {
+        writeln!(output, "            }})")?;
+    }
+    Ok(())
+}
+
+fn underscore_to_hypen<W: Write>(mut output: W, ident: &str) -> fmt::Result {
+    for c in ident.chars() {
+        if c == '_' {
+                write!(output, "-")?;
+        } else {
+                write!(output, "{}", c)?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns the name of the env var of the param or switch called `name`.
+fn env_var_name(config: &Config, name: &::config::Ident) -> String {
+    match &config.general.env_prefix {
+        Some(prefix) => format!("{}_{}", prefix.to_uppercase(), name.as_upper_case()),
+        None => name.as_upper_case().to_string(),
+    }
+}
+
+/// Returns the names of the env vars of a param or switch in the order of precedence.
+///
+/// `env` overrides the name derived from `name`.
+fn env_var_names(config: &Config, name: &::config::Ident, env: &[String]) -> Vec<String> {
+    if env.is_empty() {
+        vec![env_var_name(config, name)]
+    } else {
+        env.to_vec()
     }
-    Ok(())
 }
 
 fn gen_merge_env<W: Write>(config: &Config, mut output: W) -> fmt::Result {
-    for param in &config.params {
+    for param in all_params(config) {
         if !param.env_var {
             continue;
         }
-        write!(output, "        if let Some(val) = ::std::env::var_os(\"")?;
-        config.general.env_prefix.as_ref().map(|prefix| { upper_case(&mut output, &prefix)?; write!(output, "_") }).unwrap_or(Ok(()))?;
-        writeln!(output, "{}\") {{", param.name.as_upper_case())?;
-        writeln!(output, "            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::Field{})?;", param.name.as_pascal_case())?;
-        if let Some(merge_fn) = &param.merge_fn {
-            writeln!(output, "            if let Some({}_old) = &mut self.{} {{", param.name.as_snake_case(), param.name.as_snake_case())?;
-            writeln!(output, "                {}({}_old, val);", merge_fn, param.name.as_snake_case())?;
-            writeln!(output, "            }} else {{")?;
-            writeln!(output, "                self.{} = Some(val);", param.name.as_snake_case())?;
-            writeln!(output, "            }}")?;
-        } else {
-            writeln!(output, "            self.{} = Some(val);", param.name.as_snake_case())?;
+        // Aliases and the file of a secret are processed first so that the current name takes
+        // precedence, of multiple current names only the first one set is used
+        let vars = env_var_names(config, &param.name, &param.env);
+        let aliases = param.aliases.iter().map(|alias| (vec![env_var_name(config, &param.alias_name(alias))], Some(param.alias_path(alias)), false));
+        let secret_file = if param.secret { Some((vec![format!("{}_FILE", vars[0])], None, true)) } else { None };
+        for (vars, alias, from_file) in aliases.chain(secret_file).chain(Some((vars, None, false))) {
+            for (i, var) in vars.iter().enumerate() {
+                let keyword = if i == 0 { "if" } else { "} else if" };
+                if from_file {
+                    writeln!(output, "        {} let Some(file_path) = ::std::env::var_os(\"{}\") {{", keyword, var)?;
+                    writeln!(output, "            let file_path = ::std::path::PathBuf::from(file_path);")?;
+                    writeln!(output, "            let val = ::configure_me::secret::read_file(&file_path).map_err(|err| super::EnvParseError::ReadSecretFile(err, file_path))?;")?;
+                } else {
+                    writeln!(output, "        {} let Some(val) = ::std::env::var_os(\"{}\") {{", keyword, var)?;
+                }
+                if param.multiple {
+                    writeln!(output, "            let val = match val.into_string() {{")?;
+                    writeln!(output, "                Ok(val) => val.split({:?}).map(|item| <{} as ::configure_me::parse_arg::ParseArg>::parse_arg(item.as_ref())).collect::<Result<Vec<_>, _>>(),", param.env_separator, param.ty)?;
+                    writeln!(output, "                // Values that aren't valid UTF-8 can't be split")?;
+                    writeln!(output, "                Err(val) => <{} as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(val).map(|item| vec![item]),", param.ty)?;
+                    writeln!(output, "            }}.map_err(super::EnvParseError::Field{})?;", param.name.as_pascal_case())?;
+                } else {
+                    writeln!(output, "            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::Field{})?;", param.name.as_pascal_case())?;
+                }
+                write_warnings(&mut output, "            ", "self.warnings", &param.field_path(), alias.as_deref(), &param.deprecated, &format!("super::Source::EnvVar(\"{}\")", var))?;
+                if let Some(merge_fn) = &param.merge_fn {
+                    writeln!(output, "            if let Some({}_old) = &mut self.{} {{", param.name.as_snake_case(), param.field_path())?;
+                    writeln!(output, "                {}({}_old, val);", merge_fn, param.name.as_snake_case())?;
+                    writeln!(output, "            }} else {{")?;
+                    writeln!(output, "                self.{} = Some(val);", param.field_path())?;
+                    writeln!(output, "            }}")?;
+                } else {
+                    writeln!(output, "            self.{} = Some(val);", param.field_path())?;
+                }
+                writeln!(output, "            self.{}_source = Some(super::Source::EnvVar(\"{}\"));", param.field_path(), var)?;
+            }
+            writeln!(output, "        }}")?;
         }
-        writeln!(output, "        }}")?;
     }
-    for switch in &config.switches {
+    for switch in all_switches(config) {
         if !switch.env_var {
             continue;
         }
-        write!(output, "        if let Some(val) = ::std::env::var_os(\"")?;
-        config.general.env_prefix.as_ref().map(|prefix| { upper_case(&mut output, &prefix)?; write!(output, "_") }).unwrap_or(Ok(()))?;
-        writeln!(output, "{}\") {{", switch.name.as_upper_case())?;
-        if switch.is_count() {
-            write!(output, "            let val= <u32 as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(val).map_err(super::EnvParseError::Field{})?;", switch.name.as_pascal_case())?;
-            writeln!(output, "            self.{} = Some(val);", switch.name.as_snake_case())?;
+        let aliases = switch.aliases.iter().map(|alias| (vec![env_var_name(config, &switch.alias_name(alias))], Some(switch.alias_path(alias))));
+        for (vars, alias) in aliases.chain(Some((env_var_names(config, &switch.name, &switch.env), None))) {
+            for (i, var) in vars.iter().enumerate() {
+                let keyword = if i == 0 { "if" } else { "} else if" };
+                writeln!(output, "        {} let Some(val) = ::std::env::var_os(\"{}\") {{", keyword, var)?;
+                write_warnings(&mut output, "            ", "self.warnings", &switch.field_path(), alias.as_deref(), &switch.deprecated, &format!("super::Source::EnvVar(\"{}\")", var))?;
+                if switch.is_count() {
+                    write!(output, "            let val= <u32 as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(val).map_err(super::EnvParseError::Field{})?;", switch.name.as_pascal_case())?;
+                    writeln!(output, "            self.{} = Some(val);", switch.field_path())?;
+                } else {
+                    writeln!(output, "            if val == *\"1\" || val == *\"true\" {{")?;
+                    writeln!(output, "                self.{} = Some(true);", switch.field_path())?;
+                    writeln!(output, "            }} else if val == *\"0\" || val == *\"false\" {{")?;
+                    writeln!(output, "                self.{} = Some(false);", switch.field_path())?;
+                    writeln!(output, "            }} else {{")?;
+                    writeln!(output, "                return Err(super::EnvParseError::Field{}(val).into());", switch.name.as_pascal_case())?;
+                    writeln!(output, "            }}")?;
+                }
+                writeln!(output, "            self.{}_source = Some(super::Source::EnvVar(\"{}\"));", switch.field_path(), var)?;
+            }
+            writeln!(output, "        }}")?;
+        }
+    }
+    Ok(())
+}
+
+fn gen_merge_in<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    write_params_and_switches::<visitor::MergeIn, _>(config, &mut output)?;
+    for section in &config.sections {
+        writeln!(output, "            self.{}.merge_in(other.{});", section.name.as_snake_case(), section.name.as_snake_case())?;
+    }
+    if !config.subcommands.is_empty() {
+        writeln!(output, "            if other.subcommand.is_some() {{")?;
+        writeln!(output, "                self.subcommand = other.subcommand;")?;
+        writeln!(output, "            }}")?;
+    }
+    Ok(())
+}
+
+/// Returns the long options accepted by the config and its sections.
+///
+/// These are the same options `MergeArgs` matches, except for hidden params and switches which
+/// are intentionally never suggested.
+fn known_arguments(config: &Config) -> Vec<String> {
+    let general = &config.general;
+    let general = general.conf_file_param.iter()
+        .chain(&general.conf_dir_param)
+        .chain(&general.skip_default_conf_files_switch)
+        .chain(&general.print_config_switch)
+        .chain(&general.check_config_switch)
+        .map(|name| format!("--{}", name.as_hypenated()));
+    let params = all_params(config)
+        .filter(|param| param.argument && !param.hidden)
+        .flat_map(|param| {
+            let secret_file = if param.secret { Some(format!("--{}-file", param.long_name())) } else { None };
+            let aliases = param.aliases.iter().map(move |alias| format!("--{}", param.alias_name(alias).as_hypenated()));
+            Some(param_long(param)).into_iter().chain(secret_file).chain(aliases)
+        });
+    let switches = all_switches(config)
+        .filter(|switch| !switch.hidden)
+        .flat_map(|switch| {
+            let aliases = switch.aliases.iter().map(move |alias| switch.alias_name(alias).as_hypenated().to_string());
+            Some(switch.long_name()).into_iter().chain(aliases).flat_map(move |name| {
+                let enable = if switch.negatable || !switch.is_inverted() { Some(format!("--{}", name)) } else { None };
+                let disable = if switch.negatable || switch.is_inverted() { Some(format!("--no-{}", name)) } else { None };
+                enable.into_iter().chain(disable)
+            })
+        });
+    Some("--help".to_owned()).into_iter().chain(general).chain(params).chain(switches).collect()
+}
+
+/// Writes the branch displaying `UnknownArgument` with the closest known long option.
+fn gen_display_unknown_argument<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    writeln!(output, "            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &{:?}) {{", known_arguments(config))?;
+    writeln!(output, "                Some(known) => write!(f, \"An unknown argument '{{}}' was specified, did you mean '{{}}'?\", arg, known),")?;
+    writeln!(output, "                None => write!(f, \"An unknown argument '{{}}' was specified.\", arg),")?;
+    writeln!(output, "            }},")
+}
+
+/// Generates the branches handling short options and unknown long options.
+///
+/// `program_name` is the expression used to construct the `HelpRequested` variant.
+fn gen_merge_short_and_unknown<W: Write>(config: &Config, program_name: &str, mut output: W) -> fmt::Result {
+    writeln!(output, "                }} else if let Some(mut shorts) = ::configure_me::parse_arg::iter_short(&arg) {{")?;
+    writeln!(output, "                    for short in &mut shorts {{")?;
+    writeln!(output, "                        if short == 'h' {{")?;
+    writeln!(output, "                            return Err(ArgParseError::HelpRequested({}).into())", program_name)?;
+    write_config::<visitor::MergeShort, _>(config, &mut output)?;
+    write_section_params_and_switches::<visitor::MergeShort, _>(config, &mut output)?;
+    writeln!(output, "                        }} else {{")?;
+    writeln!(output, "                            let mut arg = String::with_capacity(2);")?;
+    writeln!(output, "                            arg.push('-');")?;
+    writeln!(output, "                            arg.push(short);")?;
+    writeln!(output, "                            return Err(ArgParseError::UnknownArgument(arg).into());")?;
+    writeln!(output, "                        }}")?;
+    writeln!(output, "                    }}")?;
+    writeln!(output, "                }} else if arg.to_str().unwrap_or(\"\").starts_with(\"--\") {{")?;
+    writeln!(output, "                    return Err(ArgParseError::UnknownArgument(arg.into_string().unwrap()).into());")
+}
+
+fn gen_merge_positional<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    for subcommand in &config.subcommands {
+        writeln!(output, "                }} else if arg == *\"{}\" {{", subcommand.name.as_hypenated())?;
+        writeln!(output, "                    let mut subcommand = super::raw_{}::Config::default();", subcommand.name.as_snake_case())?;
+        writeln!(output, "                    let first_remaining = subcommand.merge_args(&program_path, &mut iter)?;")?;
+        if uses_warnings(&subcommand.config) {
+            writeln!(output, "                    self.warnings.append(&mut subcommand.warnings);")?;
+        }
+        writeln!(output, "                    self.subcommand = Some(Subcommand::{}(subcommand));", subcommand.name.as_pascal_case())?;
+        writeln!(output, "                    return Ok((program_path, first_remaining.into_iter().chain(iter)));")?;
+    }
+    if !config.positionals.is_empty() {
+        writeln!(output, "                }} else if let Some(arg) = self.merge_positional(arg)? {{")?;
+        writeln!(output, "                    return Ok((program_path, Some(arg).into_iter().chain(iter)))")
+    } else if config.subcommands.is_empty() {
+        writeln!(output, "                }} else {{")?;
+        writeln!(output, "                    return Ok((program_path, Some(arg).into_iter().chain(iter)))")
+    } else {
+        writeln!(output, "                }} else {{")?;
+        writeln!(output, "                    return Err(ArgParseError::UnknownSubcommand(arg.to_string_lossy().into_owned()).into());")
+    }
+}
+
+/// Generates handling of arguments following `--`.
+///
+/// If there are positional arguments, they are filled first and only the rest is returned.
+fn gen_merge_double_dash<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    if !config.positionals.is_empty() {
+        writeln!(output, "                    while let Some(arg) = iter.next() {{")?;
+        writeln!(output, "                        if let Some(arg) = self.merge_positional(arg)? {{")?;
+        writeln!(output, "                            return Ok((program_path, Some(arg).into_iter().chain(iter)));")?;
+        writeln!(output, "                        }}")?;
+        writeln!(output, "                    }}")?;
+    }
+    writeln!(output, "                    return Ok((program_path, None.into_iter().chain(iter)));")
+}
+
+/// Generates the method storing a positional argument into the first free field.
+///
+/// The method returns the argument back if there's no field left.
+fn gen_positional_fn<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    if config.positionals.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(output)?;
+    writeln!(output, "        fn merge_positional(&mut self, arg: ::std::ffi::OsString) -> Result<Option<::std::ffi::OsString>, super::Error> {{")?;
+    let (multiple, single) = config.positionals.split_last().filter(|(last, _)| last.multiple).map_or((None, &*config.positionals), |(last, rest)| (Some(last), rest));
+    let indent = if single.is_empty() { "            " } else { "                " };
+    for (i, positional) in single.iter().enumerate() {
+        if i == 0 {
+            writeln!(output, "            if self.{}.is_none() {{", positional.name.as_snake_case())?;
         } else {
-            writeln!(output, "            if val == *\"1\" || val == *\"true\" {{")?;
-            writeln!(output, "                self.{} = Some(true);", switch.name.as_snake_case())?;
-            writeln!(output, "            }} else if val == *\"0\" || val == *\"false\" {{")?;
-            writeln!(output, "                self.{} = Some(false);", switch.name.as_snake_case())?;
-            writeln!(output, "            }} else {{")?;
-            writeln!(output, "                return Err(super::EnvParseError::Field{}(val).into());", switch.name.as_pascal_case())?;
-            writeln!(output, "            }}")?;
+            writeln!(output, "            }} else if self.{}.is_none() {{", positional.name.as_snake_case())?;
+        }
+        writeln!(output, "                self.{} = Some(<{} as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(arg).map_err(ArgParseError::Field{})?);", positional.name.as_snake_case(), positional.ty, positional.name.as_pascal_case())?;
+    }
+    if !single.is_empty() {
+        writeln!(output, "            }} else {{")?;
+    }
+    match multiple {
+        Some(positional) => writeln!(output, "{}self.{}.push(<{} as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(arg).map_err(ArgParseError::Field{})?);", indent, positional.name.as_snake_case(), positional.ty, positional.name.as_pascal_case())?,
+        None => writeln!(output, "{}return Ok(Some(arg));", indent)?,
+    }
+    if !single.is_empty() {
+        writeln!(output, "            }}")?;
+    }
+    writeln!(output)?;
+    writeln!(output, "            Ok(None)")?;
+    writeln!(output, "        }}")
+}
+
+/// Generates the raw structs of sections inside the `raw` module.
+fn gen_raw_sections<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    for section in &config.sections {
+        writeln!(output)?;
+        writeln!(output, "    #[derive(Deserialize, Default)]")?;
+        writeln!(output, "    #[serde(crate = \"crate::configure_me::serde\")]")?;
+        writeln!(output, "    pub struct {}Section {{", section.name.as_pascal_case())?;
+        gen_raw_config(&section.config, &mut output)?;
+        writeln!(output, "    }}")?;
+        writeln!(output)?;
+        writeln!(output, "    #[automatically_derived]")?;
+        writeln!(output, "    impl {}Section {{", section.name.as_pascal_case())?;
+        writeln!(output, "        pub fn validate(self) -> Result<super::{}Section, ValidationError> {{", section.name.as_pascal_case())?;
+        gen_validation_fn_constructing(&section.config, &format!("{}Section", section.name.as_pascal_case()), &mut output)?;
+        writeln!(output, "        }}")?;
+        writeln!(output)?;
+        writeln!(output, "        pub fn merge_in(&mut self, other: Self) {{")?;
+        gen_merge_in(&section.config, &mut output)?;
+        writeln!(output, "        }}")?;
+        writeln!(output, "    }}")?;
+    }
+    Ok(())
+}
+
+/// Writes the `Serialize` derive of the final structs.
+fn gen_serialize_derive<W: Write>(mut output: W) -> fmt::Result {
+    writeln!(output, "#[derive(Serialize)]")?;
+    writeln!(output, "#[serde(crate = \"crate::configure_me::serde\")]")
+}
+
+/// Writes the fields of the final config or section.
+///
+/// If the struct is `serialize`d, the fields are named like in config files and missing values
+/// and positional arguments are left out.
+fn gen_final_fields<W: Write>(config: &Config, serialize: bool, mut output: W) -> fmt::Result {
+    if !serialize {
+        return write_params_and_switches::<visitor::ConfigFinal, _>(config, &mut output);
+    }
+
+    for param in &config.params {
+        if let Some(file_key) = &param.file_key {
+            writeln!(output, "    #[serde(rename = {:?})]", file_key)?;
+        }
+        if let (false, Optionality::Optional) = (param.multiple, &param.optionality) {
+            writeln!(output, "    #[serde(skip_serializing_if = \"Option::is_none\")]")?;
+        }
+        VisitWrite::<visitor::ConfigFinal>::visit_write(param, &mut output)?;
+    }
+    for switch in &config.switches {
+        if let Some(file_key) = &switch.file_key {
+            writeln!(output, "    #[serde(rename = {:?})]", file_key)?;
+        }
+        VisitWrite::<visitor::ConfigFinal>::visit_write(switch, &mut output)?;
+    }
+    for positional in &config.positionals {
+        writeln!(output, "    #[serde(skip)]")?;
+        VisitWrite::<visitor::ConfigFinal>::visit_write(positional, &mut output)?;
+    }
+    Ok(())
+}
+
+/// Generates the final structs of sections.
+fn gen_sections<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    for section in &config.sections {
+        match &section.doc {
+            Some(doc) => writeln!(output, "/// {}", doc)?,
+            None => writeln!(output, "/// Configuration of the `{}` section", section.name.as_snake_case())?,
+        }
+        if config.general.serialize {
+            gen_serialize_derive(&mut output)?;
+        }
+        writeln!(output, "pub struct {}Section {{", section.name.as_pascal_case())?;
+        gen_final_fields(&section.config, config.general.serialize, &mut output)?;
+        writeln!(output, "}}")?;
+        writeln!(output)?;
+    }
+    Ok(())
+}
+
+/// Generates the raw `Subcommand` enum inside the `raw` module.
+fn gen_raw_subcommand<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    if config.subcommands.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(output)?;
+    writeln!(output, "    pub enum Subcommand {{")?;
+    for subcommand in &config.subcommands {
+        writeln!(output, "        {}(super::raw_{}::Config),", subcommand.name.as_pascal_case(), subcommand.name.as_snake_case())?;
+    }
+    writeln!(output, "    }}")?;
+    writeln!(output)?;
+    writeln!(output, "    #[automatically_derived]")?;
+    writeln!(output, "    impl Subcommand {{")?;
+    writeln!(output, "        pub fn validate(self) -> Result<super::Subcommand, ValidationError> {{")?;
+    writeln!(output, "            match self {{")?;
+    for subcommand in &config.subcommands {
+        writeln!(output, "                Subcommand::{}(config) => config.validate().map(super::Subcommand::{}),", subcommand.name.as_pascal_case(), subcommand.name.as_pascal_case())?;
+    }
+    writeln!(output, "            }}")?;
+    writeln!(output, "        }}")?;
+    writeln!(output, "    }}")
+}
+
+/// Generates the top-level items of all subcommands.
+///
+/// Each subcommand gets its own argument parsing error, raw config module and final config so
+/// that the names of its parameters don't collide with names in other subcommands.
+fn gen_subcommands<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    if config.subcommands.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(output, "/// Subcommand selected on the command line")?;
+    writeln!(output, "pub enum Subcommand {{")?;
+    for subcommand in &config.subcommands {
+        if let Some(doc) = &subcommand.doc {
+            writeln!(output, "    /// {}", doc)?;
+        }
+        writeln!(output, "    {}({}Config),", subcommand.name.as_pascal_case(), subcommand.name.as_pascal_case())?;
+    }
+    writeln!(output, "}}")?;
+    writeln!(output)?;
+
+    for subcommand in &config.subcommands {
+        gen_subcommand(subcommand, &mut output)?;
+    }
+    Ok(())
+}
+
+fn gen_subcommand<W: Write>(subcommand: &::config::Subcommand, mut output: W) -> fmt::Result {
+    let config = &subcommand.config;
+    let pascal = subcommand.name.as_pascal_case();
+    let snake = subcommand.name.as_snake_case();
+
+    gen_param_enums(config, false, &mut output)?;
+    writeln!(output, "pub enum {}ArgParseError {{", pascal)?;
+    writeln!(output, "    MissingArgument(&'static str),")?;
+    writeln!(output, "    UnknownArgument(String),")?;
+    writeln!(output, "    HelpRequested(String),")?;
+    writeln!(output)?;
+    gen_arg_parse_error(config, &mut output)?;
+    writeln!(output, "}}")?;
+    writeln!(output)?;
+    writeln!(output, "#[automatically_derived]")?;
+    writeln!(output, "impl ::std::fmt::Display for {}ArgParseError {{", pascal)?;
+    writeln!(output, "    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {{")?;
+    writeln!(output, "        use self::{}ArgParseError as ArgParseError;", pascal)?;
+    writeln!(output)?;
+    writeln!(output, "        match self {{")?;
+    writeln!(output, "            ArgParseError::MissingArgument(arg) => write!(f, \"A value to argument '{{}}' is missing.\", arg),")?;
+    gen_display_unknown_argument(config, &mut output)?;
+    gen_display_arg_parse_error(config, &mut output)?;
+    writeln!(output, "        }}")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")?;
+    writeln!(output)?;
+    writeln!(output, "#[automatically_derived]")?;
+    writeln!(output, "impl ::std::fmt::Debug for {}ArgParseError {{", pascal)?;
+    writeln!(output, "    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {{")?;
+    writeln!(output, "        ::std::fmt::Display::fmt(self, f)")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")?;
+    writeln!(output)?;
+    writeln!(output, "#[automatically_derived]")?;
+    writeln!(output, "impl From<{}ArgParseError> for Error {{", pascal)?;
+    writeln!(output, "    fn from(err: {}ArgParseError) -> Self {{", pascal)?;
+    writeln!(output, "        Error::Arguments(ArgParseError::Subcommand{}(err))", pascal)?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")?;
+    writeln!(output)?;
+    writeln!(output, "mod raw_{} {{", snake)?;
+    writeln!(output, "    use super::{{{}ArgParseError as ArgParseError, ValidationError}};", pascal)?;
+    gen_raw_imports(config, &mut output)?;
+    writeln!(output)?;
+    writeln!(output, "    #[derive(Default)]")?;
+    writeln!(output, "    pub struct Config {{")?;
+    gen_raw_fields(config, false, &mut output)?;
+    if uses_warnings(config) {
+        writeln!(output, "        pub warnings: Vec<super::Warning>,")?;
+    }
+    writeln!(output, "    }}")?;
+    writeln!(output)?;
+    writeln!(output, "    #[automatically_derived]")?;
+    writeln!(output, "    impl Config {{")?;
+    writeln!(output, "        pub fn validate(self) -> Result<super::{}Config, ValidationError> {{", pascal)?;
+    gen_validation_fn_constructing(config, &format!("{}Config", pascal), &mut output)?;
+    writeln!(output, "        }}")?;
+    writeln!(output)?;
+    writeln!(output, "        pub fn merge_args<I: Iterator<Item=::std::ffi::OsString>>(&mut self, program_path: &Option<std::path::PathBuf>, mut iter: I) -> Result<Option<::std::ffi::OsString>, super::Error> {{")?;
+    writeln!(output, "            while let Some(arg) = iter.next() {{")?;
+    writeln!(output, "                if arg == *\"--\" {{")?;
+    writeln!(output, "                    return Ok(None);")?;
+    writeln!(output, "                }} else if (arg == *\"--help\") || (arg == *\"-h\") {{")?;
+    writeln!(output, "                    return Err(ArgParseError::HelpRequested(format!(\"{{}} {}\", program_path.as_ref().unwrap().to_string_lossy())).into());", subcommand.name.as_hypenated())?;
+    write_params_and_switches::<visitor::MergeArgs, _>(config, &mut output)?;
+    gen_merge_short_and_unknown(config, &format!("format!(\"{{}} {}\", program_path.as_ref().unwrap().to_string_lossy())", subcommand.name.as_hypenated()), &mut output)?;
+    writeln!(output, "                }} else {{")?;
+    writeln!(output, "                    return Ok(Some(arg));")?;
+    writeln!(output, "                }}")?;
+    writeln!(output, "            }}")?;
+    writeln!(output)?;
+    writeln!(output, "            Ok(None)")?;
+    writeln!(output, "        }}")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")?;
+    writeln!(output)?;
+    writeln!(output, "/// Configuration of the `{}` subcommand", subcommand.name.as_hypenated())?;
+    writeln!(output, "pub struct {}Config {{", pascal)?;
+    write_params_and_switches::<visitor::ConfigFinal, _>(config, &mut output)?;
+    writeln!(output, "}}")?;
+    writeln!(output)
+}
+
+/// Generates `use` of the enums of params with enumerated values for a raw module.
+fn gen_raw_imports<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    let enums = all_params(config).filter(|param| param.values.is_some()).map(|param| &*param.ty).collect::<Vec<_>>();
+    match enums.len() {
+        0 => Ok(()),
+        1 => writeln!(output, "    use super::{};", enums[0]),
+        _ => writeln!(output, "    use super::{{{}}};", enums.join(", ")),
+    }
+}
+
+/// Generates enums for all params with enumerated values.
+///
+/// The `InvalidValue` error is shared by all enums, including those of subcommands.
+fn gen_value_enums<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    let has_values = Some(config)
+        .into_iter()
+        .chain(config.subcommands.iter().map(|subcommand| &subcommand.config))
+        .flat_map(all_params)
+        .any(|param| param.values.is_some());
+    if !has_values {
+        return Ok(());
+    }
+
+    writeln!(output, "/// Error returned when parsing a value that is not allowed")?;
+    writeln!(output, "#[derive(Debug, Clone)]")?;
+    writeln!(output, "pub struct InvalidValue(String);")?;
+    writeln!(output)?;
+    writeln!(output, "#[automatically_derived]")?;
+    writeln!(output, "impl ::std::fmt::Display for InvalidValue {{")?;
+    writeln!(output, "    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {{")?;
+    writeln!(output, "        write!(f, \"invalid value '{{}}'\", self.0)")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")?;
+    writeln!(output)?;
+    gen_param_enums(config, config.general.serialize, &mut output)?;
+    for section in &config.sections {
+        gen_param_enums(&section.config, config.general.serialize, &mut output)?;
+    }
+    Ok(())
+}
+
+/// Writes the enums of params with `values`, `serialize` adds implementations of `Serialize`.
+fn gen_param_enums<W: Write>(config: &Config, serialize: bool, mut output: W) -> fmt::Result {
+    use ::config::pascal_case;
+
+    for param in &config.params {
+        let values = match &param.values {
+            Some(values) => values,
+            None => continue,
+        };
+        let ty = &param.ty;
+
+        writeln!(output, "/// Possible values of the `{}` parameter", param.field_path())?;
+        writeln!(output, "#[derive(Debug, Copy, Clone, Eq, PartialEq)]")?;
+        writeln!(output, "pub enum {} {{", ty)?;
+        for value in values {
+            writeln!(output, "    {},", pascal_case(value))?;
+        }
+        writeln!(output, "}}")?;
+        writeln!(output)?;
+        writeln!(output, "#[automatically_derived]")?;
+        writeln!(output, "impl ::std::str::FromStr for {} {{", ty)?;
+        writeln!(output, "    type Err = InvalidValue;")?;
+        writeln!(output)?;
+        writeln!(output, "    fn from_str(s: &str) -> Result<Self, Self::Err> {{")?;
+        writeln!(output, "        match s {{")?;
+        for value in values {
+            writeln!(output, "            {:?} => Ok({}::{}),", value, ty, pascal_case(value))?;
+        }
+        writeln!(output, "            _ => Err(InvalidValue(s.to_owned())),")?;
+        writeln!(output, "        }}")?;
+        writeln!(output, "    }}")?;
+        writeln!(output, "}}")?;
+        writeln!(output)?;
+        writeln!(output, "#[automatically_derived]")?;
+        writeln!(output, "impl ::configure_me::parse_arg::ParseArgFromStr for {} {{", ty)?;
+        writeln!(output, "    fn describe_type<W: ::std::fmt::Write>(mut writer: W) -> ::std::fmt::Result {{")?;
+        let described = values.iter().map(|value| format!("`{}`", value)).collect::<Vec<_>>().join(", ");
+        writeln!(output, "        write!(writer, \"one of {}\")", described)?;
+        writeln!(output, "    }}")?;
+        writeln!(output, "}}")?;
+        writeln!(output)?;
+        writeln!(output, "#[automatically_derived]")?;
+        writeln!(output, "impl ::std::fmt::Display for {} {{", ty)?;
+        writeln!(output, "    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {{")?;
+        writeln!(output, "        match self {{")?;
+        for value in values {
+            writeln!(output, "            {}::{} => f.write_str({:?}),", ty, pascal_case(value), value)?;
         }
         writeln!(output, "        }}")?;
+        writeln!(output, "    }}")?;
+        writeln!(output, "}}")?;
+        writeln!(output)?;
+        writeln!(output, "#[automatically_derived]")?;
+        writeln!(output, "impl<'de> ::configure_me::serde::Deserialize<'de> for {} {{", ty)?;
+        writeln!(output, "    fn deserialize<D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{")?;
+        writeln!(output, "        let value = <String as ::configure_me::serde::Deserialize>::deserialize(deserializer)?;")?;
+        let quoted = values.iter().map(|value| format!("{:?}", value)).collect::<Vec<_>>().join(", ");
+        writeln!(output, "        value.parse().map_err(|_| <D::Error as ::configure_me::serde::de::Error>::unknown_variant(&value, &[{}]))", quoted)?;
+        writeln!(output, "    }}")?;
+        writeln!(output, "}}")?;
+        writeln!(output)?;
+        if serialize {
+            writeln!(output, "#[automatically_derived]")?;
+            writeln!(output, "impl ::configure_me::serde::Serialize for {} {{", ty)?;
+            writeln!(output, "    fn serialize<S: ::configure_me::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{")?;
+            writeln!(output, "        serializer.collect_str(self)")?;
+            writeln!(output, "    }}")?;
+            writeln!(output, "}}")?;
+            writeln!(output)?;
+        }
     }
     Ok(())
 }
 
 #[cfg(test)]
 fn gen_merge_args<W: Write>(config: &Config, mut output: W) -> fmt::Result {
-    write_config::<visitor::MergeArgs, _>(config, &mut output)
+    write_config::<visitor::MergeArgs, _>(config, &mut output)?;
+    write_section_params_and_switches::<visitor::MergeArgs, _>(config, &mut output)
 }
 
 pub fn generate_code<W: Write>(config: &Config, mut output: W) -> fmt::Result {
-    let has_mandatory = config.params.iter().any(|param| if let Optionality::Mandatory = param.optionality { true } else { false });
+    let has_mandatory = Some(config)
+        .into_iter()
+        .chain(config.subcommands.iter().map(|subcommand| &subcommand.config))
+        .flat_map(all_params)
+        .any(|param| if let Optionality::Mandatory = param.optionality { true } else { false });
+    let has_mandatory_positional = config.positionals.iter().any(|positional| !positional.optional);
+    let has_constraint = |is_used: fn(&::config::Constraints) -> bool| Some(config)
+        .into_iter()
+        .chain(config.subcommands.iter().map(|subcommand| &subcommand.config))
+        .flat_map(|config| all_params(config).map(|param| &param.constraints).chain(all_switches(config).map(|switch| &switch.constraints)))
+        .any(is_used);
+    let has_requires = has_constraint(|constraints| !constraints.requires.is_empty());
+    let has_conflicts_with = has_constraint(|constraints| !constraints.conflicts_with.is_empty());
+    let has_required_unless = has_constraint(|constraints| !constraints.required_unless.is_empty());
+    let has_value_constraint = |is_used: fn(&::config::ValueConstraints) -> bool| Some(config)
+        .into_iter()
+        .chain(config.subcommands.iter().map(|subcommand| &subcommand.config))
+        .flat_map(all_params)
+        .any(|param| is_used(&param.value_constraints));
+    let has_range = has_value_constraint(|constraints| constraints.min.is_some() || constraints.max.is_some() || constraints.min_len.is_some() || constraints.max_len.is_some());
+    let has_pattern = has_value_constraint(|constraints| constraints.pattern.is_some());
+    let has_custom = config.general.validate_fn.is_some() || Some(config)
+        .into_iter()
+        .chain(config.subcommands.iter().map(|subcommand| &subcommand.config))
+        .flat_map(all_params)
+        .any(|param| param.validate_fn.is_some());
     writeln!(output, "pub mod prelude {{")?;
     writeln!(output, "    pub use super::{{Config, ResultExt}};")?;
     writeln!(output, "}}")?;
@@ -690,7 +2204,7 @@ pub fn generate_code<W: Write>(config: &Config, mut output: W) -> fmt::Result {
     writeln!(output, "    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {{")?;
     writeln!(output, "        match self {{")?;
     writeln!(output, "            ArgParseError::MissingArgument(arg) => write!(f, \"A value to argument '{{}}' is missing.\", arg),")?;
-    writeln!(output, "            ArgParseError::UnknownArgument(arg) => write!(f, \"An unknown argument '{{}}' was specified.\", arg),")?;
+    gen_display_unknown_argument(config, &mut output)?;
     gen_display_arg_parse_error(config, &mut output)?;
     writeln!(output, "        }}")?;
     writeln!(output, "    }}")?;
@@ -730,6 +2244,30 @@ pub fn generate_code<W: Write>(config: &Config, mut output: W) -> fmt::Result {
     if let ProgramName::Required = config.general.program_name {
         writeln!(output, "    MissingProgramName,")?;
     }
+    if has_mandatory_positional {
+        writeln!(output, "    MissingPositional(&'static str),")?;
+    }
+    if !config.subcommands.is_empty() {
+        writeln!(output, "    MissingSubcommand,")?;
+    }
+    if has_requires {
+        writeln!(output, "    Requires {{ field: &'static str, required: &'static str }},")?;
+    }
+    if has_conflicts_with {
+        writeln!(output, "    ConflictsWith {{ field: &'static str, conflicting: &'static str }},")?;
+    }
+    if has_required_unless {
+        writeln!(output, "    RequiredUnless {{ field: &'static str, alternatives: &'static [&'static str] }},")?;
+    }
+    if has_range {
+        writeln!(output, "    OutOfRange {{ field: &'static str, source: Source, constraint: &'static str }},")?;
+    }
+    if has_pattern {
+        writeln!(output, "    PatternMismatch {{ field: &'static str, source: Source, pattern: &'static str }},")?;
+    }
+    if has_custom {
+        writeln!(output, "    Custom {{ field: Option<&'static str>, source: Option<Source>, message: String }},")?;
+    }
     writeln!(output, "}}")?;
     writeln!(output)?;
     writeln!(output, "#[automatically_derived]")?;
@@ -742,6 +2280,41 @@ pub fn generate_code<W: Write>(config: &Config, mut output: W) -> fmt::Result {
     if let ProgramName::Required = config.general.program_name {
         writeln!(output, "            ValidationError::MissingProgramName => write!(f, \"Missing program name (the zeroth argument)\"),")?;
     }
+    if has_mandatory_positional {
+        writeln!(output, "            ValidationError::MissingPositional(positional) => write!(f, \"Positional argument '{{}}' not specified.\", positional),")?;
+    }
+    if !config.subcommands.is_empty() {
+        writeln!(output, "            ValidationError::MissingSubcommand => write!(f, \"Missing subcommand.\"),")?;
+    }
+    if has_requires {
+        writeln!(output, "            ValidationError::Requires {{ field, required }} => write!(f, \"Configuration parameter '{{}}' requires '{{}}' to be specified as well.\", field, required),")?;
+    }
+    if has_conflicts_with {
+        writeln!(output, "            ValidationError::ConflictsWith {{ field, conflicting }} => write!(f, \"Configuration parameters '{{}}' and '{{}}' can't be specified at the same time.\", field, conflicting),")?;
+    }
+    if has_required_unless {
+        writeln!(output, "            ValidationError::RequiredUnless {{ field, alternatives }} => {{")?;
+        writeln!(output, "                write!(f, \"Configuration parameter '{{}}' not specified, it's required unless \", field)?;")?;
+        writeln!(output, "                for (i, alternative) in alternatives.iter().enumerate() {{")?;
+        writeln!(output, "                    if i > 0 {{")?;
+        writeln!(output, "                        write!(f, \" or \")?;")?;
+        writeln!(output, "                    }}")?;
+        writeln!(output, "                    write!(f, \"'{{}}'\", alternative)?;")?;
+        writeln!(output, "                }}")?;
+        writeln!(output, "                write!(f, \" is specified.\")")?;
+        writeln!(output, "            }},")?;
+    }
+    if has_range {
+        writeln!(output, "            ValidationError::OutOfRange {{ field, ref source, constraint }} => write!(f, \"Value of configuration parameter '{{}}' from {{}} must be {{}}.\", field, source, constraint),")?;
+    }
+    if has_pattern {
+        writeln!(output, "            ValidationError::PatternMismatch {{ field, ref source, pattern }} => write!(f, \"Value of configuration parameter '{{}}' from {{}} must match the pattern `{{}}`.\", field, source, pattern),")?;
+    }
+    if has_custom {
+        writeln!(output, "            ValidationError::Custom {{ field: Some(field), source: Some(ref source), ref message }} => write!(f, \"Value of configuration parameter '{{}}' from {{}} is invalid: {{}}\", field, source, message),")?;
+        writeln!(output, "            ValidationError::Custom {{ field: Some(field), source: None, ref message }} => write!(f, \"Configuration parameter '{{}}' is invalid: {{}}\", field, message),")?;
+        writeln!(output, "            ValidationError::Custom {{ field: None, ref message, .. }} => write!(f, \"{{}}\", message),")?;
+    }
     writeln!(output, "        }}")?;
     writeln!(output, "    }}")?;
     writeln!(output, "}}")?;
@@ -753,9 +2326,30 @@ pub fn generate_code<W: Write>(config: &Config, mut output: W) -> fmt::Result {
     writeln!(output, "    }}")?;
     writeln!(output, "}}")?;
     writeln!(output)?;
+    gen_source(config, &mut output)?;
+    gen_warning(config, &mut output)?;
     writeln!(output, "pub enum Error {{")?;
     writeln!(output, "    Reading {{ file: ::std::path::PathBuf, error: ::std::io::Error }},")?;
-    writeln!(output, "    ConfigParsing {{ file: ::std::path::PathBuf, error: ::configure_me::toml::de::Error }},")?;
+    if config.general.conf_format.is_empty() {
+        writeln!(output, "    ConfigParsing {{ file: ::std::path::PathBuf, error: ::configure_me::toml::de::Error }},")?;
+    } else {
+        writeln!(output, "    ConfigParsing {{ file: ::std::path::PathBuf, error: ::configure_me::format::Error }},")?;
+    }
+    if config.general.unknown_keys == UnknownKeys::Error {
+        writeln!(output, "    UnknownKey {{ file: ::std::path::PathBuf, key: String, suggestion: Option<&'static str> }},")?;
+    }
+    if config.general.print_config_switch.is_some() {
+        writeln!(output, "    /// The configuration requested by the print config switch, not an actual error")?;
+        writeln!(output, "    PrintConfig(String),")?;
+        writeln!(output, "    SerializingConfig(::configure_me::toml::ser::Error),")?;
+    }
+    if config.general.check_config_switch.is_some() {
+        writeln!(output, "    /// The configuration passed the check requested by the check config switch, not an actual error")?;
+        writeln!(output, "    ConfigValid,")?;
+    }
+    if has_not_reloadable(config) {
+        writeln!(output, "    NotReloadable(&'static str),")?;
+    }
     writeln!(output, "    Arguments(ArgParseError),")?;
     writeln!(output, "    Environment(EnvParseError),")?;
     writeln!(output, "    Validation(ValidationError),")?;
@@ -788,6 +2382,20 @@ pub fn generate_code<W: Write>(config: &Config, mut output: W) -> fmt::Result {
     writeln!(output, "        match self {{")?;
     writeln!(output, "            Error::Reading {{ file, error }} => write!(f, \"Failed to read configuration file {{}}: {{}}\", file.display(), error),")?;
     writeln!(output, "            Error::ConfigParsing {{ file, error }} => write!(f, \"Failed to parse configuration file {{}}: {{}}\", file.display(), error),")?;
+    if config.general.unknown_keys == UnknownKeys::Error {
+        writeln!(output, "            Error::UnknownKey {{ file, key, suggestion: Some(suggestion) }} => write!(f, \"Unknown key '{{}}' in configuration file {{}}, did you mean '{{}}'?\", key, file.display(), suggestion),")?;
+        writeln!(output, "            Error::UnknownKey {{ file, key, suggestion: None }} => write!(f, \"Unknown key '{{}}' in configuration file {{}}.\", key, file.display()),")?;
+    }
+    if config.general.print_config_switch.is_some() {
+        writeln!(output, "            Error::PrintConfig(config) => write!(f, \"{{}}\", config.trim_end()),")?;
+        writeln!(output, "            Error::SerializingConfig(error) => write!(f, \"Failed to serialize the configuration: {{}}\", error),")?;
+    }
+    if config.general.check_config_switch.is_some() {
+        writeln!(output, "            Error::ConfigValid => write!(f, \"The configuration is valid.\"),")?;
+    }
+    if has_not_reloadable(config) {
+        writeln!(output, "            Error::NotReloadable(field) => write!(f, \"Configuration parameter '{{}}' can't be changed without restarting.\", field),")?;
+    }
     writeln!(output, "            Error::Arguments(err) => write!(f, \"{{}}\", err),")?;
     writeln!(output, "            Error::Environment(err) => write!(f, \"{{}}\", err),")?;
     writeln!(output, "            Error::Validation(err) => write!(f, \"Invalid configuration: {{}}\", err),")?;
@@ -802,13 +2410,27 @@ pub fn generate_code<W: Write>(config: &Config, mut output: W) -> fmt::Result {
     writeln!(output, "    }}")?;
     writeln!(output, "}}")?;
     writeln!(output)?;
+    gen_value_enums(config, &mut output)?;
     writeln!(output, "mod raw {{")?;
     writeln!(output, "    use super::{{ArgParseError, ValidationError}};")?;
+    gen_raw_imports(config, &mut output)?;
     writeln!(output)?;
     writeln!(output, "    #[derive(Deserialize, Default)]")?;
     writeln!(output, "    #[serde(crate = \"crate::configure_me::serde\")]")?;
     writeln!(output, "    pub struct Config {{")?;
     gen_raw_config(config, &mut output)?;
+    if has_warnings(config) {
+        writeln!(output, "        #[serde(skip)]")?;
+        writeln!(output, "        pub warnings: Vec<super::Warning>,")?;
+    }
+    if config.general.print_config_switch.is_some() {
+        writeln!(output, "        #[serde(skip)]")?;
+        writeln!(output, "        pub print_config: bool,")?;
+    }
+    if config.general.check_config_switch.is_some() {
+        writeln!(output, "        #[serde(skip)]")?;
+        writeln!(output, "        pub check_config: bool,")?;
+    }
     writeln!(output, "    }}")?;
     writeln!(output)?;
     writeln!(output, "    #[automatically_derived]")?;
@@ -819,7 +2441,7 @@ pub fn generate_code<W: Write>(config: &Config, mut output: W) -> fmt::Result {
     writeln!(output, "            let mut config_file = ::std::fs::File::open(&config_file_name).map_err(|error| super::Error::Reading {{ file: config_file_name.as_ref().into(), error }})?;")?;
     writeln!(output, "            let mut config_content = Vec::new();")?;
     writeln!(output, "            config_file.read_to_end(&mut config_content).map_err(|error| super::Error::Reading {{ file: config_file_name.as_ref().into(), error }})?;")?;
-    writeln!(output, "            ::configure_me::toml::from_slice(&config_content).map_err(|error| super::Error::ConfigParsing {{ file: config_file_name.as_ref().into(), error }})")?;
+    gen_load_config(config, &mut output)?;
     writeln!(output, "        }}")?;
     writeln!(output)?;
     writeln!(output, "        pub fn validate(self) -> Result<super::Config, ValidationError> {{")?;
@@ -827,7 +2449,16 @@ pub fn generate_code<W: Write>(config: &Config, mut output: W) -> fmt::Result {
     writeln!(output, "        }}")?;
     writeln!(output)?;
     writeln!(output, "        pub fn merge_in(&mut self, other: Self) {{")?;
-    write_params_and_switches::<visitor::MergeIn, _>(config, &mut output)?;
+    gen_merge_in(config, &mut output)?;
+    if has_warnings(config) {
+        writeln!(output, "            self.warnings.extend(other.warnings);")?;
+    }
+    if config.general.print_config_switch.is_some() {
+        writeln!(output, "            self.print_config |= other.print_config;")?;
+    }
+    if config.general.check_config_switch.is_some() {
+        writeln!(output, "            self.check_config |= other.check_config;")?;
+    }
     writeln!(output, "        }}")?;
     writeln!(output)?;
     writeln!(output, "        pub fn merge_args<I: IntoIterator<Item=::std::ffi::OsString>>(&mut self, args: I, skip_default_conf_files: &mut bool) -> Result<(Option<std::path::PathBuf>, impl Iterator<Item=::std::ffi::OsString>), super::Error> {{")?;
@@ -840,26 +2471,13 @@ pub fn generate_code<W: Write>(config: &Config, mut output: W) -> fmt::Result {
     writeln!(output)?;
     writeln!(output, "            while let Some(arg) = iter.next() {{")?;
     writeln!(output, "                if arg == *\"--\" {{")?;
-    writeln!(output, "                    return Ok((program_path, None.into_iter().chain(iter)));")?;
+    gen_merge_double_dash(config, &mut output)?;
     writeln!(output, "                }} else if (arg == *\"--help\") || (arg == *\"-h\") {{")?;
     writeln!(output, "                    return Err(ArgParseError::HelpRequested(program_path.as_ref().unwrap().to_string_lossy().into()).into());")?;
     write_config::<visitor::MergeArgs, _>(config, &mut output)?;
-    writeln!(output, "                }} else if let Some(mut shorts) = ::configure_me::parse_arg::iter_short(&arg) {{")?;
-    writeln!(output, "                    for short in &mut shorts {{")?;
-    writeln!(output, "                        if short == 'h' {{")?;
-    writeln!(output, "                            return Err(ArgParseError::HelpRequested(program_path.as_ref().unwrap().to_string_lossy().into()).into())")?;
-    write_config::<visitor::MergeShort, _>(config, &mut output)?;
-    writeln!(output, "                        }} else {{")?;
-    writeln!(output, "                            let mut arg = String::with_capacity(2);")?;
-    writeln!(output, "                            arg.push('-');")?;
-    writeln!(output, "                            arg.push(short);")?;
-    writeln!(output, "                            return Err(ArgParseError::UnknownArgument(arg).into());")?;
-    writeln!(output, "                        }}")?;
-    writeln!(output, "                    }}")?;
-    writeln!(output, "                }} else if arg.to_str().unwrap_or(\"\").starts_with(\"--\") {{")?;
-    writeln!(output, "                    return Err(ArgParseError::UnknownArgument(arg.into_string().unwrap()).into());")?;
-    writeln!(output, "                }} else {{")?;
-    writeln!(output, "                    return Ok((program_path, Some(arg).into_iter().chain(iter)))")?;
+    write_section_params_and_switches::<visitor::MergeArgs, _>(config, &mut output)?;
+    gen_merge_short_and_unknown(config, "program_path.as_ref().unwrap().to_string_lossy().into()", &mut output)?;
+    gen_merge_positional(config, &mut output)?;
     writeln!(output, "                }}")?;
     writeln!(output, "            }}")?;
     writeln!(output)?;
@@ -870,12 +2488,29 @@ pub fn generate_code<W: Write>(config: &Config, mut output: W) -> fmt::Result {
     gen_merge_env(config, &mut output)?;
     writeln!(output, "            Ok(())")?;
     writeln!(output, "        }}")?;
+    gen_positional_fn(config, &mut output)?;
     writeln!(output, "    }}")?;
+    gen_raw_sections(config, &mut output)?;
+    gen_raw_subcommand(config, &mut output)?;
     writeln!(output, "}}")?;
     writeln!(output)?;
+    gen_subcommands(config, &mut output)?;
+    gen_sections(config, &mut output)?;
     writeln!(output, "/// Configuration of the application")?;
+    if config.general.serialize {
+        gen_serialize_derive(&mut output)?;
+    }
     writeln!(output, "pub struct Config {{")?;
-    write_params_and_switches::<visitor::ConfigFinal, _>(config, &mut output)?;
+    gen_final_fields(config, config.general.serialize, &mut output)?;
+    for section in &config.sections {
+        writeln!(output, "    pub {}: {}Section,", section.name.as_snake_case(), section.name.as_pascal_case())?;
+    }
+    if !config.subcommands.is_empty() {
+        if config.general.serialize {
+            writeln!(output, "    #[serde(skip)]")?;
+        }
+        writeln!(output, "    pub subcommand: Subcommand,")?;
+    }
     writeln!(output, "}}")?;
     writeln!(output)?;
     writeln!(output, "#[automatically_derived]")?;
@@ -920,19 +2555,51 @@ pub fn generate_code<W: Write>(config: &Config, mut output: W) -> fmt::Result {
         ProgramName::Unused => (),
         ProgramName::Optional | ProgramName::Required => writeln!(output, "            program_name,")?,
     }
+    if has_warnings(config) {
+        writeln!(output, "            warnings: ::std::mem::take(&mut config.warnings),")?;
+    }
+    writeln!(output, "            sources: Sources {{")?;
+    gen_sources_values(config, "                ", &mut output)?;
+    if !config.subcommands.is_empty() {
+        writeln!(output, "                subcommand: match &config.subcommand {{")?;
+        for subcommand in &config.subcommands {
+            let pascal = subcommand.name.as_pascal_case();
+            writeln!(output, "                    Some(raw::Subcommand::{}(config)) => SubcommandSources::{}({}Sources {{", pascal, pascal, pascal)?;
+            gen_sources_values(&subcommand.config, "                        ", &mut output)?;
+            writeln!(output, "                    }}),")?;
+        }
+        // Validation would fail the same way, it just checks the subcommand first
+        writeln!(output, "                    None => return Err(ValidationError::MissingSubcommand.into()),")?;
+        writeln!(output, "                }},")?;
+    }
+    writeln!(output, "            }},")?;
     writeln!(output, "        }};")?;
     writeln!(output)?;
+    if config.general.check_config_switch.is_some() {
+        writeln!(output, "        if config.check_config {{")?;
+        writeln!(output, "            config.validate()?;")?;
+        writeln!(output, "            return Err(Error::ConfigValid);")?;
+        writeln!(output, "        }}")?;
+    }
+    if config.general.print_config_switch.is_some() {
+        writeln!(output, "        if config.print_config {{")?;
+        writeln!(output, "            let config = config.validate()?;")?;
+        writeln!(output, "            return Err(::configure_me::toml::to_string(&config).map_or_else(Error::SerializingConfig, Error::PrintConfig));")?;
+        writeln!(output, "        }}")?;
+    }
     writeln!(output, "        config")?;
     writeln!(output, "            .validate()")?;
     writeln!(output, "            .map(|cfg| (cfg, remaining_args, metadata))")?;
     writeln!(output, "            .map_err(Into::into)")?;
     writeln!(output, "    }}")?;
+    gen_reload(config, &mut output)?;
     writeln!(output, "}}")?;
     writeln!(output)?;
     writeln!(output, "/// Metadata of the configuration.")?;
     writeln!(output, "///")?;
     writeln!(output, "/// This struct provides some additional information regarding the configuration.")?;
-    writeln!(output, "/// Currently it only contains program name but more items could be available in the future.")?;
+    writeln!(output, "/// It contains the sources of the values and, depending on the specification, the program name")?;
+    writeln!(output, "/// and warnings. More items could be available in the future.")?;
     writeln!(output, "#[non_exhaustive]")?;
     writeln!(output, "pub struct Metadata {{")?;
     match config.general.program_name {
@@ -940,6 +2607,12 @@ pub fn generate_code<W: Write>(config: &Config, mut output: W) -> fmt::Result {
         ProgramName::Optional => writeln!(output, "    pub program_name: Option<std::path::PathBuf>,")?,
         ProgramName::Required => writeln!(output, "    pub program_name: std::path::PathBuf,")?,
     }
+    if has_warnings(config) {
+        writeln!(output, "    /// Problems found in the configuration, such as use of deprecated options")?;
+        writeln!(output, "    pub warnings: Vec<Warning>,")?;
+    }
+    writeln!(output, "    /// Where the value of each parameter and switch came from")?;
+    writeln!(output, "    pub sources: Sources,")?;
     writeln!(output, "}}")?;
     writeln!(output)?;
     writeln!(output, "pub trait ResultExt {{")?;
@@ -957,6 +2630,15 @@ pub fn generate_code<W: Write>(config: &Config, mut output: W) -> fmt::Result {
     writeln!(output)?;
     writeln!(output, "        match self {{")?;
     writeln!(output, "            Ok(item) => item,")?;
+    for subcommand in &config.subcommands {
+        writeln!(output, "            Err(err @ Error::Arguments(ArgParseError::Subcommand{}({}ArgParseError::HelpRequested(_)))) |", subcommand.name.as_pascal_case(), subcommand.name.as_pascal_case())?;
+    }
+    if config.general.print_config_switch.is_some() {
+        writeln!(output, "            Err(err @ Error::PrintConfig(_)) |")?;
+    }
+    if config.general.check_config_switch.is_some() {
+        writeln!(output, "            Err(err @ Error::ConfigValid) |")?;
+    }
     writeln!(output, "            Err(err @ Error::Arguments(ArgParseError::HelpRequested(_))) => {{")?;
     writeln!(output, "                println!(\"{{}}\", err);")?;
     writeln!(output, "                std::io::stdout().flush().expect(\"failed to flush stdout\");")?;
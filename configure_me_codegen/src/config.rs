@@ -1,12 +1,41 @@
 use std::fmt;
 
+#[cfg_attr(not(feature = "spanned"), allow(unused))]
 #[derive(Debug)]
 pub enum FieldError {
     MandatoryWithDefault { optional_span: Span, default_span: Span, },
     InvertedWithAbbr { default_span: Span, abbr_span: Span, },
     InvertedWithCount { default_span: Span, count_span: Span, },
     InvalidAbbr { abbr_span: Span, },
+    EnvVarInSubcommand { env_var_span: Span, },
+    MultiplePositionalNotLast { multiple_span: Span, },
+    MandatoryPositionalAfterOptional { optional_span: Span, },
+    PositionalWithSubcommands,
+    SeparatorWithoutMultiple { separator_span: Span, },
+    MergeFnWithMultiple { merge_fn_span: Span, },
+    MissingType,
+    TypeWithValues { type_span: Span, values_span: Span, },
+    EmptyValues { values_span: Span, },
+    InvalidValue { value_span: Span, },
+    DuplicateValue { first_span: Span, duplicate_span: Span, },
+    ReservedEnumName { values_span: Span, enum_name: String, },
     ReservedParameter,
+    UnknownReference { key: &'static str, reference_span: Span, },
+    SelfReference { key: &'static str, reference_span: Span, },
+    RequiredUnlessNotOptional { required_unless_span: Span, },
+    InvalidBound { bound_span: Span, },
+    EmptyRange { min_span: Span, max_span: Span, },
+    InvalidPattern { pattern_span: Span, error: String, },
+    ValueConstraintWithValues { constraint_span: Span, values_span: Span, },
+    InvalidLong { long_span: Span, },
+    InvalidEnvVarName { env_span: Span, },
+    EmptyEnvList { env_span: Span, },
+    EmptyFileKey { file_key_span: Span, },
+    EnvWithDisabledEnvVar { env_var_span: Span, env_span: Span, },
+    FileKeyInSubcommand { file_key_span: Span, },
+    InvalidRustCode { key: &'static str, code_span: Span, error: snippet::Error, },
+    NegatableCount { negatable_span: Span, count_span: Span, },
+    OptionalValueWithoutArgument { optional_value_span: Span, argument_span: Option<Span>, },
 }
 
 #[derive(Debug)]
@@ -25,10 +54,42 @@ impl ValidationError {
             InvalidField { kind: InvertedWithAbbr { abbr_span, default_span }, .. } => abbr_span.start.min(default_span.start),
             InvalidField { kind: InvertedWithCount { count_span, default_span }, .. } => count_span.start.min(default_span.start),
             InvalidField { kind: InvalidAbbr { abbr_span }, .. } => abbr_span.start,
+            InvalidField { kind: EnvVarInSubcommand { env_var_span }, .. } => env_var_span.start,
+            InvalidField { kind: MultiplePositionalNotLast { multiple_span }, .. } => multiple_span.start,
+            InvalidField { kind: MandatoryPositionalAfterOptional { optional_span }, .. } => optional_span.start,
+            InvalidField { kind: PositionalWithSubcommands, span, .. } => span.start,
+            InvalidField { kind: SeparatorWithoutMultiple { separator_span }, .. } => separator_span.start,
+            InvalidField { kind: MergeFnWithMultiple { merge_fn_span }, .. } => merge_fn_span.start,
+            InvalidField { kind: MissingType, span, .. } => span.start,
+            InvalidField { kind: TypeWithValues { type_span, values_span }, .. } => type_span.start.min(values_span.start),
+            InvalidField { kind: EmptyValues { values_span }, .. } => values_span.start,
+            InvalidField { kind: InvalidValue { value_span }, .. } => value_span.start,
+            InvalidField { kind: DuplicateValue { duplicate_span, .. }, .. } => duplicate_span.start,
+            InvalidField { kind: ReservedEnumName { values_span, .. }, .. } => values_span.start,
             InvalidField { kind: ReservedParameter, span, .. } => span.start,
+            InvalidField { kind: UnknownReference { reference_span, .. }, .. } => reference_span.start,
+            InvalidField { kind: SelfReference { reference_span, .. }, .. } => reference_span.start,
+            InvalidField { kind: RequiredUnlessNotOptional { required_unless_span }, .. } => required_unless_span.start,
+            InvalidField { kind: InvalidBound { bound_span }, .. } => bound_span.start,
+            InvalidField { kind: EmptyRange { min_span, max_span }, .. } => min_span.start.min(max_span.start),
+            InvalidField { kind: InvalidPattern { pattern_span, .. }, .. } => pattern_span.start,
+            InvalidField { kind: ValueConstraintWithValues { constraint_span, values_span }, .. } => constraint_span.start.min(values_span.start),
+            InvalidField { kind: InvalidLong { long_span }, .. } => long_span.start,
+            InvalidField { kind: InvalidEnvVarName { env_span }, .. } => env_span.start,
+            InvalidField { kind: EmptyEnvList { env_span }, .. } => env_span.start,
+            InvalidField { kind: EmptyFileKey { file_key_span }, .. } => file_key_span.start,
+            InvalidField { kind: EnvWithDisabledEnvVar { env_var_span, env_span }, .. } => env_var_span.start.min(env_span.start),
+            InvalidField { kind: FileKeyInSubcommand { file_key_span }, .. } => file_key_span.start,
+            InvalidField { kind: InvalidRustCode { code_span, .. }, .. } => code_span.start,
+            InvalidField { kind: NegatableCount { negatable_span, count_span }, .. } => negatable_span.start.min(count_span.start),
+            InvalidField { kind: OptionalValueWithoutArgument { optional_value_span, .. }, .. } => optional_value_span.start,
             Duplicates { duplicate_spans, .. } => duplicate_spans[0].start, // always non-empty
             InvalidIdentifier(error) => error.span().start,
             InvalidProgramName { span, .. } => span.start,
+            InvalidConfFormat { span, .. } => span.start,
+            EmptyConfFormatList { span } => span.start,
+            InvalidUnknownKeys { span, .. } => span.start,
+            InvalidConfDirExtension { span, .. } => span.start,
         }
     }
 }
@@ -39,7 +100,11 @@ enum ValidationErrorSource {
     InvalidField { name: String, span: Span, kind: FieldError },
     Duplicates { name: String, first_span: Span, duplicate_spans: Vec<Span> },
     InvalidIdentifier(ident::Error),
-    InvalidProgramName { input: String, span: Span }
+    InvalidProgramName { input: String, span: Span },
+    InvalidConfFormat { input: String, span: Span },
+    EmptyConfFormatList { span: Span },
+    InvalidUnknownKeys { input: String, span: Span },
+    InvalidConfDirExtension { input: String, span: Span },
 }
 
 impl From<ident::Error> for ValidationError {
@@ -61,7 +126,35 @@ impl fmt::Display for ValidationError {
                     InvertedWithAbbr { .. } => "inverted switch can't have a short option",
                     InvertedWithCount { .. } => "inverted switch can't be a count",
                     InvalidAbbr { .. } => "invalid short switch: must be [a-zA-Z]",
+                    EnvVarInSubcommand { .. } => "options of subcommands can't be read from environment variables",
+                    MultiplePositionalNotLast { .. } => "only the last positional argument can accept multiple values",
+                    MandatoryPositionalAfterOptional { .. } => "mandatory positional argument can't follow an optional one",
+                    PositionalWithSubcommands => "positional arguments can't be combined with subcommands",
+                    SeparatorWithoutMultiple { .. } => "env var separator is only allowed for parameters accepting multiple values",
+                    MergeFnWithMultiple { .. } => "merge_fn can't be used with parameters accepting multiple values",
+                    MissingType => "either type or values must be specified",
+                    TypeWithValues { .. } => "type and values can't be specified at the same time",
+                    EmptyValues { .. } => "the list of values is empty",
+                    InvalidValue { .. } => "invalid value: must start with [a-z] followed by [a-z0-9_-]",
+                    DuplicateValue { .. } => "the same value is specified multiple times",
+                    ReservedEnumName { .. } => "the name of the generated enum collides with another generated type",
                     ReservedParameter => "this parameter is reserved and always implemented by configure_me",
+                    UnknownReference { .. } => "refers to a parameter or switch that doesn't exist",
+                    SelfReference { .. } => "a parameter or switch can't refer to itself",
+                    RequiredUnlessNotOptional { .. } => "required_unless is only allowed for optional parameters",
+                    InvalidBound { .. } => "min and max must be numbers",
+                    EmptyRange { .. } => "the minimum is greater than the maximum",
+                    InvalidPattern { .. } => "invalid regular expression in pattern",
+                    ValueConstraintWithValues { .. } => "value constraints can't be combined with values",
+                    InvalidLong { .. } => "invalid long option: must be [a-zA-Z0-9_-] and not start with a dash",
+                    InvalidEnvVarName { .. } => "invalid env var name: must be [a-zA-Z0-9_] and not start with a digit",
+                    EmptyEnvList { .. } => "the list of env var names is empty",
+                    EmptyFileKey { .. } => "the key in config files is empty",
+                    EnvWithDisabledEnvVar { .. } => "env var names are specified but env vars are disabled",
+                    FileKeyInSubcommand { .. } => "options of subcommands can't be read from config files",
+                    NegatableCount { .. } => "counting switch can't be negatable",
+                    OptionalValueWithoutArgument { .. } => "optional value is only allowed for parameters passed as arguments",
+                    InvalidRustCode { key, error, .. } => return write!(f, "invalid configuration for field {}: invalid Rust code in {}: {}", name, key, error),
                 };
                 write!(f, "invalid configuration for field {}: {}", name, msg)
             },
@@ -69,6 +162,10 @@ impl fmt::Display for ValidationError {
             Duplicates { name, duplicate_spans, .. } => write!(f, "the option {} occurs {} times", name, duplicate_spans.len() + 1),
             InvalidIdentifier(error) => fmt::Display::fmt(error, f),
             InvalidProgramName { input, .. } => write!(f, "the string `{}` is not a valid program name handling strategy", input),
+            InvalidConfFormat { input, .. } => write!(f, "the string `{}` is not a valid config file format", input),
+            EmptyConfFormatList { .. } => write!(f, "the list of config file formats is empty"),
+            InvalidUnknownKeys { input, .. } => write!(f, "the string `{}` is not a valid way of handling unknown keys", input),
+            InvalidConfDirExtension { input, .. } => write!(f, "the string `{}` is not a valid extension of files in the config directory", input),
         }
     }
 }
@@ -129,6 +226,172 @@ impl ValidationError {
                                 "Note: only English letters (both lower case and upper case) are allowed".to_owned()
                             ])
                     },
+                    EnvVarInSubcommand { env_var_span } => {
+                        diagnostic
+                            .with_message("an option of a subcommand attempts to be read from an environment variable")
+                            .with_labels(vec![
+                                 Label::primary(file_id, *env_var_span).with_message("environment variable enabled here"),
+                                 Label::secondary(file_id, *span).with_message(format!("in the parameter `{}`", name)),
+                            ])
+                            .with_notes(vec![
+                                "Help: options of subcommands can only be passed as command-line arguments, move the option to the top level if it should be configurable".to_owned()
+                            ])
+                    },
+                    NegatableCount { negatable_span, count_span } => {
+                        diagnostic.with_message("counting switch attempts to be negatable")
+                            .with_labels(vec![
+                                 Label::primary(file_id, *negatable_span).with_message("the switch is made negatable here"),
+                                 Label::primary(file_id, *count_span).with_message("but it counts the occurrences here"),
+                                 Label::secondary(file_id, *span).with_message(format!("in the parameter `{}`", name)),
+                            ])
+                            .with_notes(vec![
+                                "Help: `--no-` can only reset switches that are either on or off, remove `negatable` or `count`".to_owned()
+                            ])
+                    },
+                    OptionalValueWithoutArgument { optional_value_span, argument_span } => {
+                        let mut labels = vec![
+                            Label::primary(file_id, *optional_value_span).with_message("the optional value is specified here"),
+                            Label::secondary(file_id, *span).with_message(format!("but the parameter `{}` can't be passed as an argument", name)),
+                        ];
+                        if let Some(argument_span) = argument_span {
+                            labels.push(Label::secondary(file_id, *argument_span).with_message("arguments are disabled here"));
+                        }
+                        diagnostic
+                            .with_message("optional value of a parameter that isn't an argument")
+                            .with_labels(labels)
+                            .with_notes(vec![
+                                "Help: remove `optional_value` or `argument = false`".to_owned()
+                            ])
+                    },
+                    InvalidRustCode { key, code_span, error } => {
+                        diagnostic
+                            .with_message(format!("invalid Rust code in `{}`", key))
+                            .with_labels(vec![
+                                 Label::primary(file_id, *code_span).with_message(error.to_string()),
+                                 Label::secondary(file_id, *span).with_message(format!("in the parameter `{}`", name)),
+                            ])
+                            .with_notes(vec![
+                                "Note: the code is copied into the generated file verbatim so it has to be valid Rust".to_owned()
+                            ])
+                    },
+                    MultiplePositionalNotLast { multiple_span } => {
+                        diagnostic
+                            .with_message("a positional argument follows a positional argument accepting multiple values")
+                            .with_labels(vec![
+                                 Label::primary(file_id, *multiple_span).with_message("this positional argument accepts multiple values"),
+                                 Label::secondary(file_id, *span).with_message(format!("so the positional argument `{}` would never get a value", name)),
+                            ])
+                            .with_notes(vec![
+                                "Help: move the positional argument accepting multiple values to the end".to_owned()
+                            ])
+                    },
+                    MandatoryPositionalAfterOptional { optional_span } => {
+                        diagnostic
+                            .with_message("a mandatory positional argument follows an optional one")
+                            .with_labels(vec![
+                                 Label::primary(file_id, *optional_span).with_message("this positional argument is optional"),
+                                 Label::secondary(file_id, *span).with_message(format!("but the positional argument `{}` is mandatory", name)),
+                            ])
+                            .with_notes(vec![
+                                "Help: declare all mandatory positional arguments before optional ones".to_owned()
+                            ])
+                    },
+                    PositionalWithSubcommands => {
+                        diagnostic
+                            .with_message("positional arguments are combined with subcommands")
+                            .with_labels(vec![
+                                 Label::primary(file_id, *span).with_message("positional argument declared here"),
+                            ])
+                            .with_notes(vec![
+                                "Help: the first positional argument selects the subcommand, remove either positional arguments or subcommands".to_owned()
+                            ])
+                    },
+                    SeparatorWithoutMultiple { separator_span } => {
+                        diagnostic
+                            .with_message("a single-valued parameter has an env var separator")
+                            .with_labels(vec![
+                                 Label::primary(file_id, *separator_span).with_message("separator defined here"),
+                                 Label::secondary(file_id, *span).with_message(format!("in the parameter `{}`", name)),
+                            ])
+                            .with_notes(vec![
+                                "Help: either remove the separator or set `multiple = true`".to_owned()
+                            ])
+                    },
+                    MergeFnWithMultiple { merge_fn_span } => {
+                        diagnostic
+                            .with_message("a parameter accepting multiple values has a merge function")
+                            .with_labels(vec![
+                                 Label::primary(file_id, *merge_fn_span).with_message("merge function defined here"),
+                                 Label::secondary(file_id, *span).with_message(format!("in the parameter `{}`", name)),
+                            ])
+                            .with_notes(vec![
+                                "Help: values of such parameters are always collected, remove either `merge_fn` or `multiple`".to_owned()
+                            ])
+                    },
+                    MissingType => {
+                        diagnostic
+                            .with_message("the type of the parameter is unknown")
+                            .with_labels(vec![
+                                 Label::primary(file_id, *span).with_message(format!("the parameter `{}` has neither `type` nor `values`", name)),
+                            ])
+                            .with_notes(vec![
+                                "Help: add `type` with the Rust type of the parameter or `values` with the list of allowed values".to_owned()
+                            ])
+                    },
+                    TypeWithValues { type_span, values_span } => {
+                        diagnostic
+                            .with_message("parameter has both a type and a list of values")
+                            .with_labels(vec![
+                                 Label::primary(file_id, *type_span).with_message("the type is specified here"),
+                                 Label::primary(file_id, *values_span).with_message("the values are specified here"),
+                                 Label::secondary(file_id, *span).with_message(format!("in the parameter `{}`", name)),
+                            ])
+                            .with_notes(vec![
+                                "Help: remove the type, an enum is generated from the values automatically".to_owned()
+                            ])
+                    },
+                    EmptyValues { values_span } => {
+                        diagnostic
+                            .with_message("empty list of allowed values")
+                            .with_labels(vec![
+                                 Label::primary(file_id, *values_span).with_message("no value is allowed here"),
+                                 Label::secondary(file_id, *span).with_message(format!("in the parameter `{}`", name)),
+                            ])
+                    },
+                    InvalidValue { value_span } => {
+                        diagnostic
+                            .with_message("invalid value")
+                            .with_labels(vec![
+                                 Label::primary(file_id, *value_span).with_message("this value can't be converted to an enum variant"),
+                                 Label::secondary(file_id, *span).with_message(format!("in the parameter `{}`", name)),
+                            ])
+                            .with_notes(vec![
+                                "Note: values must start with a lower case English letter followed by lower case English letters, digits, underscores or dashes".to_owned()
+                            ])
+                    },
+                    DuplicateValue { first_span, duplicate_span } => {
+                        diagnostic
+                            .with_message("duplicate value")
+                            .with_labels(vec![
+                                 Label::secondary(file_id, *first_span).with_message("the value was first defined here"),
+                                 Label::primary(file_id, *duplicate_span).with_message("the value is repeated here"),
+                                 Label::secondary(file_id, *span).with_message(format!("in the parameter `{}`", name)),
+                            ])
+                            .with_notes(vec![
+                                "Note: dashes and underscores are considered equal because they map to the same enum variant".to_owned()
+                            ])
+                    },
+                    ReservedEnumName { values_span, enum_name } => {
+                        diagnostic
+                            .with_message(format!("the generated enum `{}` collides with another generated type", enum_name))
+                            .with_labels(vec![
+                                 Label::primary(file_id, *values_span).with_message("the enum is generated because of these values"),
+                                 Label::secondary(file_id, *span).with_message(format!("the enum is named after the parameter `{}`", name)),
+                            ])
+                            .with_notes(vec![
+                                "Help: rename the parameter".to_owned()
+                            ])
+                    },
                     ReservedParameter => {
                         diagnostic
                             .with_message("use of reserved option")
@@ -136,6 +399,145 @@ impl ValidationError {
                                  Label::primary(file_id, *span).with_message("this option is reserved because it's always implemented by `configure_me`"),
                             ])
                     },
+                    UnknownReference { key, reference_span } => {
+                        diagnostic
+                            .with_message("reference to an unknown parameter or switch")
+                            .with_labels(vec![
+                                 Label::primary(file_id, *reference_span).with_message(format!("this name is used in `{}` but there's no such parameter or switch", key)),
+                                 Label::secondary(file_id, *span).with_message(format!("in the parameter `{}`", name)),
+                            ])
+                            .with_notes(vec![
+                                "Note: only parameters and switches declared in the same section or subcommand can be referenced".to_owned()
+                            ])
+                    },
+                    SelfReference { key, reference_span } => {
+                        diagnostic
+                            .with_message("parameter refers to itself")
+                            .with_labels(vec![
+                                 Label::primary(file_id, *reference_span).with_message(format!("the parameter `{}` is referenced in its own `{}`", name, key)),
+                            ])
+                            .with_notes(vec![
+                                "Help: remove the name from the list".to_owned()
+                            ])
+                    },
+                    RequiredUnlessNotOptional { required_unless_span } => {
+                        diagnostic
+                            .with_message("`required_unless` is used with a parameter that is never missing")
+                            .with_labels(vec![
+                                 Label::primary(file_id, *required_unless_span).with_message("`required_unless` is specified here"),
+                                 Label::secondary(file_id, *span).with_message(format!("but the parameter `{}` is either mandatory or has a default value", name)),
+                            ])
+                            .with_notes(vec![
+                                "Help: make the parameter optional without a default value".to_owned()
+                            ])
+                    },
+                    InvalidBound { bound_span } => {
+                        diagnostic
+                            .with_message("invalid bound")
+                            .with_labels(vec![
+                                 Label::primary(file_id, *bound_span).with_message("this is not a number"),
+                                 Label::secondary(file_id, *span).with_message(format!("in the parameter `{}`", name)),
+                            ])
+                            .with_notes(vec![
+                                "Note: `min` and `max` must be integers or floats and are compared with the parsed value".to_owned()
+                            ])
+                    },
+                    EmptyRange { min_span, max_span } => {
+                        diagnostic
+                            .with_message("no value satisfies the constraints")
+                            .with_labels(vec![
+                                 Label::primary(file_id, *min_span).with_message("the minimum is specified here"),
+                                 Label::primary(file_id, *max_span).with_message("the maximum is specified here and it's lower than the minimum"),
+                                 Label::secondary(file_id, *span).with_message(format!("in the parameter `{}`", name)),
+                            ])
+                    },
+                    InvalidPattern { pattern_span, error } => {
+                        diagnostic
+                            .with_message("invalid regular expression")
+                            .with_labels(vec![
+                                 Label::primary(file_id, *pattern_span).with_message("this pattern can't be compiled"),
+                                 Label::secondary(file_id, *span).with_message(format!("in the parameter `{}`", name)),
+                            ])
+                            .with_notes(vec![
+                                error.clone()
+                            ])
+                    },
+                    ValueConstraintWithValues { constraint_span, values_span } => {
+                        diagnostic
+                            .with_message("value constraint used with a list of values")
+                            .with_labels(vec![
+                                 Label::primary(file_id, *constraint_span).with_message("the constraint is specified here"),
+                                 Label::primary(file_id, *values_span).with_message("but only these values are allowed"),
+                                 Label::secondary(file_id, *span).with_message(format!("in the parameter `{}`", name)),
+                            ])
+                            .with_notes(vec![
+                                "Help: remove the constraint, the values are checked already".to_owned()
+                            ])
+                    },
+                    InvalidLong { long_span } => {
+                        diagnostic
+                            .with_message("invalid long option")
+                            .with_labels(vec![
+                                 Label::primary(file_id, *long_span).with_message("this option can't be used as a command-line argument"),
+                                 Label::secondary(file_id, *span).with_message(format!("in the parameter `{}`", name)),
+                            ])
+                            .with_notes(vec![
+                                "Note: write the option without the leading dashes, only English letters, digits, dashes and underscores are allowed".to_owned()
+                            ])
+                    },
+                    InvalidEnvVarName { env_span } => {
+                        diagnostic
+                            .with_message("invalid environment variable name")
+                            .with_labels(vec![
+                                 Label::primary(file_id, *env_span).with_message("this name uses an invalid character"),
+                                 Label::secondary(file_id, *span).with_message(format!("in the parameter `{}`", name)),
+                            ])
+                            .with_notes(vec![
+                                "Note: only English letters, digits and underscores are allowed and the name must not start with a digit".to_owned()
+                            ])
+                    },
+                    EmptyEnvList { env_span } => {
+                        diagnostic
+                            .with_message("empty list of environment variables")
+                            .with_labels(vec![
+                                 Label::primary(file_id, *env_span).with_message("no names are listed here"),
+                                 Label::secondary(file_id, *span).with_message(format!("in the parameter `{}`", name)),
+                            ])
+                            .with_notes(vec![
+                                "Help: to disable environment variables use `env_var = false`".to_owned()
+                            ])
+                    },
+                    EmptyFileKey { file_key_span } => {
+                        diagnostic
+                            .with_message("empty key in config files")
+                            .with_labels(vec![
+                                 Label::primary(file_id, *file_key_span).with_message("the key is empty"),
+                                 Label::secondary(file_id, *span).with_message(format!("in the parameter `{}`", name)),
+                            ])
+                    },
+                    EnvWithDisabledEnvVar { env_var_span, env_span } => {
+                        diagnostic
+                            .with_message("environment variables are specified for an option that can't be read from them")
+                            .with_labels(vec![
+                                 Label::primary(file_id, *env_var_span).with_message("environment variables are disabled here"),
+                                 Label::primary(file_id, *env_span).with_message("but their names are specified here"),
+                                 Label::secondary(file_id, *span).with_message(format!("in the parameter `{}`", name)),
+                            ])
+                            .with_notes(vec![
+                                "Help: remove `env_var = false`, specifying `env` enables environment variables".to_owned()
+                            ])
+                    },
+                    FileKeyInSubcommand { file_key_span } => {
+                        diagnostic
+                            .with_message("an option of a subcommand attempts to be read from config files")
+                            .with_labels(vec![
+                                 Label::primary(file_id, *file_key_span).with_message("the key in config files is specified here"),
+                                 Label::secondary(file_id, *span).with_message(format!("in the parameter `{}`", name)),
+                            ])
+                            .with_notes(vec![
+                                "Help: options of subcommands can only be passed as command-line arguments, move the option to the top level if it should be configurable".to_owned()
+                            ])
+                    },
                 }
             },
             ValidationErrorSource::Duplicates { first_span, duplicate_spans, name } => {
@@ -158,6 +560,118 @@ impl ValidationError {
                         "Help: valid program name handling strategies are `unused`, `optional`, `required`.".to_owned()
                     ])
             },
+            ValidationErrorSource::InvalidConfFormat { input, span } => {
+                let labels = vec![Label::primary(file_id, *span).with_message("this is an invalid config file format")];
+                diagnostic.with_message(format!("`{}` is not a valid config file format", input))
+                    .with_labels(labels)
+                    .with_notes(vec![
+                        "Help: valid config file formats are `toml`, `json`, `yaml`, `kv`, `ini`.".to_owned()
+                    ])
+            },
+            ValidationErrorSource::EmptyConfFormatList { span } => {
+                let labels = vec![Label::primary(file_id, *span).with_message("no format is listed here")];
+                diagnostic.with_message("the list of config file formats is empty")
+                    .with_labels(labels)
+            },
+            ValidationErrorSource::InvalidUnknownKeys { input, span } => {
+                let labels = vec![Label::primary(file_id, *span).with_message("this is an invalid way of handling unknown keys")];
+                diagnostic.with_message(format!("`{}` is not a valid way of handling unknown keys", input))
+                    .with_labels(labels)
+                    .with_notes(vec![
+                        "Help: unknown keys can be handled using `ignore`, `warn`, `error`.".to_owned()
+                    ])
+            },
+            ValidationErrorSource::InvalidConfDirExtension { input, span } => {
+                let labels = vec![Label::primary(file_id, *span).with_message("this is an invalid extension")];
+                diagnostic.with_message(format!("`{}` is not a valid extension of files in the config directory", input))
+                    .with_labels(labels)
+                    .with_notes(vec![
+                        "Help: the extension must not be empty nor contain a path separator, e.g. `conf`.".to_owned()
+                    ])
+            },
+        }
+    }
+}
+
+/// A problem in the specification that doesn't prevent generating the code.
+#[derive(Debug)]
+pub struct Warning {
+    name: String,
+    span: Span,
+    kind: WarningKind,
+}
+
+#[cfg_attr(not(feature = "spanned"), allow(unused))]
+#[derive(Debug)]
+enum WarningKind {
+    MissingDoc,
+    AbbrWithoutArgument { abbr_span: Span, argument_span: Option<Span> },
+    EnvVarDiffersInCase { var: String, var_span: Span, other: String, other_span: Span },
+}
+
+impl Warning {
+    fn sort_key(&self) -> usize {
+        match &self.kind {
+            WarningKind::MissingDoc => self.span.start,
+            WarningKind::AbbrWithoutArgument { abbr_span, .. } => abbr_span.start,
+            WarningKind::EnvVarDiffersInCase { other_span, .. } => other_span.start,
+        }
+    }
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            WarningKind::MissingDoc => write!(f, "the option {} is not documented", self.name),
+            WarningKind::AbbrWithoutArgument { .. } => write!(f, "the short option of {} is unused because it can't be passed as an argument", self.name),
+            WarningKind::EnvVarDiffersInCase { var, other, .. } => write!(f, "the environment variables {} and {} only differ in case", var, other),
+        }
+    }
+}
+
+#[cfg(feature = "spanned")]
+impl Warning {
+    pub fn to_diagnostic<T: Copy>(&self, file_id: T) -> codespan_reporting::diagnostic::Diagnostic<T> {
+        use codespan_reporting::diagnostic::Label;
+
+        let diagnostic = codespan_reporting::diagnostic::Diagnostic::warning();
+        match &self.kind {
+            WarningKind::MissingDoc => {
+                diagnostic
+                    .with_message(format!("missing documentation of `{}`", self.name))
+                    .with_labels(vec![
+                        Label::primary(file_id, self.span).with_message("this option has no `doc`"),
+                    ])
+                    .with_notes(vec![
+                        "Note: the documentation is shown in the help and the man page".to_owned()
+                    ])
+            },
+            WarningKind::AbbrWithoutArgument { abbr_span, argument_span } => {
+                let mut labels = vec![
+                    Label::primary(file_id, *abbr_span).with_message("this short option is never used"),
+                    Label::secondary(file_id, self.span).with_message(format!("in the parameter `{}`", self.name)),
+                ];
+                if let Some(argument_span) = argument_span {
+                    labels.push(Label::secondary(file_id, *argument_span).with_message("because the parameter can't be passed as an argument"));
+                }
+                diagnostic
+                    .with_message("short option of a parameter that isn't an argument")
+                    .with_labels(labels)
+                    .with_notes(vec![
+                        "Help: remove `abbr`".to_owned()
+                    ])
+            },
+            WarningKind::EnvVarDiffersInCase { var, var_span, other, other_span } => {
+                diagnostic
+                    .with_message("environment variables differing only in case")
+                    .with_labels(vec![
+                        Label::primary(file_id, *other_span).with_message(format!("`{}` only differs in case from `{}`", other, var)),
+                        Label::secondary(file_id, *var_span).with_message(format!("`{}` is used here", var)),
+                    ])
+                    .with_notes(vec![
+                        "Note: the names are easy to confuse and they refer to the same variable on Windows".to_owned()
+                    ])
+            },
         }
     }
 }
@@ -240,6 +754,11 @@ mod ident {
             &self.0
         }
 
+        /// Creates the identifier `prefix_self`, which is always valid.
+        pub(crate) fn prefixed(&self, prefix: &Ident) -> Ident {
+            Ident(format!("{}_{}", prefix.0, self.0))
+        }
+
         pub(crate) fn as_upper_case(&self) -> UpperCase<'_> {
             UpperCase(&self.0)
         }
@@ -281,6 +800,12 @@ mod ident {
 
     pub(crate) struct PascalCase<'a>(&'a str);
 
+    /// Converts an arbitrary string to pascal case treating both underscores and dashes as word
+    /// boundaries.
+    pub(crate) fn pascal_case(string: &str) -> String {
+        string.split(['_', '-']).map(|word| PascalCase(word).to_string()).collect()
+    }
+
     impl<'a> fmt::Display for PascalCase<'a> {
         fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
             let mut next_big = true;
@@ -395,27 +920,222 @@ mod ident {
     }
 }
 
-use self::ident::Ident;
+pub(crate) use self::ident::Ident;
+pub(crate) use self::ident::pascal_case;
 
-#[derive(Debug, Copy, Clone)]
-pub struct Span {
-    start: usize,
-    end: usize,
-}
+/// Checks of Rust code copied from the specification into the generated file.
+///
+/// The code isn't parsed, it's only tokenized far enough to catch typos that rustc would otherwise
+/// report inside `OUT_DIR` instead of the specification.
+mod snippet {
+    use std::fmt;
 
-impl From<Span> for core::ops::Range<usize> {
-    fn from(value: Span) -> Self {
-        value.start..value.end
+    #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+    pub enum Kind {
+        Type,
+        Expression,
     }
-}
 
-pub mod raw {
-    use toml::Spanned;
-    use std::convert::TryFrom;
-    use std::fmt;
-    use super::{ValidationError, FieldError, ValidationErrorSource, Optionality, SwitchKind};
-    use super::ident::Ident;
+    #[derive(Debug, Eq, PartialEq)]
+    pub enum Error {
+        Empty,
+        Unclosed(char),
+        UnexpectedClose(char),
+        Mismatched { open: char, close: char },
+        UnterminatedLiteral,
+        DanglingPathSeparator,
+        SingleColon,
+        UnexpectedChar(char),
+    }
+
+    impl fmt::Display for Error {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                Error::Empty => write!(f, "the code is empty"),
+                Error::Unclosed(open) => write!(f, "`{}` is never closed", open),
+                Error::UnexpectedClose(close) => write!(f, "`{}` doesn't close anything", close),
+                Error::Mismatched { open, close } => write!(f, "`{}` is closed by `{}`", open, close),
+                Error::UnterminatedLiteral => write!(f, "unterminated string or character literal"),
+                Error::DanglingPathSeparator => write!(f, "`::` is not followed by a name"),
+                Error::SingleColon => write!(f, "single `:` in a path, did you mean `::`?"),
+                Error::UnexpectedChar(c) => write!(f, "`{}` can't appear in a type", c),
+            }
+        }
+    }
+
+    fn is_ident_start(c: char) -> bool {
+        c.is_alphabetic() || c == '_'
+    }
+
+    fn is_ident_continue(c: char) -> bool {
+        c.is_alphanumeric() || c == '_'
+    }
+
+    fn closing(open: char) -> char {
+        match open {
+            '(' => ')',
+            '[' => ']',
+            '{' => '}',
+            '<' => '>',
+            _ => unreachable!("not an opening delimiter"),
+        }
+    }
+
+    fn close(delimiters: &mut Vec<(char, Kind)>, close: char) -> Result<(), Error> {
+        match delimiters.pop() {
+            Some((open, _)) if closing(open) == close => Ok(()),
+            Some((open, _)) => Err(Error::Mismatched { open, close }),
+            None => Err(Error::UnexpectedClose(close)),
+        }
+    }
+
+    /// Skips a string literal, `pos` points right after the opening quote.
+    fn skip_string(chars: &[char], mut pos: usize) -> Result<usize, Error> {
+        while pos < chars.len() {
+            match chars[pos] {
+                '\\' => pos += 2,
+                '"' => return Ok(pos + 1),
+                _ => pos += 1,
+            }
+        }
+        Err(Error::UnterminatedLiteral)
+    }
+
+    /// Skips a raw string literal or a raw identifier, `pos` points right after `r`.
+    fn skip_raw(chars: &[char], mut pos: usize) -> Result<usize, Error> {
+        let hashes = chars[pos..].iter().take_while(|c| **c == '#').count();
+        pos += hashes;
+        match chars.get(pos) {
+            Some('"') => {
+                let closing = chars[(pos + 1)..]
+                    .windows(hashes + 1)
+                    .position(|window| window[0] == '"' && window[1..].iter().all(|c| *c == '#'))
+                    .ok_or(Error::UnterminatedLiteral)?;
+                Ok(pos + 1 + closing + hashes + 1)
+            },
+            Some(c) if hashes == 1 && is_ident_start(*c) => Ok(pos + chars[pos..].iter().take_while(|c| is_ident_continue(**c)).count()),
+            Some(c) => Err(Error::UnexpectedChar(*c)),
+            None => Err(Error::UnterminatedLiteral),
+        }
+    }
+
+    /// Checks that delimiters are balanced, literals are terminated and paths are well-formed.
+    pub fn check(code: &str, kind: Kind) -> Result<(), Error> {
+        if code.trim().is_empty() {
+            return Err(Error::Empty);
+        }
+
+        let chars = code.chars().collect::<Vec<_>>();
+        // Each delimiter remembers what's inside it because types contain expressions too, e.g.
+        // the length of an array or a const generic argument in braces
+        let mut delimiters = Vec::<(char, Kind)>::new();
+        let mut after_path_separator = false;
+        let mut pos = 0;
+        while pos < chars.len() {
+            let c = chars[pos];
+            let kind = delimiters.last().map_or(kind, |(_, kind)| *kind);
+            if c.is_whitespace() {
+                pos += 1;
+                continue;
+            }
+            if after_path_separator && !is_ident_start(c) && c != '<' {
+                return Err(Error::DanglingPathSeparator);
+            }
+            after_path_separator = false;
+
+            match c {
+                c if is_ident_start(c) => {
+                    let len = chars[pos..].iter().take_while(|c| is_ident_continue(**c)).count();
+                    let ident = &chars[pos..(pos + len)];
+                    pos += len;
+                    let raw = ident == ['r'] || ident == ['b', 'r'];
+                    // Raw identifiers like `r#type` can appear in types too
+                    if raw && (chars.get(pos) == Some(&'#') || (kind == Kind::Expression && chars.get(pos) == Some(&'"'))) {
+                        pos = skip_raw(&chars, pos)?;
+                    }
+                },
+                '0'..='9' => pos += chars[pos..].iter().take_while(|c| is_ident_continue(**c)).count(),
+                '"' if kind == Kind::Expression => pos = skip_string(&chars, pos + 1)?,
+                '\'' => {
+                    match (chars.get(pos + 1), chars.get(pos + 2)) {
+                        (Some('\\'), _) if kind == Kind::Expression => {
+                            let len = chars[(pos + 3)..].iter().position(|c| *c == '\'').ok_or(Error::UnterminatedLiteral)?;
+                            pos += len + 4;
+                        },
+                        (Some(_), Some('\'')) if kind == Kind::Expression => pos += 3,
+                        (Some(c), _) if is_ident_start(*c) => pos += 1,
+                        _ if kind == Kind::Expression => return Err(Error::UnterminatedLiteral),
+                        _ => return Err(Error::UnexpectedChar('\'')),
+                    }
+                },
+                ':' if chars.get(pos + 1) == Some(&':') => {
+                    after_path_separator = true;
+                    pos += 2;
+                },
+                ':' if kind == Kind::Type => return Err(Error::SingleColon),
+                '(' | '[' => {
+                    delimiters.push((c, kind));
+                    pos += 1;
+                },
+                '{' => {
+                    delimiters.push((c, Kind::Expression));
+                    pos += 1;
+                },
+                // The length of an array type is an expression
+                ';' if kind == Kind::Type && delimiters.last() == Some(&('[', Kind::Type)) => {
+                    delimiters.last_mut().expect("checked above").1 = Kind::Expression;
+                    pos += 1;
+                },
+                '<' if kind == Kind::Type => {
+                    delimiters.push((c, kind));
+                    pos += 1;
+                },
+                ')' | ']' | '}' => {
+                    close(&mut delimiters, c)?;
+                    pos += 1;
+                },
+                '>' if kind == Kind::Type => {
+                    close(&mut delimiters, c)?;
+                    pos += 1;
+                },
+                '-' if kind == Kind::Type && chars.get(pos + 1) == Some(&'>') => pos += 2,
+                ',' | ';' | '&' | '*' | '+' | '-' | '=' | '!' | '?' if kind == Kind::Type => pos += 1,
+                _ if kind == Kind::Type => return Err(Error::UnexpectedChar(c)),
+                _ => pos += 1,
+            }
+        }
+
+        if after_path_separator {
+            return Err(Error::DanglingPathSeparator);
+        }
+        match delimiters.pop() {
+            Some((open, _)) => Err(Error::Unclosed(open)),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Span {
+    start: usize,
+    end: usize,
+}
+
+impl From<Span> for core::ops::Range<usize> {
+    fn from(value: Span) -> Self {
+        value.start..value.end
+    }
+}
+
+pub mod raw {
+    use toml::Spanned;
+    use std::convert::TryFrom;
+    use std::fmt;
+    use super::{ValidationError, FieldError, ValidationErrorSource, Optionality, SwitchKind};
+    use super::ident::Ident;
     use super::Span;
+    use std::collections::{HashMap, HashSet};
+    use std::collections::hash_map::Entry;
 
     #[derive(Debug)]
     struct Kv<V>(Spanned<String>, V);
@@ -492,32 +1212,47 @@ pub mod raw {
         // just for checking, order/determinism doesn't matter
         map: std::collections::HashMap<T, Option<Span>>,
         dup_cache: std::collections::HashMap<T, (Span, Vec<Span>)>,
+        // derived names only collide with the inserted ones, their own collisions are reported
+        // by another validator
+        derived: Vec<(T, Span)>,
     }
 
     impl<T: Eq + std::hash::Hash + Clone> ArgValidator<T> {
-        fn with_reserved(arg: T) -> Self {
-            let mut map = std::collections::HashMap::new();
-            map.insert(arg, None);
-
+        fn new() -> Self {
             ArgValidator {
-                map,
+                map: Default::default(),
                 dup_cache: Default::default(),
+                derived: Vec::new(),
             }
         }
 
+        fn with_reserved(arg: T) -> Self {
+            let mut validator = Self::new();
+            validator.map.insert(arg, None);
+            validator
+        }
+
+        fn insert_derived(&mut self, arg: T, arg_span: Span) {
+            self.derived.push((arg, arg_span));
+        }
+
         fn check_insert(&mut self, arg: &Spanned<T>) -> Result<(), FieldError> {
+            self.check_insert_at(arg.get_ref().clone(), arg.to_span())
+        }
+
+        fn check_insert_at(&mut self, arg: T, arg_span: Span) -> Result<(), FieldError> {
             use std::collections::hash_map::Entry;
 
-            match self.map.entry(arg.get_ref().clone()) {
+            match self.map.entry(arg.clone()) {
                 Entry::Vacant(entry) => {
-                    entry.insert(Some(Span::from(arg)));
+                    entry.insert(Some(arg_span));
                     Ok(())
                 },
                 Entry::Occupied(entry) => {
                     match entry.get() {
                         Some(span) => {
-                            let dups = self.dup_cache.entry(arg.get_ref().clone()).or_insert_with(|| (*span, Vec::new()));
-                            dups.1.push(arg.to_span());
+                            let dups = self.dup_cache.entry(arg).or_insert_with(|| (*span, Vec::new()));
+                            dups.1.push(arg_span);
                             Ok(())
                         },
                         None => Err(FieldError::ReservedParameter)
@@ -526,7 +1261,12 @@ pub mod raw {
             }
         }
 
-        fn into_duplicates<S: 'static + FnMut(T) -> String>(self, mut stringify: S) -> impl Iterator<Item=ValidationError> {
+        fn into_duplicates<S: 'static + FnMut(T) -> String>(mut self, mut stringify: S) -> impl Iterator<Item=ValidationError> {
+            for (arg, arg_span) in self.derived {
+                if let Some(Some(span)) = self.map.get(&arg) {
+                    self.dup_cache.entry(arg).or_insert_with(|| (*span, Vec::new())).1.push(arg_span);
+                }
+            }
             self.dup_cache.into_iter().map(move |(name, (first_span, duplicate_spans))| {
                 ValidationError {
                     source: ValidationErrorSource::Duplicates {
@@ -544,6 +1284,45 @@ pub mod raw {
             self.check_insert(arg).field_name(arg)
         }
 
+        /// Checks the long argument of a param or switch which is prefixed with the section name.
+        fn check_insert_scoped_long(&mut self, scope: Scope, arg: &Spanned<String>) -> Result<(), ValidationError> {
+            match scope {
+                Scope::Section(section) => self.check_insert_at(format!("{}_{}", section.as_snake_case(), arg.get_ref()), arg.to_span()).field_name(arg),
+                Scope::TopLevel | Scope::Subcommand(_) => self.check_insert_long(arg),
+            }
+        }
+
+        /// Checks the long argument of a param or switch, which is either overriden by `long` or
+        /// derived from the name.
+        ///
+        /// Dashes and underscores are considered equal.
+        fn check_insert_param_long(&mut self, scope: Scope, arg: &Spanned<String>, long: &Option<Spanned<String>>) -> Result<(), ValidationError> {
+            match long {
+                Some(long) => self.check_insert_at(long.get_ref().replace('-', "_"), long.to_span()).field_name(arg),
+                None => self.check_insert_scoped_long(scope, arg),
+            }
+        }
+
+        /// Checks the `--<name>-file` argument generated for a secret param.
+        fn check_insert_secret_file(&mut self, scope: Scope, arg: &Spanned<String>, long: &Option<Spanned<String>>) -> Result<(), ValidationError> {
+            let name = match (long, scope) {
+                (Some(long), _) => format!("{}_file", long.get_ref().replace('-', "_")),
+                (None, Scope::Section(section)) => format!("{}_{}_file", section.as_snake_case(), arg.get_ref()),
+                (None, Scope::TopLevel) | (None, Scope::Subcommand(_)) => format!("{}_file", arg.get_ref()),
+            };
+            self.check_insert_at(name, arg.to_span()).field_name(arg)
+        }
+
+        /// Checks the `--no-<name>` argument accepted by a negatable switch.
+        fn check_insert_negated(&mut self, scope: Scope, arg: &Spanned<String>, long: &Option<Spanned<String>>) -> Result<(), ValidationError> {
+            let name = match (long, scope) {
+                (Some(long), _) => format!("no_{}", long.get_ref().replace('-', "_")),
+                (None, Scope::Section(section)) => format!("no_{}_{}", section.as_snake_case(), arg.get_ref()),
+                (None, Scope::TopLevel) | (None, Scope::Subcommand(_)) => format!("no_{}", arg.get_ref()),
+            };
+            self.check_insert_at(name, arg.to_span()).field_name(arg)
+        }
+
         fn check_insert_opt_long(&mut self, arg: &Option<Spanned<String>>) -> Result<(), ValidationError> {
             if let Some(arg) = arg {
                 self.check_insert_long(arg)
@@ -553,6 +1332,44 @@ pub mod raw {
         }
     }
 
+    /// Checks that the env vars of params and switches don't collide.
+    ///
+    /// Names derived from the names of params and switches can only collide if their arguments do,
+    /// so only collisions with the names specified using `env` are reported.
+    struct EnvVarValidator {
+        prefix: Option<String>,
+        vars: ArgValidator<String>,
+    }
+
+    impl EnvVarValidator {
+        fn new(prefix: Option<&str>) -> Self {
+            EnvVarValidator {
+                prefix: prefix.map(str::to_uppercase),
+                vars: ArgValidator::new(),
+            }
+        }
+
+        fn check_insert(&mut self, scope: Scope, name: &Spanned<String>, env: &[(String, Span)]) {
+            if env.is_empty() {
+                let span = name.to_span();
+                let name = match scope {
+                    Scope::Section(section) => format!("{}_{}", section.as_snake_case(), name.get_ref()),
+                    Scope::TopLevel | Scope::Subcommand(_) => name.get_ref().clone(),
+                };
+                let var = match &self.prefix {
+                    Some(prefix) => format!("{}_{}", prefix, name.to_uppercase()),
+                    None => name.to_uppercase(),
+                };
+                self.vars.insert_derived(var, span);
+            } else {
+                for (var, span) in env {
+                    // Always Ok because nothing is reserved
+                    let _ = self.vars.check_insert_at(var.clone(), *span);
+                }
+            }
+        }
+    }
+
     pub trait IntoParts: Sized {
         type Value;
 
@@ -586,12 +1403,24 @@ pub mod raw {
     #[derive(Deserialize)]
     #[serde(deny_unknown_fields)]
     pub struct Config {
+        /// Paths of specifications merged into this one.
+        #[serde(default)]
+        include: Vec<Spanned<String>>,
         #[serde(rename = "param")]
         #[serde(default)]
         params: Map<Param>,
         #[serde(rename = "switch")]
         #[serde(default)]
         switches: Map<Switch>,
+        #[serde(rename = "subcommand")]
+        #[serde(default)]
+        subcommands: Map<Subcommand>,
+        #[serde(rename = "section")]
+        #[serde(default)]
+        sections: Map<Section>,
+        #[serde(rename = "positional")]
+        #[serde(default)]
+        positionals: Vec<Positional>,
         #[serde(default)]
         general: General,
         #[serde(default)]
@@ -600,46 +1429,309 @@ pub mod raw {
         debconf: Option<::debconf::DebConfig>,
     }
 
+    /// Where params and switches are declared.
+    #[derive(Copy, Clone)]
+    enum Scope<'a> {
+        TopLevel,
+        Subcommand(&'a str),
+        Section(&'a Ident),
+    }
+
+    impl<'a> Scope<'a> {
+        /// Enums generated for params of subcommands and sections are prefixed to avoid collisions.
+        fn enum_prefix(self) -> String {
+            match self {
+                Scope::TopLevel => String::new(),
+                Scope::Subcommand(name) => super::ident::pascal_case(name),
+                Scope::Section(name) => name.as_pascal_case().to_string(),
+            }
+        }
+
+        /// Types generated for the scope that would collide with an enum having the same name.
+        fn reserved_type_names(self) -> Vec<String> {
+            const TOP_LEVEL_NAMES: &[&str] = &["Config", "Error", "ArgParseError", "EnvParseError", "ValidationError", "Metadata", "Subcommand", "ResultExt", "InvalidValue", "Source"];
+
+            match self {
+                Scope::TopLevel => TOP_LEVEL_NAMES.iter().map(|name| (*name).to_owned()).collect(),
+                Scope::Subcommand(_) => vec![format!("{}Config", self.enum_prefix()), format!("{}ArgParseError", self.enum_prefix())],
+                Scope::Section(_) => vec![format!("{}Section", self.enum_prefix())],
+            }
+        }
+
+        fn section(self) -> Option<&'a Ident> {
+            match self {
+                Scope::Section(section) => Some(section),
+                Scope::TopLevel | Scope::Subcommand(_) => None,
+            }
+        }
+    }
+
+    /// Validates params and switches of a single scope.
+    ///
+    /// The argument validators are shared by the top level and sections because their arguments
+    /// are parsed together.
+    /// Validators of the names which must be unique within a single parser.
+    ///
+    /// The top level shares them with its sections, each subcommand has its own.
+    struct Validators<'a> {
+        long_args: ArgValidator<String>,
+        short_args: ArgValidator<char>,
+        env_vars: EnvVarValidator,
+        errors: &'a mut Vec<ValidationError>,
+    }
+
+    impl<'a> Validators<'a> {
+        fn new(long_args: ArgValidator<String>, env_prefix: Option<&str>, errors: &'a mut Vec<ValidationError>) -> Self {
+            Validators {
+                long_args,
+                short_args: ArgValidator::with_reserved('h'),
+                env_vars: EnvVarValidator::new(env_prefix),
+                errors,
+            }
+        }
+
+        /// Reports all collisions found so far.
+        fn finish(self) {
+            self.errors.extend(self.long_args.into_duplicates(std::convert::identity));
+            self.errors.extend(self.short_args.into_duplicates(|c| c.to_string()));
+            self.errors.extend(self.env_vars.vars.into_duplicates(std::convert::identity));
+        }
+    }
+
+    fn validate_params_and_switches(params: Map<Param>, switches: Map<Switch>, validators: &mut Validators, defaults: &super::Defaults, default_env_var: bool, scope: Scope) -> (Vec<super::Param>, Vec<super::Switch>) {
+        let Validators { long_args, short_args, env_vars, errors } = validators;
+        let in_subcommand = matches!(scope, Scope::Subcommand(_));
+        let default_optional = defaults.optional;
+        let default_argument = defaults.args;
+        let names = params.0.iter().map(|param| param.0.get_ref()).chain(switches.0.iter().map(|switch| switch.0.get_ref())).cloned().collect::<HashSet<_>>();
+        // Keys are only checked within the scope because each section is a separate table
+        let mut file_keys = ArgValidator::new();
+        let mut check_insert_names = |name: &Spanned<String>, aliases: &Option<Vec<Spanned<String>>>, env: &Option<Spanned<Names>>, env_var: &Option<Spanned<bool>>, file_key: &Option<Spanned<String>>, errors: &mut Vec<ValidationError>| {
+            let env_names = spanned_names(env);
+            if !env_names.is_empty() || env_var.as_ref().map_or(default_env_var, IntoParts::get) {
+                env_vars.check_insert(scope, name, &env_names);
+                for alias in aliases.iter().flatten() {
+                    env_vars.check_insert(scope, alias, &[]);
+                }
+            }
+            match file_key {
+                Some(file_key) => file_keys.check_insert(file_key).field_name(name).unwrap_or_else(|error| errors.push(error)),
+                None => file_keys.insert_derived(name.get_ref().clone(), name.to_span()),
+            }
+            for alias in aliases.iter().flatten() {
+                file_keys.insert_derived(alias.get_ref().clone(), alias.to_span());
+            }
+            if in_subcommand {
+                check_no_env_var(env_var, env).field_name(name).unwrap_or_else(|error| errors.push(error));
+                check_no_file_key(file_key).field_name(name).unwrap_or_else(|error| errors.push(error));
+            }
+        };
+
+        let params = params
+            .into_iter()
+            .filter_map(|param| {
+                long_args.check_insert_param_long(scope, &param.0, &param.1.long).unwrap_or_else(|error| errors.push(error));
+                for alias in param.1.aliases.iter().flatten() {
+                    long_args.check_insert_scoped_long(scope, alias).unwrap_or_else(|error| errors.push(error));
+                }
+                if param.1.secret.unwrap_or(false) && param.1.argument.as_ref().map_or(default_argument, IntoParts::get) {
+                    long_args.check_insert_secret_file(scope, &param.0, &param.1.long).unwrap_or_else(|error| errors.push(error));
+                }
+                if let Some(abbr) = &param.1.abbr {
+                    short_args.check_insert(abbr).field_name(&param.0).unwrap_or_else(|error| errors.push(error));
+                }
+                check_insert_names(&param.0, &param.1.aliases, &param.1.env, &param.1.env_var, &param.1.file_key, errors);
+                param.validate_param(default_optional, default_argument, default_env_var, scope, &names).map_err(|error| errors.extend(error)).ok()
+            })
+            .collect::<Vec<_>>();
+
+        let switches = switches
+            .into_iter()
+            .filter_map(|switch| {
+                long_args.check_insert_param_long(scope, &switch.0, &switch.1.long).unwrap_or_else(|error| errors.push(error));
+                for alias in switch.1.aliases.iter().flatten() {
+                    long_args.check_insert_scoped_long(scope, alias).unwrap_or_else(|error| errors.push(error));
+                }
+                if switch.1.negatable.as_ref().map(IntoParts::get).unwrap_or(false) {
+                    long_args.check_insert_negated(scope, &switch.0, &switch.1.long).unwrap_or_else(|error| errors.push(error));
+                    for alias in switch.1.aliases.iter().flatten() {
+                        long_args.check_insert_negated(scope, alias, &None).unwrap_or_else(|error| errors.push(error));
+                    }
+                }
+                if let Some(abbr) = &switch.1.abbr {
+                    short_args.check_insert(abbr).field_name(&switch.0).unwrap_or_else(|error| errors.push(error));
+                }
+                check_insert_names(&switch.0, &switch.1.aliases, &switch.1.env, &switch.1.env_var, &switch.1.file_key, errors);
+                switch.validate_switch(default_env_var, scope.section(), &names).map_err(|error| errors.extend(error)).ok()
+            })
+            .collect::<Vec<_>>();
+        errors.extend(file_keys.into_duplicates(std::convert::identity));
+
+        (params, switches)
+    }
+
+    fn check_no_env_var(env_var: &Option<Spanned<bool>>, env: &Option<Spanned<Names>>) -> Result<(), FieldError> {
+        match (env_var, env) {
+            (Some(env_var), _) if env_var.get() => Err(FieldError::EnvVarInSubcommand { env_var_span: env_var.to_span() }),
+            (_, Some(env)) => Err(FieldError::EnvVarInSubcommand { env_var_span: env.to_span() }),
+            _ => Ok(()),
+        }
+    }
+
+    fn check_no_file_key(file_key: &Option<Spanned<String>>) -> Result<(), FieldError> {
+        match file_key {
+            Some(file_key) => Err(FieldError::FileKeyInSubcommand { file_key_span: file_key.to_span() }),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks the params and switches of a single scope for problems that don't prevent
+    /// generating the code.
+    ///
+    /// `env_vars` collects the names of env vars with their spans, it's `None` if the scope
+    /// doesn't support env vars.
+    fn lint_params_and_switches(params: &Map<Param>, switches: &Map<Switch>, defaults: &super::Defaults, default_env_var: bool, env_var_name: &dyn Fn(&str) -> String, mut env_vars: Option<&mut Vec<(String, Span)>>, warnings: &mut Vec<super::Warning>) {
+        use super::{Warning, WarningKind};
+
+        // hidden options aren't shown anywhere so they don't need documentation
+        let mut check_doc = |name: &Spanned<String>, doc: &Option<String>, hidden: Option<bool>| {
+            if doc.is_none() && !hidden.unwrap_or(false) {
+                warnings.push(Warning { name: name.get_ref().clone(), span: name.to_span(), kind: WarningKind::MissingDoc });
+            }
+        };
+        for Kv(name, param) in &params.0 {
+            check_doc(name, &param.doc, param.hidden);
+        }
+        for Kv(name, switch) in &switches.0 {
+            check_doc(name, &switch.doc, switch.hidden);
+        }
+
+        for Kv(name, param) in &params.0 {
+            let argument = param.argument.as_ref().map_or(defaults.args, IntoParts::get);
+            if let (false, Some(abbr)) = (argument, &param.abbr) {
+                let argument_span = param.argument.as_ref().map(IntoParts::to_span);
+                warnings.push(Warning { name: name.get_ref().clone(), span: name.to_span(), kind: WarningKind::AbbrWithoutArgument { abbr_span: abbr.to_span(), argument_span } });
+            }
+        }
+
+        if let Some(env_vars) = &mut env_vars {
+            let names = params.0.iter().map(|Kv(name, param)| (name, &param.env, &param.env_var, &param.aliases))
+                .chain(switches.0.iter().map(|Kv(name, switch)| (name, &switch.env, &switch.env_var, &switch.aliases)));
+            for (name, env, env_var, aliases) in names {
+                let env = spanned_names(env);
+                if !env.is_empty() {
+                    env_vars.extend(env);
+                } else if env_var.as_ref().map_or(default_env_var, IntoParts::get) {
+                    env_vars.push((env_var_name(name.get_ref()), name.to_span()));
+                    let aliases = aliases.iter().flatten().map(|alias| (env_var_name(alias.get_ref()), alias.to_span()));
+                    env_vars.extend(aliases);
+                }
+            }
+        }
+    }
+
+    /// Moves the items of `included` before the items of `items`.
+    fn prepend<T>(items: &mut Vec<T>, mut included: Vec<T>) {
+        included.append(items);
+        *items = included;
+    }
+
     impl Config {
+        /// Removes the paths of included specifications so that they can be loaded.
+        pub fn take_includes(&mut self) -> Vec<Spanned<String>> {
+            std::mem::take(&mut self.include)
+        }
+
+        /// Merges a specification included by this one into it.
+        ///
+        /// The included items come first and duplicates are kept so that they are reported by
+        /// validation. Keys of `general` are only taken from the included specification if this
+        /// one doesn't set them. `defaults` and `debconf` are not merged.
+        pub fn merge_included(&mut self, included: Config) {
+            prepend(&mut self.params.0, included.params.0);
+            prepend(&mut self.switches.0, included.switches.0);
+            prepend(&mut self.subcommands.0, included.subcommands.0);
+            prepend(&mut self.sections.0, included.sections.0);
+            prepend(&mut self.positionals, included.positionals);
+            self.general.merge_included(included.general);
+        }
+
+        /// Returns `true` if warnings found by `lint` should be treated as errors.
+        pub fn deny_warnings(&self) -> bool {
+            self.general.deny_warnings.unwrap_or(false)
+        }
+
+        /// Checks the specification for problems that don't prevent generating the code.
+        pub fn lint(&self) -> Vec<super::Warning> {
+            use super::{Warning, WarningKind};
+
+            let default_env_var = self.defaults.env_vars.unwrap_or(self.general.env_prefix.is_some());
+            let prefix = self.general.env_prefix.as_ref().map(|prefix| format!("{}_", prefix.to_uppercase()));
+            let prefix = prefix.as_deref().unwrap_or("");
+            let mut warnings = Vec::new();
+            let mut env_vars = Vec::new();
+
+            let env_var_name = |name: &str| format!("{}{}", prefix, name.to_uppercase());
+            lint_params_and_switches(&self.params, &self.switches, &self.defaults, default_env_var, &env_var_name, Some(&mut env_vars), &mut warnings);
+            for Kv(section, section_spec) in &self.sections.0 {
+                let env_var_name = |name: &str| format!("{}{}_{}", prefix, section.get_ref().to_uppercase(), name.to_uppercase());
+                lint_params_and_switches(&section_spec.params, &section_spec.switches, &self.defaults, default_env_var, &env_var_name, Some(&mut env_vars), &mut warnings);
+            }
+            for Kv(_, subcommand) in &self.subcommands.0 {
+                lint_params_and_switches(&subcommand.params, &subcommand.switches, &self.defaults, false, &env_var_name, None, &mut warnings);
+            }
+
+            // Exact duplicates are errors reported by `validate`
+            let mut upper_case = HashMap::new();
+            for (var, span) in env_vars {
+                match upper_case.entry(var.to_uppercase()) {
+                    Entry::Vacant(entry) => { entry.insert((var, span)); },
+                    Entry::Occupied(entry) if entry.get().0 != var => {
+                        let (first, first_span) = entry.get();
+                        let kind = WarningKind::EnvVarDiffersInCase { var: first.clone(), var_span: *first_span, other: var.clone(), other_span: span };
+                        warnings.push(Warning { name: var, span, kind });
+                    },
+                    Entry::Occupied(_) => (),
+                }
+            }
+
+            warnings.sort_by_key(Warning::sort_key);
+            warnings
+        }
+
         pub fn validate(self) -> Result<super::Config, Vec<ValidationError>> {
-            use super::ProgramName;
+            use super::{ConfFormat, ProgramName, UnknownKeys};
 
-            let default_optional = self.defaults.optional;
-            let default_argument = self.defaults.args;
             let default_env_var = self.defaults.env_vars.unwrap_or(self.general.env_prefix.is_some());
             let mut errors = Vec::new();
             let mut long_args = ArgValidator::with_reserved("help".to_owned());
-            let mut short_args = ArgValidator::with_reserved('h');
 
             long_args.check_insert_opt_long(&self.general.conf_file_param).unwrap_or_else(|error| errors.push(error));
             long_args.check_insert_opt_long(&self.general.conf_dir_param).unwrap_or_else(|error| errors.push(error));
             long_args.check_insert_opt_long(&self.general.skip_default_conf_files_switch).unwrap_or_else(|error| errors.push(error));
+            long_args.check_insert_opt_long(&self.general.print_config_switch).unwrap_or_else(|error| errors.push(error));
+            long_args.check_insert_opt_long(&self.general.check_config_switch).unwrap_or_else(|error| errors.push(error));
+
+            // Positional arguments become fields of the same struct, so their names must not
+            // collide with the names of params and switches.
+            let has_subcommands = !self.subcommands.0.is_empty();
+            let positionals = validate_positionals(self.positionals, &mut long_args, self.defaults.optional, has_subcommands, &mut errors);
 
-            let params = self.params
+            let mut validators = Validators::new(long_args, self.general.env_prefix.as_deref(), &mut errors);
+            let (params, switches) = validate_params_and_switches(self.params, self.switches, &mut validators, &self.defaults, default_env_var, Scope::TopLevel);
+
+            let defaults = &self.defaults;
+            let sections = self.sections
                 .into_iter()
-                .filter_map(|param| {
-                    long_args.check_insert_long(&param.0).unwrap_or_else(|error| errors.push(error));
-                    if let Some(abbr) = &param.1.abbr {
-                        short_args.check_insert(abbr).field_name(&param.0).unwrap_or_else(|error| errors.push(error));
-                    }
-                    param.validate_param(default_optional, default_argument, default_env_var).map_err(|error| errors.extend(error)).ok()
-                })
+                .filter_map(|section| section.validate_section(&mut validators, defaults, default_env_var))
                 .collect::<Vec<_>>();
+            validators.finish();
 
-            let switches = self.switches
+            let subcommands = self.subcommands
                 .into_iter()
-                .filter_map(|switch| {
-                    long_args.check_insert_long(&switch.0).unwrap_or_else(|error| errors.push(error));
-                    if let Some(abbr) = &switch.1.abbr {
-                        short_args.check_insert(abbr).field_name(&switch.0).unwrap_or_else(|error| errors.push(error));
-                    }
-                    switch.validate_switch(default_env_var).map_err(|error| errors.extend(error)).ok()
-                })
+                .filter_map(|subcommand| subcommand.validate_subcommand(defaults, &mut errors))
                 .collect::<Vec<_>>();
 
-            errors.extend(long_args.into_duplicates(std::convert::identity));
-            errors.extend(short_args.into_duplicates(|c| c.to_string()));
-
             let mut to_ident = |opt: Option<Spanned<String>>| {
                 opt.and_then(|string| {
                     Ident::try_from(string).map_err(|error| errors.push(error.into())).ok()
@@ -649,6 +1741,10 @@ pub mod raw {
             let conf_file_param = to_ident(self.general.conf_file_param);
             let conf_dir_param = to_ident(self.general.conf_dir_param);
             let skip_default_conf_files_switch = to_ident(self.general.skip_default_conf_files_switch);
+            let print_config_switch = to_ident(self.general.print_config_switch);
+            let check_config_switch = to_ident(self.general.check_config_switch);
+            // Printing the configuration needs it to be serializable
+            let serialize = self.general.serialize.unwrap_or(false) || print_config_switch.is_some();
 
             let program_name = self.general.program_name.map(|program_name| {
                 let span = Span::from(&program_name);
@@ -666,6 +1762,64 @@ pub mod raw {
                     }
                 }
             }).unwrap_or_default();
+
+            if let Some(formats) = &self.general.conf_format {
+                if let Names::Fallbacks(list) = formats.get_ref() {
+                    if list.is_empty() {
+                        errors.push(ValidationError { source: ValidationErrorSource::EmptyConfFormatList { span: formats.to_span() } });
+                    }
+                }
+            }
+            let conf_format = spanned_names(&self.general.conf_format)
+                .into_iter()
+                .filter_map(|(input, span)| {
+                    let format = match &*input {
+                        "toml" => ConfFormat::Toml,
+                        "json" => ConfFormat::Json,
+                        "yaml" => ConfFormat::Yaml,
+                        "kv" => ConfFormat::KeyValue,
+                        "ini" => ConfFormat::Ini,
+                        _ => {
+                            errors.push(ValidationError { source: ValidationErrorSource::InvalidConfFormat { input, span } });
+                            return None;
+                        },
+                    };
+                    Some(format)
+                })
+                .collect();
+
+            let unknown_keys = self.general.unknown_keys.map(|unknown_keys| {
+                let span = Span::from(&unknown_keys);
+                let unknown_keys = unknown_keys.into_inner();
+                match &*unknown_keys {
+                    "ignore" => UnknownKeys::Ignore,
+                    "warn" => UnknownKeys::Warn,
+                    "error" => UnknownKeys::Error,
+                    _ => {
+                        let error = ValidationError {
+                            source: ValidationErrorSource::InvalidUnknownKeys { input: unknown_keys, span },
+                        };
+                        errors.push(error);
+                        UnknownKeys::Ignore
+                    }
+                }
+            }).unwrap_or_default();
+
+            let conf_dir_extension = self.general.conf_dir_extension.and_then(|extension| {
+                let span = Span::from(&extension);
+                let extension = extension.into_inner();
+                // Both `conf` and `.conf` are accepted
+                let trimmed = extension.trim_start_matches('.');
+                if trimmed.is_empty() || trimmed.contains(&['/', '\\'][..]) {
+                    let error = ValidationError {
+                        source: ValidationErrorSource::InvalidConfDirExtension { input: extension, span },
+                    };
+                    errors.push(error);
+                    None
+                } else {
+                    Some(trimmed.to_owned())
+                }
+            });
             if !errors.is_empty() {
                 errors.sort_by_key(ValidationError::sort_key);
                 return Err(errors);
@@ -678,8 +1832,16 @@ pub mod raw {
                 env_prefix: self.general.env_prefix,
                 conf_file_param,
                 conf_dir_param,
+                conf_dir_extension,
                 skip_default_conf_files_switch,
+                print_config_switch,
+                check_config_switch,
                 program_name,
+                validate_fn: self.general.validate_fn,
+                conf_format,
+                unknown_keys,
+                serialize,
+                reload: self.general.reload.unwrap_or(false),
             };
 
             Ok(super::Config {
@@ -687,12 +1849,144 @@ pub mod raw {
                 defaults: self.defaults,
                 params,
                 switches,
+                positionals,
+                subcommands,
+                sections,
                 #[cfg(feature = "debconf")]
                 debconf: self.debconf,
             })
         }
     }
 
+    #[derive(Debug)]
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Subcommand {
+        doc: Option<String>,
+        #[serde(rename = "param")]
+        #[serde(default)]
+        params: Map<Param>,
+        #[serde(rename = "switch")]
+        #[serde(default)]
+        switches: Map<Switch>,
+    }
+
+    impl Kv<Subcommand> {
+        fn validate_subcommand(self, defaults: &super::Defaults, errors: &mut Vec<ValidationError>) -> Option<super::Subcommand> {
+            // Subcommands accept only command-line arguments, so env vars are never enabled.
+            let mut validators = Validators::new(ArgValidator::with_reserved("help".to_owned()), None, errors);
+            let (params, switches) = validate_params_and_switches(self.1.params, self.1.switches, &mut validators, defaults, false, Scope::Subcommand(self.0.get_ref()));
+            validators.finish();
+            let name = Ident::try_from(self.0).map_err(|error| errors.push(error.into())).ok()?;
+
+            Some(super::Subcommand {
+                name,
+                doc: self.1.doc,
+                config: super::Config {
+                    general: Default::default(),
+                    defaults: defaults.clone(),
+                    params,
+                    switches,
+                    positionals: Vec::new(),
+                    subcommands: Vec::new(),
+                    sections: Vec::new(),
+                    #[cfg(feature = "debconf")]
+                    debconf: None,
+                },
+            })
+        }
+    }
+
+    #[derive(Debug)]
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Section {
+        doc: Option<String>,
+        #[serde(rename = "param")]
+        #[serde(default)]
+        params: Map<Param>,
+        #[serde(rename = "switch")]
+        #[serde(default)]
+        switches: Map<Switch>,
+    }
+
+    impl Kv<Section> {
+        fn validate_section(self, validators: &mut Validators, defaults: &super::Defaults, default_env_var: bool) -> Option<super::Section> {
+            // The section becomes a field of the config so it must not collide with params.
+            let errors = &mut validators.errors;
+            validators.long_args.check_insert_long(&self.0).unwrap_or_else(|error| errors.push(error));
+            let name = Ident::try_from(self.0).map_err(|error| validators.errors.push(error.into())).ok()?;
+            let (params, switches) = validate_params_and_switches(self.1.params, self.1.switches, validators, defaults, default_env_var, Scope::Section(&name));
+
+            Some(super::Section {
+                name,
+                doc: self.1.doc,
+                config: super::Config {
+                    general: Default::default(),
+                    defaults: defaults.clone(),
+                    params,
+                    switches,
+                    positionals: Vec::new(),
+                    subcommands: Vec::new(),
+                    sections: Vec::new(),
+                    #[cfg(feature = "debconf")]
+                    debconf: None,
+                },
+            })
+        }
+    }
+
+    #[derive(Debug)]
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Positional {
+        name: Spanned<String>,
+        #[serde(rename = "type")]
+        ty: String,
+        optional: Option<Spanned<bool>>,
+        multiple: Option<Spanned<bool>>,
+        doc: Option<String>,
+    }
+
+    fn validate_positionals(positionals: Vec<Positional>, long_args: &mut ArgValidator<String>, default_optional: bool, has_subcommands: bool, errors: &mut Vec<ValidationError>) -> Vec<super::Positional> {
+        let mut last_optional = None;
+        let mut last_multiple = None;
+
+        positionals
+            .into_iter()
+            .filter_map(|positional| {
+                let optional = positional.optional.as_ref().map(IntoParts::get).unwrap_or(default_optional);
+                let multiple = positional.multiple.as_ref().map(IntoParts::get).unwrap_or(false);
+
+                long_args.check_insert_long(&positional.name).unwrap_or_else(|error| errors.push(error));
+                if has_subcommands {
+                    Err::<(), _>(FieldError::PositionalWithSubcommands).field_name(&positional.name).unwrap_or_else(|error| errors.push(error));
+                }
+                if let Some(multiple_span) = last_multiple {
+                    Err::<(), _>(FieldError::MultiplePositionalNotLast { multiple_span }).field_name(&positional.name).unwrap_or_else(|error| errors.push(error));
+                }
+                match (optional, last_optional) {
+                    (false, Some(optional_span)) => Err::<(), _>(FieldError::MandatoryPositionalAfterOptional { optional_span }).field_name(&positional.name).unwrap_or_else(|error| errors.push(error)),
+                    (true, None) => last_optional = Some(positional.optional.as_ref().map_or_else(|| positional.name.to_span(), IntoParts::to_span)),
+                    _ => (),
+                }
+                if multiple {
+                    last_multiple = positional.multiple.as_ref().map(IntoParts::to_span);
+                }
+
+                let name = Ident::try_from(positional.name).map_err(|error| errors.push(error.into())).ok()?;
+
+                Some(super::Positional {
+                    name,
+                    ty: positional.ty,
+                    optional,
+                    multiple,
+                    doc: positional.doc,
+                })
+            })
+            .collect()
+    }
+
     #[derive(Debug)]
     #[derive(Deserialize, Default)]
     #[serde(deny_unknown_fields)]
@@ -703,10 +1997,194 @@ pub mod raw {
         env_prefix: Option<String>,
         conf_file_param: Option<Spanned<String>>,
         conf_dir_param: Option<Spanned<String>>,
+        conf_dir_extension: Option<Spanned<String>>,
         skip_default_conf_files_switch: Option<Spanned<String>>,
+        print_config_switch: Option<Spanned<String>>,
+        check_config_switch: Option<Spanned<String>>,
         program_name: Option<Spanned<String>>,
+        validate_fn: Option<String>,
+        deny_warnings: Option<bool>,
+        conf_format: Option<Spanned<Names>>,
+        unknown_keys: Option<Spanned<String>>,
+        serialize: Option<bool>,
+        reload: Option<bool>,
+    }
+
+    impl General {
+        fn merge_included(&mut self, included: General) {
+            fn fill<T>(value: &mut Option<T>, included: Option<T>) {
+                if value.is_none() {
+                    *value = included;
+                }
+            }
+
+            fill(&mut self.name, included.name);
+            fill(&mut self.summary, included.summary);
+            fill(&mut self.doc, included.doc);
+            fill(&mut self.env_prefix, included.env_prefix);
+            fill(&mut self.conf_file_param, included.conf_file_param);
+            fill(&mut self.conf_dir_param, included.conf_dir_param);
+            fill(&mut self.conf_dir_extension, included.conf_dir_extension);
+            fill(&mut self.skip_default_conf_files_switch, included.skip_default_conf_files_switch);
+            fill(&mut self.print_config_switch, included.print_config_switch);
+            fill(&mut self.check_config_switch, included.check_config_switch);
+            fill(&mut self.program_name, included.program_name);
+            fill(&mut self.validate_fn, included.validate_fn);
+            fill(&mut self.deny_warnings, included.deny_warnings);
+            fill(&mut self.conf_format, included.conf_format);
+            fill(&mut self.unknown_keys, included.unknown_keys);
+            fill(&mut self.serialize, included.serialize);
+            fill(&mut self.reload, included.reload);
+        }
+    }
+
+
+    /// Names of other params or switches of the same scope.
+    type References = Option<Spanned<Vec<Spanned<String>>>>;
+
+    /// Checks that the params and switches referenced by `name` exist in the same scope.
+    ///
+    /// `names` contains the names of all params and switches of the scope.
+    fn validate_constraints(name: &Spanned<String>, requires: References, conflicts_with: References, required_unless: References, names: &HashSet<String>) -> Result<super::Constraints, Vec<ValidationError>> {
+        let mut errors = Vec::new();
+        let mut resolve = |key, references: References| {
+            references
+                .map_or_else(Vec::new, Spanned::into_inner)
+                .into_iter()
+                .filter_map(|reference| {
+                    let reference_span = reference.to_span();
+                    if reference.get_ref() == name.get_ref() {
+                        errors.push(Err::<(), _>(FieldError::SelfReference { key, reference_span }).field_name(name).unwrap_err());
+                        None
+                    } else if !names.contains(reference.get_ref()) {
+                        errors.push(Err::<(), _>(FieldError::UnknownReference { key, reference_span }).field_name(name).unwrap_err());
+                        None
+                    } else {
+                        Ident::try_from(reference).map_err(|error| errors.push(error.into())).ok()
+                    }
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let requires = resolve("requires", requires);
+        let conflicts_with = resolve("conflicts_with", conflicts_with);
+        let required_unless = resolve("required_unless", required_unless);
+
+        if errors.is_empty() {
+            Ok(super::Constraints { requires, conflicts_with, required_unless, })
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Names written either as a single string or as a list of fallbacks, used for env vars
+    /// and config file formats.
+    #[derive(Debug)]
+    enum Names {
+        Single(String),
+        Fallbacks(Vec<Spanned<String>>),
+    }
+
+    impl<'de> serde::Deserialize<'de> for Names {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct Visitor;
+
+            impl<'de> serde::de::Visitor<'de> for Visitor {
+                type Value = Names;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    f.write_str("a string or a list of strings")
+                }
+
+                fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                    Ok(Names::Single(value.to_owned()))
+                }
+
+                fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                    let mut names = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                    while let Some(name) = seq.next_element()? {
+                        names.push(name);
+                    }
+                    Ok(Names::Fallbacks(names))
+                }
+            }
+
+            deserializer.deserialize_any(Visitor)
+        }
+    }
+
+    /// Returns the names specified using `names` with their spans.
+    fn spanned_names(names: &Option<Spanned<Names>>) -> Vec<(String, Span)> {
+        match names.as_ref().map(|names| (names.get_ref(), names.to_span())) {
+            Some((Names::Single(name), span)) => vec![(name.clone(), span)],
+            Some((Names::Fallbacks(names), _)) => names.iter().map(|name| (name.get_ref().clone(), name.to_span())).collect(),
+            None => Vec::new(),
+        }
     }
 
+    /// Names of a param or switch used instead of the ones derived from its name.
+    struct Overrides {
+        long: Option<String>,
+        env: Vec<String>,
+        file_key: Option<String>,
+    }
+
+    /// Checks the `long`, `env` and `file_key` of a param or switch.
+    fn validate_overrides(name: &Spanned<String>, long: Option<Spanned<String>>, env: Option<Spanned<Names>>, file_key: Option<Spanned<String>>, env_var: &Option<Spanned<bool>>) -> Result<Overrides, Vec<ValidationError>> {
+        let mut errors = Vec::new();
+        let mut push = |error| errors.push(Err::<(), _>(error).field_name(name).unwrap_err());
+
+        if let Some(long) = &long {
+            let valid = !long.get_ref().starts_with('-') && !long.get_ref().is_empty() && long.get_ref().chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+            if !valid {
+                push(FieldError::InvalidLong { long_span: long.to_span() });
+            }
+        }
+        let env_span = env.as_ref().map(IntoParts::to_span);
+        let env = spanned_names(&env);
+        for (var, span) in &env {
+            let valid = !var.is_empty() && !var.starts_with(|c: char| c.is_ascii_digit()) && var.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+            if !valid {
+                push(FieldError::InvalidEnvVarName { env_span: *span });
+            }
+        }
+        match (env_var, env_span) {
+            (Some(env_var), Some(env_span)) if !env_var.get() => push(FieldError::EnvWithDisabledEnvVar { env_var_span: env_var.to_span(), env_span }),
+            (_, Some(env_span)) if env.is_empty() => push(FieldError::EmptyEnvList { env_span }),
+            _ => (),
+        }
+        if let Some(file_key) = &file_key {
+            if file_key.get_ref().is_empty() {
+                push(FieldError::EmptyFileKey { file_key_span: file_key.to_span() });
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(Overrides {
+                long: long.map(Spanned::into_inner),
+                env: env.into_iter().map(|(var, _)| var).collect(),
+                file_key: file_key.map(Spanned::into_inner),
+            })
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Converts the old names of a param or switch to identifiers.
+    fn validate_aliases(aliases: Option<Vec<Spanned<String>>>) -> Result<Vec<Ident>, Vec<ValidationError>> {
+        let mut errors = Vec::new();
+        let aliases = aliases
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|alias| Ident::try_from(alias).map_err(|error| errors.push(error.into())).ok())
+            .collect();
+
+        if errors.is_empty() {
+            Ok(aliases)
+        } else {
+            Err(errors)
+        }
+    }
 
     #[derive(Debug)]
     #[derive(Deserialize)]
@@ -714,21 +2192,205 @@ pub mod raw {
     struct Param {
         abbr: Option<Spanned<char>>,
         #[serde(rename = "type")]
-        ty: String,
+        ty: Option<Spanned<String>>,
+        values: Option<Spanned<Vec<Spanned<String>>>>,
         optional: Option<Spanned<bool>>,
         default: Option<Spanned<String>>,
         doc: Option<String>,
-        argument: Option<bool>,
-        env_var: Option<bool>,
-        convert_into: Option<String>,
-        merge_fn: Option<String>,
+        argument: Option<Spanned<bool>>,
+        env_var: Option<Spanned<bool>>,
+        convert_into: Option<Spanned<String>>,
+        merge_fn: Option<Spanned<String>>,
+        optional_value: Option<Spanned<String>>,
+        validate_fn: Option<String>,
+        multiple: Option<bool>,
+        env_separator: Option<Spanned<String>>,
+        requires: References,
+        conflicts_with: References,
+        required_unless: References,
+        min: Option<Spanned<toml::Value>>,
+        max: Option<Spanned<toml::Value>>,
+        min_len: Option<Spanned<u64>>,
+        max_len: Option<Spanned<u64>>,
+        pattern: Option<Spanned<String>>,
+        aliases: Option<Vec<Spanned<String>>>,
+        deprecated: Option<String>,
+        secret: Option<bool>,
+        reloadable: Option<bool>,
+        long: Option<Spanned<String>>,
+        env: Option<Spanned<Names>>,
+        file_key: Option<Spanned<String>>,
+        hidden: Option<bool>,
+        category: Option<String>,
         #[cfg(feature = "debconf")]
         debconf_priority: Option<::debconf::Priority>,
         #[cfg(feature = "debconf")]
         debconf_default: Option<String>,
     }
 
+    /// Value constraints of a param as written in the specification.
+    struct RawValueConstraints {
+        min: Option<Spanned<toml::Value>>,
+        max: Option<Spanned<toml::Value>>,
+        min_len: Option<Spanned<u64>>,
+        max_len: Option<Spanned<u64>>,
+        pattern: Option<Spanned<String>>,
+    }
+
+    impl RawValueConstraints {
+        /// Returns the span of the first constraint or `None` if there are no constraints.
+        fn first_span(&self) -> Option<Span> {
+            self.min.as_ref().map(IntoParts::to_span)
+                .or_else(|| self.max.as_ref().map(IntoParts::to_span))
+                .or_else(|| self.min_len.as_ref().map(IntoParts::to_span))
+                .or_else(|| self.max_len.as_ref().map(IntoParts::to_span))
+                .or_else(|| self.pattern.as_ref().map(IntoParts::to_span))
+        }
+
+        fn validate_bound(bound: Option<Spanned<toml::Value>>) -> Result<Option<(super::Bound, Span)>, FieldError> {
+            let bound = match bound {
+                Some(bound) => bound,
+                None => return Ok(None),
+            };
+            let bound_span = bound.to_span();
+            match bound.into_inner() {
+                toml::Value::Integer(bound) => Ok(Some((super::Bound::Integer(bound), bound_span))),
+                toml::Value::Float(bound) if bound.is_finite() => Ok(Some((super::Bound::Float(bound), bound_span))),
+                _ => Err(FieldError::InvalidBound { bound_span }),
+            }
+        }
+
+        fn validate(self, values_span: Option<Span>) -> Result<super::ValueConstraints, Vec<FieldError>> {
+            if let (Some(constraint_span), Some(values_span)) = (self.first_span(), values_span) {
+                return Err(vec![FieldError::ValueConstraintWithValues { constraint_span, values_span }]);
+            }
+
+            let mut errors = Vec::new();
+            let min = Self::validate_bound(self.min).map_err(|error| errors.push(error)).ok().flatten();
+            let max = Self::validate_bound(self.max).map_err(|error| errors.push(error)).ok().flatten();
+            if let (Some((min, min_span)), Some((max, max_span))) = (&min, &max) {
+                if min.as_f64() > max.as_f64() {
+                    errors.push(FieldError::EmptyRange { min_span: *min_span, max_span: *max_span });
+                }
+            }
+            if let (Some(min_len), Some(max_len)) = (&self.min_len, &self.max_len) {
+                if min_len.get() > max_len.get() {
+                    errors.push(FieldError::EmptyRange { min_span: min_len.to_span(), max_span: max_len.to_span() });
+                }
+            }
+            if let Some(pattern) = &self.pattern {
+                if let Err(error) = regex_syntax::Parser::new().parse(pattern.get_ref()) {
+                    errors.push(FieldError::InvalidPattern { pattern_span: pattern.to_span(), error: error.to_string() });
+                }
+            }
+
+            if errors.is_empty() {
+                Ok(super::ValueConstraints {
+                    min: min.map(|(min, _)| min),
+                    max: max.map(|(max, _)| max),
+                    min_len: self.min_len.map(Spanned::into_inner),
+                    max_len: self.max_len.map(Spanned::into_inner),
+                    pattern: self.pattern.map(Spanned::into_inner),
+                })
+            } else {
+                Err(errors)
+            }
+        }
+    }
+
     impl Param {
+        fn validate_value(value: &Spanned<String>) -> Result<(), FieldError> {
+            let mut chars = value.get_ref().chars();
+            let valid = chars.next().filter(char::is_ascii_lowercase).is_some() && chars.all(|c| c == '_' || c == '-' || c.is_ascii_lowercase() || c.is_ascii_digit());
+            if valid {
+                Ok(())
+            } else {
+                Err(FieldError::InvalidValue { value_span: value.to_span() })
+            }
+        }
+
+        /// Returns the type of the parameter and the list of allowed values if the enum should be
+        /// generated.
+        fn validate_type(name: &str, ty: Option<Spanned<String>>, values: Option<Spanned<Vec<Spanned<String>>>>, scope: Scope) -> Result<(String, Option<Vec<String>>), Vec<FieldError>> {
+            let values = match (ty, values) {
+                (Some(ty), None) => return Ok((ty.into_inner(), None)),
+                (None, None) => return Err(vec![FieldError::MissingType]),
+                (Some(ty), Some(values)) => return Err(vec![FieldError::TypeWithValues { type_span: ty.to_span(), values_span: values.to_span() }]),
+                (None, Some(values)) => values,
+            };
+
+            let values_span = values.to_span();
+            let values = values.into_inner();
+            let enum_name = format!("{}{}", scope.enum_prefix(), super::ident::pascal_case(name));
+            let mut errors = Vec::new();
+            if values.is_empty() {
+                errors.push(FieldError::EmptyValues { values_span });
+            }
+            if scope.reserved_type_names().contains(&enum_name) {
+                errors.push(FieldError::ReservedEnumName { values_span, enum_name: enum_name.clone() });
+            }
+            let mut variants = std::collections::HashMap::new();
+            for value in &values {
+                if let Err(error) = Param::validate_value(value) {
+                    errors.push(error);
+                } else if let Some(first_span) = variants.insert(super::ident::pascal_case(value.get_ref()), value.to_span()) {
+                    errors.push(FieldError::DuplicateValue { first_span, duplicate_span: value.to_span() });
+                }
+            }
+
+            if errors.is_empty() {
+                Ok((enum_name, Some(values.into_iter().map(Spanned::into_inner).collect())))
+            } else {
+                Err(errors)
+            }
+        }
+
+        /// Checks the Rust code that is copied into the generated file.
+        fn validate_code(&self) -> Result<(), Vec<FieldError>> {
+            use super::snippet::Kind;
+
+            let code = [
+                ("type", &self.ty, Kind::Type),
+                ("default", &self.default, Kind::Expression),
+                ("convert_into", &self.convert_into, Kind::Type),
+                ("merge_fn", &self.merge_fn, Kind::Expression),
+                ("optional_value", &self.optional_value, Kind::Expression),
+            ];
+            let errors = code.iter()
+                .filter_map(|(key, code, kind)| code.as_ref().map(|code| (*key, code, *kind)))
+                .filter_map(|(key, code, kind)| super::snippet::check(code.get_ref(), kind).err().map(|error| FieldError::InvalidRustCode { key, code_span: code.to_span(), error }))
+                .collect::<Vec<_>>();
+
+            if errors.is_empty() {
+                Ok(())
+            } else {
+                Err(errors)
+            }
+        }
+
+        fn validate_optional_value(optional_value: Option<Spanned<String>>, argument: &Option<Spanned<bool>>, default_argument: bool) -> Result<Option<String>, FieldError> {
+            match (optional_value, argument) {
+                (Some(optional_value), Some(argument)) if !argument.get() => Err(FieldError::OptionalValueWithoutArgument { optional_value_span: optional_value.to_span(), argument_span: Some(argument.to_span()) }),
+                (Some(optional_value), None) if !default_argument => Err(FieldError::OptionalValueWithoutArgument { optional_value_span: optional_value.to_span(), argument_span: None }),
+                (optional_value, _) => Ok(optional_value.map(Spanned::into_inner)),
+            }
+        }
+
+        fn validate_env_separator(multiple: bool, env_separator: Option<Spanned<String>>) -> Result<String, FieldError> {
+            match (multiple, env_separator) {
+                (false, Some(separator)) => Err(FieldError::SeparatorWithoutMultiple { separator_span: separator.to_span() }),
+                (_, Some(separator)) => Ok(separator.into_inner()),
+                (_, None) => Ok(",".to_owned()),
+            }
+        }
+
+        fn validate_merge_fn(multiple: bool, merge_fn: Option<Spanned<String>>) -> Result<Option<String>, FieldError> {
+            match (multiple, merge_fn) {
+                (true, Some(merge_fn)) => Err(FieldError::MergeFnWithMultiple { merge_fn_span: merge_fn.to_span() }),
+                (_, merge_fn) => Ok(merge_fn.map(Spanned::into_inner)),
+            }
+        }
+
         fn validate_optionality(optional: Option<Spanned<bool>>, default_optional: bool, default: Option<Spanned<String>>) -> Result<Optionality, FieldError> {
             match (optional, default_optional, default) {
                 (Some(opt), _, None) if !opt.get() => Ok(Optionality::Mandatory),
@@ -742,24 +2404,56 @@ pub mod raw {
     }
 
     impl Kv<Param> {
-        fn validate_param(self, default_optional: bool, default_argument: bool, default_env_var: bool) -> Result<super::Param, impl Iterator<Item=ValidationError>> {
+        fn validate_param(self, default_optional: bool, default_argument: bool, default_env_var: bool, scope: Scope, names: &HashSet<String>) -> Result<super::Param, impl Iterator<Item=ValidationError>> {
+            let name_ref = &self.0;
+            let code = self.1.validate_code()
+                .map_err(|errors| errors.into_iter().map(|error| Err::<(), _>(error).field_name(name_ref).unwrap_err()).collect::<Vec<_>>());
+            let value_constraints = RawValueConstraints {
+                min: self.1.min,
+                max: self.1.max,
+                min_len: self.1.min_len,
+                max_len: self.1.max_len,
+                pattern: self.1.pattern,
+            };
+            let value_constraints = value_constraints.validate(self.1.values.as_ref().map(IntoParts::to_span))
+                .map_err(|errors| errors.into_iter().map(|error| Err::<(), _>(error).field_name(name_ref).unwrap_err()).collect::<Vec<_>>());
+            let ty_values = Param::validate_type(self.0.get_ref(), self.1.ty, self.1.values, scope)
+                .map_err(|errors| errors.into_iter().map(|error| Err::<(), _>(error).field_name(name_ref).unwrap_err()).collect::<Vec<_>>());
+            let required_unless_span = self.1.required_unless.as_ref().map(IntoParts::to_span);
             let optionality = Param::validate_optionality(self.1.optional, default_optional, self.1.default)
+                .and_then(|optionality| match (&optionality, required_unless_span) {
+                    (Optionality::Optional, _) | (_, None) => Ok(optionality),
+                    (_, Some(required_unless_span)) => Err(FieldError::RequiredUnlessNotOptional { required_unless_span }),
+                })
+                .field_name(&self.0);
+            let constraints = validate_constraints(&self.0, self.1.requires, self.1.conflicts_with, self.1.required_unless, names);
+            let multiple = self.1.multiple.unwrap_or(false);
+            let env_separator = Param::validate_env_separator(multiple, self.1.env_separator)
+                .field_name(&self.0);
+            let merge_fn = Param::validate_merge_fn(multiple, self.1.merge_fn)
+                .field_name(&self.0);
+            let optional_value = Param::validate_optional_value(self.1.optional_value, &self.1.argument, default_argument)
                 .field_name(&self.0);
+            let aliases = validate_aliases(self.1.aliases);
+            let overrides = validate_overrides(&self.0, self.1.long, self.1.env, self.1.file_key, &self.1.env_var);
             let name = Ident::try_from(self.0).map_err(Into::into);
 
-            let (name, optionality) = match (name, optionality) {
-                (Ok(name), Ok(optionality)) => (name, optionality),
-                (err1, err2) => return Err(err1.err().into_iter().chain(err2.err())),
+            let (field, optionality, env_separator, merge_fn, optional_value, (ty, values), constraints, value_constraints, aliases, overrides, ()) = match (name, optionality, env_separator, merge_fn, optional_value, ty_values, constraints, value_constraints, aliases, overrides, code) {
+                (Ok(name), Ok(optionality), Ok(env_separator), Ok(merge_fn), Ok(optional_value), Ok(ty_values), Ok(constraints), Ok(value_constraints), Ok(aliases), Ok(overrides), Ok(())) => (name, optionality, env_separator, merge_fn, optional_value, ty_values, constraints, value_constraints, aliases, overrides, ()),
+                (err1, err2, err3, err4, err5, err6, err7, err8, err9, err10, err11) => return Err(err1.err().into_iter().chain(err2.err()).chain(err3.err()).chain(err4.err()).chain(err5.err()).chain(err6.err().into_iter().flatten()).chain(err7.err().into_iter().flatten()).chain(err8.err().into_iter().flatten()).chain(err9.err().into_iter().flatten()).chain(err10.err().into_iter().flatten()).chain(err11.err().into_iter().flatten())),
             };
+            let section = scope.section().cloned();
+            let name = section.as_ref().map_or_else(|| field.clone(), |section| field.prefixed(section));
 
-            let ty = self.1.ty;
-            let argument = self.1.argument.unwrap_or(default_argument);
-            let env_var = self.1.env_var.unwrap_or(default_env_var);
+            let argument = self.1.argument.as_ref().map_or(default_argument, IntoParts::get);
+            let env_var = !overrides.env.is_empty() || self.1.env_var.map(Spanned::into_inner).unwrap_or(default_env_var);
             let needs_conversion = self.1.convert_into.is_some();
-            let convert_into = self.1.convert_into.unwrap_or_else(|| ty.clone());
+            let convert_into = self.1.convert_into.map(Spanned::into_inner).unwrap_or_else(|| ty.clone());
 
             Ok(super::Param {
                 name,
+                field,
+                section,
                 ty,
                 optionality,
                 abbr: self.1.abbr.map(Spanned::into_inner),
@@ -768,7 +2462,23 @@ pub mod raw {
                 env_var,
                 convert_into,
                 needs_conversion,
-                merge_fn: self.1.merge_fn,
+                merge_fn,
+                optional_value,
+                validate_fn: self.1.validate_fn,
+                multiple,
+                env_separator,
+                values,
+                constraints,
+                value_constraints,
+                aliases,
+                deprecated: self.1.deprecated,
+                secret: self.1.secret.unwrap_or(false),
+                reloadable: self.1.reloadable.unwrap_or(true),
+                long: overrides.long,
+                env: overrides.env,
+                file_key: overrides.file_key,
+                hidden: self.1.hidden.unwrap_or(false),
+                category: self.1.category,
                 #[cfg(feature = "debconf")]
                 debconf_priority: self.1.debconf_priority,
                 #[cfg(feature = "debconf")]
@@ -784,8 +2494,19 @@ pub mod raw {
         abbr: Option<Spanned<char>>,
         default: Option<Spanned<bool>>,
         doc: Option<String>,
-        env_var: Option<bool>,
+        env_var: Option<Spanned<bool>>,
         count: Option<Spanned<bool>>,
+        negatable: Option<Spanned<bool>>,
+        requires: References,
+        conflicts_with: References,
+        required_unless: References,
+        aliases: Option<Vec<Spanned<String>>>,
+        deprecated: Option<String>,
+        long: Option<Spanned<String>>,
+        env: Option<Spanned<Names>>,
+        file_key: Option<Spanned<String>>,
+        hidden: Option<bool>,
+        category: Option<String>,
         #[cfg(feature = "debconf")]
         debconf_priority: Option<::debconf::Priority>,
     }
@@ -808,14 +2529,23 @@ pub mod raw {
                 (abbr, _, count) => Ok(SwitchKind::Normal { abbr: abbr.map(Spanned::into_inner), count: count.map(Spanned::into_inner).unwrap_or(false) }),
             }
         }
+
+        fn validate_negatable(negatable: Option<Spanned<bool>>, count: &Option<Spanned<bool>>) -> Result<bool, FieldError> {
+            match (negatable, count) {
+                (Some(negatable), Some(count)) if negatable.get() && count.get() => Err(FieldError::NegatableCount { negatable_span: negatable.to_span(), count_span: count.to_span() }),
+                (negatable, _) => Ok(negatable.map(Spanned::into_inner).unwrap_or(false)),
+            }
+        }
     }
 
     impl Kv<Switch> {
-        fn validate_switch(self, default_env_var: bool) -> Result<super::Switch, impl Iterator<Item=ValidationError>> {
+        fn validate_switch(self, default_env_var: bool, section: Option<&Ident>, names: &HashSet<String>) -> Result<super::Switch, impl Iterator<Item=ValidationError>> {
             let abbr = self.1.abbr;
             let default = self.1.default;
             let count = self.1.count;
             let name = &self.0;
+            let negatable = Switch::validate_negatable(self.1.negatable, &count)
+                .field_name(name);
 
             let kind = abbr
                 .map(Switch::validate_abbr)
@@ -825,19 +2555,34 @@ pub mod raw {
                     Switch::validate_kind(abbr, default, count)
                         .field_name(name)
                 });
+            let constraints = validate_constraints(&self.0, self.1.requires, self.1.conflicts_with, self.1.required_unless, names);
 
+            let aliases = validate_aliases(self.1.aliases);
+            let overrides = validate_overrides(&self.0, self.1.long, self.1.env, self.1.file_key, &self.1.env_var);
             let name = Ident::try_from(self.0).map_err(Into::into);
 
-            let (name, kind) = match (name, kind) {
-                (Ok(name), Ok(kind)) => (name, kind),
-                (err1, err2) => return Err(err1.err().into_iter().chain(err2.err())),
+            let (field, kind, negatable, constraints, aliases, overrides) = match (name, kind, negatable, constraints, aliases, overrides) {
+                (Ok(name), Ok(kind), Ok(negatable), Ok(constraints), Ok(aliases), Ok(overrides)) => (name, kind, negatable, constraints, aliases, overrides),
+                (err1, err2, err3, err4, err5, err6) => return Err(err1.err().into_iter().chain(err2.err()).chain(err3.err()).chain(err4.err().into_iter().flatten()).chain(err5.err().into_iter().flatten()).chain(err6.err().into_iter().flatten())),
             };
+            let name = section.map_or_else(|| field.clone(), |section| field.prefixed(section));
 
             Ok(super::Switch {
                 name,
+                field,
+                section: section.cloned(),
                 kind,
+                negatable,
                 doc: self.1.doc,
-                env_var: self.1.env_var.unwrap_or(default_env_var),
+                env_var: !overrides.env.is_empty() || self.1.env_var.map(Spanned::into_inner).unwrap_or(default_env_var),
+                constraints,
+                aliases,
+                deprecated: self.1.deprecated,
+                long: overrides.long,
+                env: overrides.env,
+                file_key: overrides.file_key,
+                hidden: self.1.hidden.unwrap_or(false),
+                category: self.1.category,
                 #[cfg(feature = "debconf")]
                 debconf_priority: self.1.debconf_priority,
             })
@@ -856,6 +2601,9 @@ pub struct Config {
     pub defaults: Defaults,
     pub params: Vec<Param>,
     pub switches: Vec<Switch>,
+    pub positionals: Vec<Positional>,
+    pub subcommands: Vec<Subcommand>,
+    pub sections: Vec<Section>,
 }
 
 #[derive(Debug, Default)]
@@ -887,11 +2635,26 @@ pub struct General {
     /// configuration provided so far with them.
     pub conf_dir_param: Option<Ident>,
 
+    /// If set, only files with this extension
+    /// are loaded from the configuration
+    /// directory.
+    pub conf_dir_extension: Option<String>,
+
     /// The name of the switch which, if
     /// specified, avoids reading default
     /// configuration files.
     pub skip_default_conf_files_switch: Option<Ident>,
 
+    /// The name of the switch which, if
+    /// specified, prints the validated
+    /// configuration as TOML and exits.
+    pub print_config_switch: Option<Ident>,
+
+    /// The name of the switch which, if
+    /// specified, validates the configuration
+    /// and exits.
+    pub check_config_switch: Option<Ident>,
+
     /// How to handle the zeroth argument - program name.
     ///
     /// If the program name is unused it will not be added to the `Metadata` struct.
@@ -900,9 +2663,30 @@ pub struct General {
     /// If the program name is required `PathBuf` is added to `Metadata` and a nice error message
     /// will be reported if it is missing.
     pub program_name: ProgramName,
+
+    /// Path to the function called with the fully built
+    /// `Config` to check relations the constraints
+    /// can't express.
+    pub validate_fn: Option<String>,
+
+    /// Formats of configuration files.
+    ///
+    /// If more than one format is specified the format of each file is detected from its
+    /// extension, falling back to the first one. If empty, TOML is used without going through
+    /// `configure_me::format`, so the generated code stays compatible with older versions.
+    pub conf_format: Vec<ConfFormat>,
+
+    /// What to do with keys in configuration files that don't belong to any param or switch.
+    pub unknown_keys: UnknownKeys,
+
+    /// Implement `Serialize` for `Config`, implied by `print_config_switch`.
+    pub serialize: bool,
+
+    /// Generate `Config::reload` reporting which values changed.
+    pub reload: bool,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[derive(Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct Defaults {
@@ -937,7 +2721,11 @@ pub enum SwitchKind {
 }
 
 pub struct Param {
+    /// Name used for the argument, env var and error variants, prefixed with the section if any
     pub name: Ident,
+    /// Name of the field in the struct of the section or config
+    pub field: Ident,
+    pub section: Option<Ident>,
     pub abbr: Option<char>,
     pub ty: String,
     pub optionality: Optionality,
@@ -947,6 +2735,36 @@ pub struct Param {
     pub convert_into: String,
     pub needs_conversion: bool,
     pub merge_fn: Option<String>,
+    /// Expression used if the argument is given without a value
+    pub optional_value: Option<String>,
+    /// Function checking the value, called with a reference to it
+    pub validate_fn: Option<String>,
+    /// The parameter collects all occurrences into `Vec`
+    pub multiple: bool,
+    /// Separator of values in the env var if `multiple` is `true`
+    pub env_separator: String,
+    /// Allowed values if the type is an enum generated from them
+    pub values: Option<Vec<String>>,
+    pub constraints: Constraints,
+    pub value_constraints: ValueConstraints,
+    /// Old names still accepted in arguments, env vars and config files
+    pub aliases: Vec<Ident>,
+    /// Message reported in a warning if the param is used
+    pub deprecated: Option<String>,
+    /// The value is sensitive: it's redacted in `Debug` and errors and can be read from a file
+    pub secret: bool,
+    /// A change of the value is applied by `Config::reload` instead of being reported as an error
+    pub reloadable: bool,
+    /// Long option used instead of the one derived from the name, without the leading dashes
+    pub long: Option<String>,
+    /// Env vars used instead of the one derived from the name, the first one set is used
+    pub env: Vec<String>,
+    /// Key used in config files instead of the field name
+    pub file_key: Option<String>,
+    /// The option is parsed but not shown in the help and the man page
+    pub hidden: bool,
+    /// Heading under which the option is listed in the help
+    pub category: Option<String>,
     #[cfg(feature = "debconf")]
     pub debconf_priority: Option<::debconf::Priority>,
     #[cfg(feature = "debconf")]
@@ -954,15 +2772,230 @@ pub struct Param {
 }
 
 pub struct Switch {
+    /// Name used for the argument, env var and error variants, prefixed with the section if any
     pub name: Ident,
+    /// Name of the field in the struct of the section or config
+    pub field: Ident,
+    pub section: Option<Ident>,
     pub kind: SwitchKind,
+    /// Both `--<name>` and `--no-<name>` are accepted, the last one wins
+    pub negatable: bool,
     pub doc: Option<String>,
     pub env_var: bool,
+    pub constraints: Constraints,
+    /// Old names still accepted in arguments, env vars and config files
+    pub aliases: Vec<Ident>,
+    /// Message reported in a warning if the switch is used
+    pub deprecated: Option<String>,
+    /// Long option used instead of the one derived from the name, without the leading dashes
+    pub long: Option<String>,
+    /// Env vars used instead of the one derived from the name, the first one set is used
+    pub env: Vec<String>,
+    /// Key used in config files instead of the field name
+    pub file_key: Option<String>,
+    /// The option is parsed but not shown in the help and the man page
+    pub hidden: bool,
+    /// Heading under which the option is listed in the help
+    pub category: Option<String>,
     #[cfg(feature = "debconf")]
     pub debconf_priority: Option<::debconf::Priority>,
 }
 
+/// Relations to other params and switches of the same scope checked when the config is validated.
+///
+/// A param is considered specified if it has a value from any source, a switch if it's not in its
+/// default state.
+pub struct Constraints {
+    /// These must be specified as well if this one is
+    pub requires: Vec<Ident>,
+    /// These must not be specified if this one is
+    pub conflicts_with: Vec<Ident>,
+    /// This one must be specified unless any of these is
+    pub required_unless: Vec<Ident>,
+}
+
+/// Constraints on the parsed value of a param checked when the config is validated.
+///
+/// The constraints apply to each value if the param accepts multiple values.
+pub struct ValueConstraints {
+    /// The lowest allowed value
+    pub min: Option<Bound>,
+    /// The highest allowed value
+    pub max: Option<Bound>,
+    /// Minimal number of chars
+    pub min_len: Option<u64>,
+    /// Maximal number of chars
+    pub max_len: Option<u64>,
+    /// Regular expression the value must match
+    pub pattern: Option<String>,
+}
+
+/// Numeric bound of a value.
+#[derive(Copy, Clone)]
+pub enum Bound {
+    Integer(i64),
+    Float(f64),
+}
+
+impl Bound {
+    fn as_f64(self) -> f64 {
+        match self {
+            Bound::Integer(bound) => bound as f64,
+            Bound::Float(bound) => bound,
+        }
+    }
+
+    /// Returns the Rust literal of the bound compared with values of type `ty`.
+    ///
+    /// Integer bounds of floating point types are written as floats so that they type check.
+    pub fn literal(self, ty: &str) -> String {
+        match self {
+            Bound::Integer(bound) if ty == "f32" || ty == "f64" => format!("{:?}", bound as f64),
+            Bound::Integer(bound) => bound.to_string(),
+            // Debug formatting always produces a float literal
+            Bound::Float(bound) => format!("{:?}", bound),
+        }
+    }
+}
+
+impl fmt::Display for Bound {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Bound::Integer(bound) => fmt::Display::fmt(bound, f),
+            Bound::Float(bound) => fmt::Display::fmt(bound, f),
+        }
+    }
+}
+
+impl ValueConstraints {
+    pub fn is_empty(&self) -> bool {
+        self.min.is_none() && self.max.is_none() && self.min_len.is_none() && self.max_len.is_none() && self.pattern.is_none()
+    }
+
+    /// Returns human-readable descriptions of the constraints used in documentation.
+    pub fn descriptions(&self) -> Vec<String> {
+        let range = self.min.iter().map(|min| format!("at least {}", min))
+            .chain(self.max.iter().map(|max| format!("at most {}", max)));
+        let len = self.min_len.iter().map(|min_len| format!("at least {} characters long", min_len))
+            .chain(self.max_len.iter().map(|max_len| format!("at most {} characters long", max_len)));
+        let pattern = self.pattern.iter().map(|pattern| format!("matching `{}`", pattern));
+
+        range.chain(len).chain(pattern).collect()
+    }
+}
+
+/// A typed positional argument.
+///
+/// If `multiple` is set the argument collects all remaining positional arguments.
+pub struct Positional {
+    pub name: Ident,
+    pub ty: String,
+    pub optional: bool,
+    pub multiple: bool,
+    pub doc: Option<String>,
+}
+
+/// A subcommand with its own set of arguments.
+///
+/// The `config` of a subcommand never has subcommands, general settings or env vars.
+pub struct Subcommand {
+    pub name: Ident,
+    pub doc: Option<String>,
+    pub config: Config,
+}
+
+/// A group of params and switches stored in a nested struct and a table of the config file.
+///
+/// The `config` of a section contains only params and switches.
+pub struct Section {
+    pub name: Ident,
+    pub doc: Option<String>,
+    pub config: Config,
+}
+
+/// Returns the path of the field from the raw config, e.g. `network.bind_addr`.
+fn field_path(section: &Option<Ident>, field: &Ident) -> String {
+    match section {
+        Some(section) => format!("{}.{}", section.as_snake_case(), field.as_snake_case()),
+        None => field.as_snake_case().to_owned(),
+    }
+}
+
+/// Returns the key of a field in configuration files prefixed with its section.
+fn file_key_path(section: &Option<Ident>, field: &Ident, file_key: &Option<String>) -> String {
+    match file_key {
+        Some(file_key) => match section {
+            Some(section) => format!("{}.{}", section.as_snake_case(), file_key),
+            None => file_key.clone(),
+        },
+        None => field_path(section, field),
+    }
+}
+
+impl Param {
+    pub fn field_path(&self) -> String {
+        field_path(&self.section, &self.field)
+    }
+
+    /// Returns the path of the field holding the value of the alias in the raw config.
+    pub fn alias_path(&self, alias: &Ident) -> String {
+        field_path(&self.section, alias)
+    }
+
+    /// Returns the alias prefixed with the section, as used in arguments and env vars.
+    pub fn alias_name(&self, alias: &Ident) -> Ident {
+        self.section.as_ref().map_or_else(|| alias.clone(), |section| alias.prefixed(section))
+    }
+
+    /// Returns the long option without the leading dashes.
+    pub fn long_name(&self) -> String {
+        self.long.clone().unwrap_or_else(|| self.name.as_hypenated().to_string())
+    }
+
+    /// Returns the key in the configuration file.
+    #[cfg(feature = "debconf")]
+    pub fn file_key(&self) -> String {
+        self.file_key.clone().unwrap_or_else(|| self.name.as_snake_case().to_string())
+    }
+
+    /// Returns the key in configuration files prefixed with the section, e.g. `network.port`.
+    pub fn file_key_path(&self) -> String {
+        file_key_path(&self.section, &self.field, &self.file_key)
+    }
+}
+
 impl Switch {
+    pub fn field_path(&self) -> String {
+        field_path(&self.section, &self.field)
+    }
+
+    /// Returns the path of the field holding the value of the alias in the raw config.
+    pub fn alias_path(&self, alias: &Ident) -> String {
+        field_path(&self.section, alias)
+    }
+
+    /// Returns the alias prefixed with the section, as used in arguments and env vars.
+    pub fn alias_name(&self, alias: &Ident) -> Ident {
+        self.section.as_ref().map_or_else(|| alias.clone(), |section| alias.prefixed(section))
+    }
+
+    /// Returns the long option without the leading dashes and the `no-` prefix of inverted
+    /// switches.
+    pub fn long_name(&self) -> String {
+        self.long.clone().unwrap_or_else(|| self.name.as_hypenated().to_string())
+    }
+
+    /// Returns the key in the configuration file.
+    #[cfg(feature = "debconf")]
+    pub fn file_key(&self) -> String {
+        self.file_key.clone().unwrap_or_else(|| self.name.as_snake_case().to_string())
+    }
+
+    /// Returns the key in configuration files prefixed with the section, e.g. `network.ipv6`.
+    pub fn file_key_path(&self) -> String {
+        file_key_path(&self.section, &self.field, &self.file_key)
+    }
+
     pub fn is_inverted(&self) -> bool {
         self.kind == SwitchKind::Inverted
     }
@@ -977,6 +3010,40 @@ impl Switch {
 
 }
 
+/// Format of configuration files.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ConfFormat {
+    Toml,
+    Json,
+    Yaml,
+    /// Flat `key=value` files like `bitcoin.conf`
+    KeyValue,
+    /// Same as `KeyValue` but detected by the `.ini` extension
+    Ini,
+}
+
+impl ConfFormat {
+    /// The name of the matching variant of `configure_me::format::Format`.
+    pub fn variant(self) -> &'static str {
+        match self {
+            ConfFormat::Toml => "Toml",
+            ConfFormat::Json => "Json",
+            ConfFormat::Yaml => "Yaml",
+            ConfFormat::KeyValue => "KeyValue",
+            ConfFormat::Ini => "Ini",
+        }
+    }
+}
+
+/// Handling of unknown keys in configuration files.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum UnknownKeys {
+    #[default]
+    Ignore,
+    Warn,
+    Error,
+}
+
 #[derive(Debug)]
 pub enum ProgramName {
     Unused,
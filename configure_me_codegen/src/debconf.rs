@@ -277,8 +277,13 @@ impl VisitWrite<visitor::Templates> for ::config::Param {
     fn visit_write<W: fmt::Write>(&self, config: &DebConfig, mut output: W) -> fmt::Result {
         if self.debconf_priority.is_some() {
             writeln!(output, "Template: {}/{}", config.package_name, self.name.as_snake_case())?;
-            if self.ty == "bool" {
+            if let Some(values) = &self.values {
+                writeln!(output, "Type: select")?;
+                writeln!(output, "Choices: {}", values.join(", "))?;
+            } else if self.ty == "bool" {
                 writeln!(output, "Type: bool")?;
+            } else if self.secret {
+                writeln!(output, "Type: password")?;
             } else {
                 writeln!(output, "Type: string")?;
             }
@@ -342,8 +347,8 @@ impl VisitWrite<visitor::Postinst> for ::config::Param {
             writeln!(output, "db_get {}/{}", config.package_name, self.name.as_snake_case())?;
             match self.ty.as_str() {
                 "bool" | "u8" | "u16" | "u32" | "u64" | "u128" |
-                    "i8" | "i16" | "i32" | "i64" | "i128" | "f32" | "f64" => writeln!(output, "echo {}=\"$RET\" >> \"$CONF_FILE\"", self.name.as_snake_case()),
-                _ => writeln!(output, "echo \"$RET\" | sed -e 's/\"/\\\"/g' -e 's/^/{}=\"/' -e 's/$/\"/' >> \"$CONF_FILE\"", self.name.as_snake_case()),
+                    "i8" | "i16" | "i32" | "i64" | "i128" | "f32" | "f64" => writeln!(output, "echo {}=\"$RET\" >> \"$CONF_FILE\"", self.file_key()),
+                _ => writeln!(output, "echo \"$RET\" | sed -e 's/\"/\\\"/g' -e 's/^/{}=\"/' -e 's/$/\"/' >> \"$CONF_FILE\"", self.file_key()),
             }
         } else {
             Ok(())
@@ -355,7 +360,7 @@ impl VisitWrite<visitor::Postinst> for ::config::Switch {
     fn visit_write<W: fmt::Write>(&self, config: &DebConfig, mut output: W) -> fmt::Result {
         if self.debconf_priority.is_some() {
             writeln!(output, "db_get {}/{}", config.package_name, self.name.as_snake_case())?;
-            writeln!(output, "echo {}=\"$RET\" >> \"$CONF_FILE\"", self.name.as_snake_case())
+            writeln!(output, "echo {}=\"$RET\" >> \"$CONF_FILE\"", self.file_key())
         } else {
             Ok(())
         }
@@ -0,0 +1,139 @@
+use ::config::{Config, Ident, Optionality, Param, Switch, SwitchKind};
+use std::fmt::{self, Write};
+
+const INTEGER_TYPES: &[&str] = &["u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize"];
+
+/// Returns the key within the table of the section (if any).
+fn file_key(field: &Ident, file_key: &Option<String>) -> String {
+    file_key.clone().unwrap_or_else(|| field.as_snake_case().to_owned())
+}
+
+fn write_doc<W: Write>(doc: Option<&str>, output: &mut W) -> fmt::Result {
+    for line in doc.into_iter().flat_map(str::lines) {
+        if line.is_empty() {
+            writeln!(output, "#")?;
+        } else {
+            writeln!(output, "# {}", line)?;
+        }
+    }
+    Ok(())
+}
+
+/// Converts the default value to TOML if it's a simple literal.
+///
+/// Defaults are Rust expressions so anything more complicated than a literal (optionally converted
+/// to an owned string) can't be represented.
+fn default_literal(param: &Param, default: &str) -> Option<String> {
+    if let Some(values) = &param.values {
+        let variant = default.rsplit("::").next()?;
+        return values
+            .iter()
+            .find(|value| ::config::pascal_case(value) == variant)
+            .map(|value| ::toml::Value::String(value.clone()).to_string());
+    }
+
+    let default = default.trim();
+    let literal = [".to_owned()", ".to_string()", ".into()"]
+        .iter()
+        .find_map(|suffix| default.strip_suffix(suffix))
+        .unwrap_or(default);
+    let parsed = ::toml::from_str::<::toml::value::Table>(&format!("value = {}", literal)).ok()?;
+    match parsed.get("value")? {
+        ::toml::Value::Array(_) | ::toml::Value::Table(_) => None,
+        value => Some(value.to_string()),
+    }
+}
+
+/// Returns an example value based on the type of the parameter.
+fn example_value(param: &Param) -> String {
+    if let Some(value) = param.values.as_ref().and_then(|values| values.first()) {
+        return ::toml::Value::String(value.clone()).to_string();
+    }
+
+    let ty = param.ty.trim();
+    if ty == "bool" {
+        "false".to_owned()
+    } else if INTEGER_TYPES.contains(&ty) {
+        "0".to_owned()
+    } else if ty == "f32" || ty == "f64" {
+        "0.0".to_owned()
+    } else if ty.ends_with("Duration") {
+        "\"30s\"".to_owned()
+    } else if ty.ends_with("ByteSize") {
+        "\"1MiB\"".to_owned()
+    } else {
+        "\"\"".to_owned()
+    }
+}
+
+fn write_param<W: Write>(param: &Param, output: &mut W) -> fmt::Result {
+    write_doc(param.doc.as_deref(), output)?;
+    if let Some(values) = &param.values {
+        writeln!(output, "# Possible values: {}", values.join(", "))?;
+    }
+    if let Optionality::Mandatory = param.optionality {
+        writeln!(output, "# This parameter is mandatory.")?;
+    }
+    let value = if param.secret {
+        writeln!(output, "# This value is secret, make sure the file isn't readable by others.")?;
+        "\"\"".to_owned()
+    } else if param.multiple {
+        "[]".to_owned()
+    } else if let Optionality::DefaultValue(default) = &param.optionality {
+        default_literal(param, default).unwrap_or_else(|| example_value(param))
+    } else {
+        example_value(param)
+    };
+    writeln!(output, "#{} = {}", file_key(&param.field, &param.file_key), value)
+}
+
+fn write_switch<W: Write>(switch: &Switch, output: &mut W) -> fmt::Result {
+    write_doc(switch.doc.as_deref(), output)?;
+    let value = match switch.kind {
+        SwitchKind::Normal { count: true, .. } => "0",
+        SwitchKind::Normal { count: false, .. } => "false",
+        SwitchKind::Inverted => "true",
+    };
+    writeln!(output, "#{} = {}", file_key(&switch.field, &switch.file_key), value)
+}
+
+fn write_items<W: Write>(config: &Config, path: &str, output: &mut W) -> fmt::Result {
+    let params = config.params.iter().filter(|param| !param.hidden && param.deprecated.is_none());
+    let switches = config.switches.iter().filter(|switch| !switch.hidden && switch.deprecated.is_none());
+
+    for param in params {
+        writeln!(output)?;
+        write_param(param, output)?;
+    }
+
+    for switch in switches {
+        writeln!(output)?;
+        write_switch(switch, output)?;
+    }
+
+    for section in &config.sections {
+        let path = if path.is_empty() {
+            section.name.as_snake_case().to_owned()
+        } else {
+            format!("{}.{}", path, section.name.as_snake_case())
+        };
+        writeln!(output)?;
+        write_doc(section.doc.as_deref(), output)?;
+        writeln!(output, "[{}]", path)?;
+        write_items(&section.config, &path, output)?;
+    }
+    Ok(())
+}
+
+/// Generates an example configuration file in TOML format.
+///
+/// Every parameter and switch is documented and commented out so the file can be installed as-is.
+pub fn generate_config_template<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    match &config.general.name {
+        Some(name) => writeln!(output, "# Example configuration of {}", name)?,
+        None => writeln!(output, "# Example configuration")?,
+    }
+    writeln!(output, "#")?;
+    writeln!(output, "# Uncomment and change the values you want to set.")?;
+    write_items(config, "", &mut output)
+}
@@ -51,7 +51,10 @@ fn generate_conf_file_param(man: Manual, config: &Config) -> Manual {
 fn generate_conf_dir_param(man: Manual, config: &Config) -> Manual {
     if let Some(conf_dir_param) = &config.general.conf_dir_param {
         let opt = Opt::new("CONFIG_DIR").long(&::codegen::param_long_raw(conf_dir_param.as_snake_case()));
-        let opt = opt.help("Loads configuration from all files in the directory CONFIG_DIR.");
+        let opt = match &config.general.conf_dir_extension {
+            Some(extension) => opt.help(&format!("Loads configuration from all .{} files in the directory CONFIG_DIR in alphabetical order.", extension)),
+            None => opt.help("Loads configuration from all files in the directory CONFIG_DIR in alphabetical order."),
+        };
         man.option(opt)
     } else {
         man
@@ -68,18 +71,119 @@ fn generate_skip_default_conf_files_switch(man: Manual, config: &Config) -> Manu
     }
 }
 
-fn generate_params(man: Manual, config: &Config) -> Manual {
+fn generate_print_config_switch(man: Manual, config: &Config) -> Manual {
+    if let Some(switch) = &config.general.print_config_switch {
+        let opt = Flag::new().long(&::codegen::param_long_raw(switch.as_snake_case()));
+        let opt = opt.help("Print the configuration as TOML and exit. Secret values are redacted.");
+        man.flag(opt)
+    } else {
+        man
+    }
+}
+
+fn generate_check_config_switch(man: Manual, config: &Config) -> Manual {
+    if let Some(switch) = &config.general.check_config_switch {
+        let opt = Flag::new().long(&::codegen::param_long_raw(switch.as_snake_case()));
+        let opt = opt.help("Check the configuration and exit with a non-zero status if it's invalid.");
+        man.flag(opt)
+    } else {
+        man
+    }
+}
+
+/// Prepends the sentence describing where the argument is valid (subcommand, section) to the doc.
+fn context_help(context: &Option<String>, doc: &Option<String>) -> Option<String> {
+    match (context, doc) {
+        (Some(context), Some(doc)) => Some(format!("{} {}", context, doc)),
+        (Some(context), None) => Some(context.clone()),
+        (None, doc) => doc.clone(),
+    }
+}
+
+/// Prepends the category to the context so that the options of a category can be told apart.
+fn category_context(category: &str, context: Option<String>) -> Option<String> {
+    match context {
+        Some(context) => Some(format!("{}: {}", category, context)),
+        None => Some(format!("{}:", category)),
+    }
+}
+
+/// Returns `true` if the option isn't hidden and belongs to `category`.
+fn is_listed(hidden: bool, option_category: &Option<String>, category: Option<&str>) -> bool {
+    !hidden && option_category.as_ref().map(AsRef::as_ref) == category
+}
+
+fn env_prefix(config: &Config) -> String {
+    config.general.env_prefix.as_ref().map_or_else(String::new, |prefix| [prefix, "_"].join(""))
+}
+
+/// Returns the name of the env var and the doc with a note about fallbacks if there are any.
+fn env_name_and_doc(prefix: &str, name: &::config::Ident, env: &[String], doc: Option<String>) -> (String, Option<String>) {
+    match env.split_first() {
+        Some((first, [])) => (first.clone(), doc),
+        Some((first, fallbacks)) => (first.clone(), append_note(doc, &format!("falls back to {} if unset", fallbacks.join(", ")))),
+        None => ([prefix, &name.as_upper_case().to_string()].join(""), doc),
+    }
+}
+
+/// Appends a note in parentheses to the doc or makes a sentence from the note if there's no doc.
+fn append_note(doc: Option<String>, note: &str) -> Option<String> {
+    let mut chars = note.chars();
+    let sentence = chars.next().map(|first| format!("{}{}.", first.to_uppercase(), chars.as_str()));
+    match doc {
+        // The doc already is a complete sentence, so the note is appended as another one
+        Some(doc) if doc.ends_with('.') => Some(format!("{} {}", doc, sentence.unwrap_or_default())),
+        Some(doc) => Some(format!("{} ({})", doc, note)),
+        None => sentence,
+    }
+}
+
+fn append_values(doc: Option<String>, param: &::config::Param) -> Option<String> {
+    let doc = match ::codegen::unit_syntax(param) {
+        Some(syntax) => append_note(doc, syntax),
+        None => doc,
+    };
+    let doc = match &param.values {
+        Some(values) => append_note(doc, &format!("possible values: {}", values.join(", "))),
+        None => doc,
+    };
+    let constraints = param.value_constraints.descriptions();
+    if constraints.is_empty() {
+        doc
+    } else {
+        append_note(doc, &format!("must be {}", constraints.join(", ")))
+    }
+}
+
+fn generate_params(man: Manual, config: &Config, context: Option<String>, category: Option<&str>) -> Manual {
     config
         .params
         .iter()
-        .filter(|param| param.argument).map(|param| {
-            let opt = Opt::new(&param.name.as_upper_case().to_string()).long(&::codegen::param_long(param));
+        .filter(|param| param.argument && is_listed(param.hidden, &param.category, category)).flat_map(|param| {
+            let long = ::codegen::param_long(param);
+            let value_name = match &param.optional_value {
+                Some(_) => format!("[{}]", param.name.as_upper_case()),
+                None => param.name.as_upper_case().to_string(),
+            };
+            let opt = Opt::new(&value_name).long(&long);
             let opt = if let Some(short) = ::codegen::param_short(param) {
                 opt.short(&short)
             } else {
                 opt
             };
-            let opt = if let Some(doc) = &param.doc {
+            let doc = context_help(&context, &param.doc);
+            let doc = if param.multiple {
+                append_note(doc, "can be specified multiple times")
+            } else {
+                doc
+            };
+            let doc = append_values(doc, param);
+            let doc = if param.secret {
+                append_note(doc, &format!("sensitive, prefer passing it using {}-file", long))
+            } else {
+                doc
+            };
+            let opt = if let Some(doc) = doc {
                 opt.help(&doc)
             } else {
                 opt
@@ -89,15 +193,22 @@ fn generate_params(man: Manual, config: &Config) -> Manual {
             } else {
                 opt
             };
-            opt
+            let file_opt = if param.secret {
+                let file_opt = Opt::new("FILE").long(&format!("{}-file", long));
+                Some(file_opt.help(&format!("Reads the value of {} from FILE.", long)))
+            } else {
+                None
+            };
+            Some(opt).into_iter().chain(file_opt)
         })
         .fold(man, |man, opt| man.option(opt))
 }
 
-fn generate_switches(man: Manual, config: &Config) -> Manual {
+fn generate_switches(man: Manual, config: &Config, context: Option<String>, category: Option<&str>) -> Manual {
     config
         .switches
         .iter()
+        .filter(|switch| is_listed(switch.hidden, &switch.category, category))
         .map(|switch| {
             let flag = Flag::new()
                 .long(&::codegen::switch_long(switch));
@@ -106,7 +217,7 @@ fn generate_switches(man: Manual, config: &Config) -> Manual {
             } else {
                 flag
             };
-            let flag = if let Some(doc) = &switch.doc {
+            let flag = if let Some(doc) = context_help(&context, &switch.doc) {
                 flag.help(&doc)
             } else {
                 flag
@@ -116,14 +227,25 @@ fn generate_switches(man: Manual, config: &Config) -> Manual {
         .fold(man, |man, flag| man.flag(flag))
 }
 
-fn generate_param_env_vars(man: Manual, config: &Config) -> Manual {
-    let prefix = config.general.env_prefix.as_ref().map_or_else(String::new, |prefix| [&prefix, "_"].join(""));
+fn generate_param_env_vars(man: Manual, config: &Config, prefix: &str, context: Option<String>) -> Manual {
     config
         .params
         .iter()
-        .filter(|param| param.env_var).map(|param| {
-            let env = Env::new(&[&prefix as &str, &param.name.as_upper_case().to_string()].join(""));
-            let env = if let Some(doc) = &param.doc {
+        .filter(|param| param.env_var && !param.hidden).flat_map(|param| {
+            let (name, doc) = env_name_and_doc(prefix, &param.name, &param.env, context_help(&context, &param.doc));
+            let env = Env::new(&name);
+            let doc = if param.multiple {
+                append_note(doc, &format!("multiple values are separated by '{}'", param.env_separator))
+            } else {
+                doc
+            };
+            let doc = append_values(doc, param);
+            let doc = if param.secret {
+                append_note(doc, &format!("sensitive, prefer passing it using {}_FILE", name))
+            } else {
+                doc
+            };
+            let env = if let Some(doc) = doc {
                 env.help(&doc)
             } else {
                 env
@@ -133,19 +255,25 @@ fn generate_param_env_vars(man: Manual, config: &Config) -> Manual {
             } else {
                 env
             };
-            env
+            let file_env = if param.secret {
+                let file_env = Env::new(&format!("{}_FILE", name));
+                Some(file_env.help(&format!("Path to a file containing the value of {}.", name)))
+            } else {
+                None
+            };
+            Some(env).into_iter().chain(file_env)
         })
         .fold(man, |man, env| man.env(env))
 }
 
-fn generate_switch_env_vars(man: Manual, config: &Config) -> Manual {
-    let prefix = config.general.env_prefix.as_ref().map_or_else(String::new, |prefix| [&prefix, "_"].join(""));
+fn generate_switch_env_vars(man: Manual, config: &Config, prefix: &str, context: Option<String>) -> Manual {
     config
         .switches
         .iter()
-        .filter(|switch| switch.env_var).map(|switch| {
-            let env = Env::new(&[&prefix as &str, &switch.name.as_upper_case().to_string()].join(""));
-            let env = if let Some(doc) = &switch.doc {
+        .filter(|switch| switch.env_var && !switch.hidden).map(|switch| {
+            let (name, doc) = env_name_and_doc(prefix, &switch.name, &switch.env, context_help(&context, &switch.doc));
+            let env = Env::new(&name);
+            let env = if let Some(doc) = doc {
                 env.help(&doc)
             } else {
                 env
@@ -160,20 +288,117 @@ fn generate_switch_env_vars(man: Manual, config: &Config) -> Manual {
         .fold(man, |man, env| man.env(env))
 }
 
+fn generate_positionals(man: Manual, config: &Config) -> Manual {
+    config
+        .positionals
+        .iter()
+        .fold(man, |man, positional| man.arg(Arg::new(&::codegen::positional_usage(positional))))
+}
+
+fn generate_subcommands(man: Manual, config: &Config) -> Manual {
+    if config.subcommands.is_empty() {
+        return man;
+    }
+
+    let man = man.arg(Arg::new("SUBCOMMAND"));
+    config
+        .subcommands
+        .iter()
+        .fold(man, |man, subcommand| {
+            let name = subcommand.name.as_hypenated().to_string();
+            let context = format!("Only valid after subcommand {}.", name);
+            generate_args(man, &subcommand.config, Some(context))
+        })
+}
+
+fn section_context(section: &::config::Section) -> Option<String> {
+    Some(format!("Part of section {}.", section.name.as_snake_case()))
+}
+
+fn generate_section_args(man: Manual, config: &Config, category: Option<&str>) -> Manual {
+    config
+        .sections
+        .iter()
+        .fold(man, |man, section| {
+            let context = match category {
+                Some(category) => category_context(category, section_context(section)),
+                None => section_context(section),
+            };
+            let man = generate_params(man, &section.config, context.clone(), category);
+            generate_switches(man, &section.config, context, category)
+        })
+}
+
+/// Generates the params and switches including sections, the uncategorized ones go first.
+fn generate_args(man: Manual, config: &Config, context: Option<String>) -> Manual {
+    let man = generate_params(man, config, context.clone(), None);
+    let man = generate_switches(man, config, context.clone(), None);
+    let man = generate_section_args(man, config, None);
+    ::codegen::help_categories(config)
+        .into_iter()
+        .fold(man, |man, category| {
+            let context = category_context(category, context.clone());
+            let man = generate_params(man, config, context.clone(), Some(category));
+            let man = generate_switches(man, config, context, Some(category));
+            generate_section_args(man, config, Some(category))
+        })
+}
+
+fn generate_section_env_vars(man: Manual, config: &Config, prefix: &str) -> Manual {
+    config
+        .sections
+        .iter()
+        .fold(man, |man, section| {
+            let man = generate_param_env_vars(man, &section.config, prefix, section_context(section));
+            generate_switch_env_vars(man, &section.config, prefix, section_context(section))
+        })
+}
+
+fn subcommands_description(config: &Config) -> Option<String> {
+    if config.subcommands.is_empty() {
+        return None;
+    }
+
+    let subcommands = config
+        .subcommands
+        .iter()
+        .map(|subcommand| {
+            let name = subcommand.name.as_hypenated().to_string();
+            match &subcommand.doc {
+                Some(doc) => format!("{} ({})", name, doc),
+                None => name,
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    Some(format!("Available subcommands: {}.", subcommands))
+}
+
 pub fn generate_man_page(config: &Config, manifest: &Manifest) -> Result<String, manifest::Error> {
     let man = generate_meta(config, manifest)?;
-    let man = if let Some(doc) = &config.general.doc {
-        man.description(doc.to_owned())
+    let description = match (&config.general.doc, subcommands_description(config)) {
+        (Some(doc), Some(subcommands)) => Some(format!("{}\n\n{}", doc, subcommands)),
+        (Some(doc), None) => Some(doc.to_owned()),
+        (None, subcommands) => subcommands,
+    };
+    let man = if let Some(description) = description {
+        man.description(description)
     } else {
         man
     };
     let man = generate_conf_file_param(man, config);
     let man = generate_conf_dir_param(man, config);
     let man = generate_skip_default_conf_files_switch(man, config);
-    let man = generate_params(man, config);
-    let man = generate_switches(man, config);
-    let man = generate_param_env_vars(man, config);
-    let man = generate_switch_env_vars(man, config);
+    let man = generate_print_config_switch(man, config);
+    let man = generate_check_config_switch(man, config);
+    let man = generate_args(man, config, None);
+    let man = generate_positionals(man, config);
+    let man = generate_subcommands(man, config);
+    let prefix = env_prefix(config);
+    let man = generate_param_env_vars(man, config, &prefix, None);
+    let man = generate_switch_env_vars(man, config, &prefix, None);
+    let man = generate_section_env_vars(man, config, &prefix);
 
     Ok(man.render())
 }
@@ -31,6 +31,7 @@ extern crate toml;
 extern crate unicode_segmentation;
 extern crate fmt2io;
 extern crate cargo_toml;
+extern crate regex_syntax;
 #[cfg(feature = "man")]
 extern crate man;
 #[cfg(feature = "spanned")]
@@ -40,22 +41,85 @@ pub(crate) mod config;
 pub(crate) mod codegen;
 #[cfg(feature = "man")]
 pub (crate) mod gen_man;
+pub (crate) mod gen_config_template;
 #[cfg(feature = "debconf")]
 pub (crate) mod debconf;
 
 pub mod manifest;
 
 use std::borrow::Borrow;
+use std::collections::HashSet;
 use std::fmt;
 use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
 use manifest::LoadManifest;
 
-#[cfg(feature = "spanned")]
-type FileSpec = codespan_reporting::files::SimpleFile<String, String>;
+/// A file of the specification, either the main one or an included one.
+#[derive(Debug)]
+struct SpecFile {
+    name: String,
+    source: String,
+    /// Spans in the file are shifted by this offset, see `SpecFiles::parse`.
+    offset: usize,
+}
 
-#[cfg(not(feature = "spanned"))]
-type FileSpec = ();
+/// All files the specification was loaded from, the main one is first.
+#[derive(Debug, Default)]
+struct SpecFiles {
+    files: Vec<SpecFile>,
+}
+
+impl SpecFiles {
+    /// Parses the file as if it was placed after all previously parsed files.
+    ///
+    /// This makes spans in different files disjoint so the file an error comes from can be found
+    /// from the span alone.
+    fn parse(&mut self, name: String, source: String) -> Result<config::raw::Config, InputErrorSource> {
+        let offset = self.files.last().map_or(0, |file| file.offset + file.source.len() + 1);
+        let mut padded = "\n".repeat(offset);
+        padded.push_str(&source);
+        let result = toml::from_str(&padded).map_err(|error| {
+            // Parsing the file again is cheaper than fixing the line in the error and only
+            // happens on failure
+            let error = toml::from_str::<config::raw::Config>(&source).err().unwrap_or(error);
+            InputErrorSource::Toml(error, self.files.len())
+        });
+        self.files.push(SpecFile { name, source, offset, });
+        result
+    }
+
+    /// Returns the index of the file containing the span and the span relative to the file.
+    #[cfg(feature = "spanned")]
+    fn locate(&self, range: std::ops::Range<usize>) -> (usize, std::ops::Range<usize>) {
+        let index = self.files.iter().rposition(|file| file.offset <= range.start).unwrap_or(0);
+        let offset = self.files[index].offset;
+        (index, (range.start - offset)..(range.end - offset))
+    }
+
+    /// Moves the labels of the diagnostic from global spans to the files they point into.
+    #[cfg(feature = "spanned")]
+    fn locate_diagnostic(&self, diagnostic: codespan_reporting::diagnostic::Diagnostic<()>) -> codespan_reporting::diagnostic::Diagnostic<usize> {
+        use codespan_reporting::diagnostic::{Diagnostic, Label};
+
+        let labels = diagnostic.labels
+            .into_iter()
+            .map(|label| {
+                let (file_id, range) = self.locate(label.range);
+                Label { style: label.style, file_id, range, message: label.message }
+            })
+            .collect();
+        Diagnostic { severity: diagnostic.severity, code: diagnostic.code, message: diagnostic.message, labels, notes: diagnostic.notes }
+    }
+
+    #[cfg(feature = "spanned")]
+    fn to_codespan(&self) -> codespan_reporting::files::SimpleFiles<&str, &str> {
+        let mut files = codespan_reporting::files::SimpleFiles::new();
+        for file in &self.files {
+            files.add(&*file.name, &*file.source);
+        }
+        files
+    }
+}
 
 #[derive(Debug)]
 enum ErrorData {
@@ -71,42 +135,74 @@ enum ErrorData {
 
 #[derive(Debug)]
 struct InputError {
-    #[cfg_attr(not(feature = "spanned"), allow(unused))]
-    file: FileSpec,
+    files: SpecFiles,
     source: InputErrorSource,
 }
 
 #[derive(Debug)]
 enum InputErrorSource {
-    Toml(toml::de::Error),
+    /// Failed to parse the file with the given index.
+    Toml(toml::de::Error, usize),
     Config(Vec<config::ValidationError>),
+    Include { file: PathBuf, #[cfg_attr(not(feature = "spanned"), allow(unused))] span: std::ops::Range<usize>, error: io::Error },
+    IncludeCycle { file: PathBuf, #[cfg_attr(not(feature = "spanned"), allow(unused))] span: std::ops::Range<usize> },
+    /// Warnings turned into errors by `deny_warnings`.
+    DeniedWarnings(Vec<config::Warning>),
 }
 
 impl InputError {
     #[cfg(feature = "spanned")]
-    fn to_diagnostics(&self) -> impl Iterator<Item=codespan_reporting::diagnostic::Diagnostic<()>> + '_ {
-        use codespan_reporting::diagnostic::Label;
+    fn to_diagnostics(&self) -> Vec<codespan_reporting::diagnostic::Diagnostic<usize>> {
+        use codespan_reporting::diagnostic::{Diagnostic, Label, Severity};
+
+        // Spans of errors are global, see `SpecFiles::parse`
+        let locate = move |diagnostic| self.files.locate_diagnostic(diagnostic);
 
         match &self.source {
-            InputErrorSource::Toml(error) => {
-                let diagnostic = codespan_reporting::diagnostic::Diagnostic::error()
+            InputErrorSource::Toml(error, file_id) => {
+                let diagnostic = Diagnostic::error()
                     .with_message("failed to parse config specification");
                 let diagnostic = match error.line_col() {
                     Some((line, col)) => {
-                        let line_sum = self.file.source().split('\n').take(line).map(|line| line.len()).sum::<usize>();
+                        let source = &self.files.files[*file_id].source;
+                        let line_sum = source.split('\n').take(line).map(|line| line.len()).sum::<usize>();
                         // The code above deosn't account for '\n' characters so we add the count
                         // here.
                         let start = line_sum + col + line;
                         let end = start + 1;
                         diagnostic.with_labels(vec![
-                            Label::primary((), start..end).with_message(error.to_string()),
+                            Label::primary(*file_id, start..end).with_message(error.to_string()),
                         ])
                     },
                     None => diagnostic.with_notes(vec![error.to_string()]),
                 };
-                Some(diagnostic).into_iter().chain(None.into_iter().flatten())
+                vec![diagnostic]
+            },
+            InputErrorSource::Config(errors) => errors.iter().map(|error| locate(error.to_diagnostic(()))).collect(),
+            InputErrorSource::DeniedWarnings(warnings) => {
+                warnings.iter().map(|warning| {
+                    let mut diagnostic = locate(warning.to_diagnostic(()));
+                    diagnostic.severity = Severity::Error;
+                    diagnostic.notes.push("Note: warnings are denied by `deny_warnings` in `general`".to_owned());
+                    diagnostic
+                }).collect()
+            },
+            InputErrorSource::Include { file, span, error } => {
+                let diagnostic = Diagnostic::error()
+                    .with_message(format!("failed to read included file {}", file.display()))
+                    .with_labels(vec![
+                        Label::primary((), span.clone()).with_message(error.to_string()),
+                    ]);
+                vec![locate(diagnostic)]
+            },
+            InputErrorSource::IncludeCycle { file, span } => {
+                let diagnostic = Diagnostic::error()
+                    .with_message(format!("the file {} includes itself", file.display()))
+                    .with_labels(vec![
+                        Label::primary((), span.clone()).with_message("the file is included here again"),
+                    ]);
+                vec![locate(diagnostic)]
             },
-            InputErrorSource::Config(errors) => None.into_iter().chain(Some(errors.iter().map(|error| error.to_diagnostic(()))).into_iter().flatten()),
         }
     }
 }
@@ -114,7 +210,8 @@ impl InputError {
 impl fmt::Display for InputError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match &self.source {
-            InputErrorSource::Toml(err) => write!(f, "failed to parse config specification: {}", err),
+            InputErrorSource::Toml(err, 0) => write!(f, "failed to parse config specification: {}", err),
+            InputErrorSource::Toml(err, file_id) => write!(f, "failed to parse included config specification {}: {}", self.files.files[*file_id].name, err),
             InputErrorSource::Config(errors) => {
                 for error in errors {
                     fmt::Display::fmt(&error, f)?;
@@ -122,17 +219,19 @@ impl fmt::Display for InputError {
                 }
                 Ok(())
             },
+            InputErrorSource::DeniedWarnings(warnings) => {
+                for warning in warnings {
+                    write!(f, "{} (warnings are denied)", warning)?;
+                    writeln!(f)?;
+                }
+                Ok(())
+            },
+            InputErrorSource::Include { file, error, .. } => write!(f, "failed to read included file {}: {}", file.display(), error),
+            InputErrorSource::IncludeCycle { file, .. } => write!(f, "the file {} includes itself", file.display()),
         }
     }
 }
 
-
-impl From<toml::de::Error> for InputErrorSource {
-    fn from(value: toml::de::Error) -> Self {
-        InputErrorSource::Toml(value)
-    }
-}
-
 impl From<Vec<config::ValidationError>> for InputErrorSource {
     fn from(value: Vec<config::ValidationError>) -> Self {
         InputErrorSource::Config(value)
@@ -186,12 +285,13 @@ impl fmt::Debug for Error {
             if let ErrorData::Input(error) = &self.data {
                 writeln!(f, "invalid config specification:")?;
                 let diagnostics = error.to_diagnostics();
+                let files = error.files.to_codespan();
 
                 let mut writer = NoColor::new(WrapIo(&mut *f));
                 let config = codespan_reporting::term::Config::default();
 
                 for diagnostic in diagnostics {
-                    match codespan_reporting::term::emit(&mut writer, &config, &error.file, &diagnostic) {
+                    match codespan_reporting::term::emit(&mut writer, &config, &files, &diagnostic) {
                         Ok(()) => (),
                         Err(codespan_reporting::files::Error::Io(_)) => return Err(fmt::Error),
                         Err(other) => panic!("unexpected error: {}", other),
@@ -246,13 +346,14 @@ impl Error {
 
         if let ErrorData::Input(error) = &self.data {
             let diagnostics = error.to_diagnostics();
+            let files = error.files.to_codespan();
 
             let writer = StandardStream::stderr(ColorChoice::Always);
             let mut writer = writer.lock();
             let config = codespan_reporting::term::Config::default();
 
             for diagnostic in diagnostics {
-                match codespan_reporting::term::emit(&mut writer, &config, &error.file, &diagnostic) {
+                match codespan_reporting::term::emit(&mut writer, &config, &files, &diagnostic) {
                     Ok(()) => (),
                     Err(codespan_reporting::files::Error::Io(error)) => return Err(error),
                     Err(other) => panic!("unexpected error: {}", other),
@@ -325,31 +426,113 @@ impl From<debconf::Error> for Error {
     }
 }
 
-fn load<S: Read, N: fmt::Display>(mut source: S, name: N) -> Result<config::Config, Error> {
+/// Warnings found in the specification by the lint pass.
+struct Warnings {
+    #[cfg_attr(not(feature = "spanned"), allow(unused))]
+    files: SpecFiles,
+    warnings: Vec<config::Warning>,
+}
+
+impl Warnings {
+    /// Prints the warnings so that cargo shows them when running a build script.
+    fn emit_cargo_warnings(&self) {
+        for line in self.render().lines().filter(|line| !line.is_empty()) {
+            println!("cargo:warning={}", line);
+        }
+    }
+
+    #[cfg(not(feature = "spanned"))]
+    fn render(&self) -> String {
+        self.warnings.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n")
+    }
+
+    #[cfg(feature = "spanned")]
+    fn render(&self) -> String {
+        use codespan_reporting::term::termcolor::NoColor;
+
+        let files = self.files.to_codespan();
+        let config = codespan_reporting::term::Config::default();
+        let mut writer = NoColor::new(Vec::new());
+        for warning in &self.warnings {
+            let diagnostic = self.files.locate_diagnostic(warning.to_diagnostic(()));
+            // Writing to Vec never fails
+            codespan_reporting::term::emit(&mut writer, &config, &files, &diagnostic).expect("failed to render warning");
+        }
+        String::from_utf8(writer.into_inner()).expect("codespan emitted invalid UTF-8")
+    }
+}
+
+/// Specification returned by `load`.
+struct LoadedSpec {
+    config: config::Config,
+    /// Paths of the files included by the specification.
+    included: Vec<PathBuf>,
+    warnings: Warnings,
+}
+
+/// Loads the specification and the files it includes.
+///
+/// Included files are resolved relative to the directory of `path` or the current directory if
+/// the path is unknown.
+fn load<S: Read, N: fmt::Display>(mut source: S, name: N, path: Option<&Path>) -> Result<LoadedSpec, Error> {
     let mut data = String::new();
     source.read_to_string(&mut data)?;
-    (|| {
-        let cfg = toml::from_str::<config::raw::Config>(&data)?;
+    let dir = path.and_then(Path::parent).unwrap_or_else(|| Path::new(""));
+    let mut stack = path.map(|path| path.canonicalize().unwrap_or_else(|_| path.to_owned())).into_iter().collect();
+    let mut files = SpecFiles::default();
+    let mut included = Vec::new();
+    let mut merged = HashSet::new();
+    let result = (|| {
+        let cfg = load_includes(&mut files, name.to_string(), data, dir, &mut stack, &mut included, &mut merged)?;
+        let warnings = cfg.lint();
+        let deny_warnings = cfg.deny_warnings();
         let cfg = cfg.validate()?;
+        if deny_warnings && !warnings.is_empty() {
+            return Err(InputErrorSource::DeniedWarnings(warnings));
+        }
 
-        Ok(cfg)
-    })().map_err(|source| {
-        #[cfg(feature = "spanned")]
-        {
-            ErrorData::Input(InputError { file: FileSpec::new(name.to_string(), data), source }).into()
+        Ok((cfg, warnings))
+    })();
+    match result {
+        Ok((config, warnings)) => Ok(LoadedSpec { config, included, warnings: Warnings { files, warnings } }),
+        Err(source) => Err(ErrorData::Input(InputError { files, source }).into()),
+    }
+}
+
+/// Parses the specification and merges the files it includes into it.
+///
+/// `stack` contains the canonical paths of the files currently being included to detect cycles,
+/// `merged` the canonical paths of all files included so far so that a file included from
+/// several places (e.g. common definitions) is merged only once.
+fn load_includes(files: &mut SpecFiles, name: String, data: String, dir: &Path, stack: &mut Vec<PathBuf>, included: &mut Vec<PathBuf>, merged: &mut HashSet<PathBuf>) -> Result<config::raw::Config, InputErrorSource> {
+    let mut cfg = files.parse(name, data)?;
+    // Included files are merged in reverse so that their items end up in the order they were
+    // included in
+    for include in cfg.take_includes().into_iter().rev() {
+        let span = include.start()..include.end();
+        let file = dir.join(include.into_inner());
+        let canonical = file.canonicalize().unwrap_or_else(|_| file.clone());
+        if stack.contains(&canonical) {
+            return Err(InputErrorSource::IncludeCycle { file, span });
         }
-        #[cfg(not(feature = "spanned"))]
-        {
-            let _ = name;
-            ErrorData::Input(InputError{ file: (), source }).into()
+        if !merged.insert(canonical.clone()) {
+            continue;
         }
-    })
+        let data = std::fs::read_to_string(&file).map_err(|error| InputErrorSource::Include { file: file.clone(), span: span.clone(), error })?;
+        stack.push(canonical);
+        let file_dir = file.parent().unwrap_or_else(|| Path::new("")).to_owned();
+        let included_cfg = load_includes(files, file.display().to_string(), data, &file_dir, stack, included, merged)?;
+        stack.pop();
+        included.push(file);
+        cfg.merge_included(included_cfg);
+    }
+    Ok(cfg)
 }
 
-fn load_from_file<P: AsRef<Path>>(source: P) -> Result<::config::Config, Error> {
+fn load_from_file<P: AsRef<Path>>(source: P) -> Result<LoadedSpec, Error> {
      let config_spec = std::fs::File::open(&source).map_err(|error| ErrorData::Open { file: source.as_ref().into(), error })?;
 
-     load(config_spec, source.as_ref().display())
+     load(config_spec, source.as_ref().display(), Some(source.as_ref()))
 }
 
 fn path_in_out_dir<P: AsRef<Path>>(file_name: P) -> Result<PathBuf, Error> {
@@ -386,17 +569,23 @@ fn generate_to_file<P: AsRef<Path> + Into<PathBuf>>(config_spec: &::config::Conf
 }
 
 fn load_and_generate_default<P: AsRef<Path>>(source: P, binary: Option<&str>) -> Result<::config::Config, Error> {
-    let config_spec = load_from_file(&source)?;
-    generate_to_file(&config_spec, default_out_file(binary)?)?;
+    let spec = load_from_file(&source)?;
+    spec.warnings.emit_cargo_warnings();
+    generate_to_file(&spec.config, default_out_file(binary)?)?;
     #[cfg(feature = "debconf")]
-    debconf::generate_if_requested(&config_spec)?;
+    debconf::generate_if_requested(&spec.config)?;
     println!("cargo:rerun-if-changed={}", source.as_ref().display());
-    Ok(config_spec)
+    for file in &spec.included {
+        println!("cargo:rerun-if-changed={}", file.display());
+    }
+    Ok(spec.config)
 }
 
 /// Generates the source code for you from provided `toml` configuration.
+///
+/// Files included by the configuration are resolved relative to the current directory.
 pub fn generate_source<S: Read, O: Write>(source: S, output: O) -> Result<(), Error> {
-    let cfg = load(source, "unknown file")?;
+    let cfg = load(source, "unknown file", None)?.config;
     
      ::fmt2io::write(output, |output| codegen::generate_code(&cfg, output)).map_err(Into::into)
 }
@@ -499,13 +688,23 @@ pub fn build_script_with_man_written_to<P: AsRef<Path>, M: AsRef<Path> + Into<Pa
 /// This is useful outside build scripts.
 #[cfg(feature = "man")]
 pub fn generate_man<M: LoadManifest, W: std::io::Write, S: AsRef<Path>>(source: S, mut dest: W, manifest: M) -> Result<(), Error> where Error: std::convert::From<<M as manifest::LoadManifest>::Error> {
-    let config_spec = load_from_file(&source)?;
+    let config_spec = load_from_file(&source)?.config;
     let manifest = manifest.load_manifest()?;
     let man_page = gen_man::generate_man_page(&config_spec, manifest.borrow())?;
     dest.write_all(man_page.as_bytes())?;
     Ok(())
 }
 
+/// Generates an example configuration file **only**.
+///
+/// The file is in TOML format and contains every parameter and switch with its documentation
+/// and its default or example value commented out. This is useful outside build scripts, e.g. to
+/// ship `/etc/app/app.conf.example` that never drifts out of sync with the specification.
+pub fn generate_config_template<W: std::io::Write, S: AsRef<Path>>(source: S, dest: W) -> Result<(), Error> {
+    let config_spec = load_from_file(&source)?.config;
+    ::fmt2io::write(dest, |dest| gen_config_template::generate_config_template(&config_spec, dest)).map_err(Into::into)
+}
+
 #[cfg(test)]
 #[deny(warnings)]
 pub(crate) mod tests {
@@ -645,6 +844,31 @@ conf_dir_param = "conf_dir"
 [param.foo]
 type = "u32"
 doc = "A foo"
+"#;
+
+    pub const CONF_DIR: &str =
+r#"
+[general]
+env_prefix = "TEST_CONF_DIR"
+conf_dir_param = "conf_dir"
+conf_dir_extension = "conf"
+
+[param.order]
+type = "String"
+merge_fn = "(|a: &mut String, b: String| a.push_str(&b))"
+doc = "Records the order in which the files were loaded"
+"#;
+
+    pub const CONF_DIR_UNFILTERED: &str =
+r#"
+[general]
+env_prefix = "TEST_CONF_DIR_UNFILTERED"
+conf_dir_param = "conf_dir"
+
+[param.order]
+type = "String"
+merge_fn = "(|a: &mut String, b: String| a.push_str(&b))"
+doc = "Records the order in which the files were loaded"
 "#;
 
     pub const CUSTOM_MERGE_FN: &str =
@@ -673,6 +897,624 @@ r#"
 program_name = "required"
 "#;
 
+    pub const SUBCOMMANDS: &str =
+r#"
+[general]
+env_prefix = "TEST_APP"
+
+[param.foo]
+type = "u32"
+doc = "A foo"
+
+[switch.verbose]
+abbr = "v"
+
+[subcommand.run]
+doc = "Runs the program"
+
+[subcommand.run.param.bar]
+type = "String"
+optional = false
+abbr = "b"
+doc = "A bar"
+
+[subcommand.run.switch.fast]
+abbr = "f"
+
+[subcommand.run_all.param.foo]
+type = "u32"
+default = "42"
+"#;
+
+    pub const MULTIPLE_VALUES: &str =
+r#"
+[general]
+env_prefix = "TEST_MULTIPLE_VALUES"
+
+[param.peer]
+type = "String"
+abbr = "p"
+multiple = true
+doc = "A peer to connect to"
+
+[param.port]
+type = "u16"
+optional = false
+multiple = true
+env_separator = ":"
+
+[param.level]
+type = "u8"
+default = "vec![1, 2]"
+multiple = true
+convert_into = "u32"
+"#;
+
+    pub const POSITIONALS: &str =
+r#"
+[param.foo]
+type = "u32"
+
+[switch.verbose]
+abbr = "v"
+
+[[positional]]
+name = "input"
+type = "String"
+optional = false
+doc = "The input"
+
+[[positional]]
+name = "output"
+type = "String"
+
+[[positional]]
+name = "numbers"
+type = "u32"
+multiple = true
+"#;
+
+    pub const CHOICE_VALUES: &str =
+r#"
+[general]
+env_prefix = "TEST_CHOICE_VALUES"
+
+[param.mode]
+values = ["fast", "slow", "auto"]
+default = "Mode::Auto"
+doc = "How fast to go"
+
+[param.log_level]
+values = ["error", "warn", "info", "very-verbose"]
+optional = false
+
+[param.feature]
+values = ["foo", "bar_baz"]
+multiple = true
+
+[subcommand.run.param.mode]
+values = ["once", "forever"]
+"#;
+
+    pub const SECTIONS: &str =
+r#"
+[general]
+env_prefix = "TEST_SECTIONS"
+
+[param.name]
+type = "String"
+doc = "Name of the node"
+
+[section.network]
+doc = "Network settings"
+
+[section.network.param.bind_addr]
+type = "String"
+abbr = "b"
+optional = false
+doc = "Address to bind to"
+
+[section.network.param.port]
+type = "u16"
+default = "8080"
+
+[section.network.switch.ipv6]
+doc = "Use IPv6"
+
+[section.storage.param.mode]
+values = ["memory", "disk"]
+default = "StorageMode::Disk"
+"#;
+
+    pub const CONSTRAINTS: &str =
+r#"
+[general]
+env_prefix = "TEST_CONSTRAINTS"
+
+[param.tls_cert]
+type = "String"
+requires = ["tls_key"]
+
+[param.tls_key]
+type = "String"
+
+[param.user]
+type = "String"
+required_unless = ["token", "anonymous"]
+
+[param.token]
+type = "String"
+
+[switch.anonymous]
+conflicts_with = ["user"]
+
+[switch.quiet]
+conflicts_with = ["verbose"]
+
+[switch.verbose]
+abbr = "v"
+count = true
+"#;
+
+    pub const VALUE_CONSTRAINTS: &str =
+r#"
+[general]
+env_prefix = "TEST_VALUE_CONSTRAINTS"
+
+[param.port]
+type = "u16"
+default = "8080"
+min = 1024
+doc = "Port to listen on"
+
+[param.ratio]
+type = "f64"
+min = 0.0
+max = 1
+
+[param.user]
+type = "String"
+optional = false
+min_len = 3
+max_len = 16
+pattern = "^[a-z]+$"
+
+[param.tag]
+type = "String"
+abbr = "t"
+multiple = true
+max_len = 8
+"#;
+
+    pub const VALIDATE_FN: &str =
+r#"
+[general]
+validate_fn = "crate::check_config"
+
+[param.port]
+type = "u16"
+default = "8080"
+validate_fn = "crate::check_port"
+
+[param.name]
+type = "String"
+validate_fn = "crate::check_name"
+
+[param.peer]
+type = "String"
+multiple = true
+validate_fn = "crate::check_name"
+
+[switch.tls]
+"#;
+
+    pub const ALIASES: &str =
+r#"
+[general]
+env_prefix = "TEST_ALIASES"
+
+[param.bind_addr]
+type = "String"
+aliases = ["listen"]
+doc = "Address to bind to"
+
+[param.threads]
+type = "u32"
+abbr = "j"
+deprecated = "the number of threads is chosen automatically"
+
+[switch.verbose]
+aliases = ["debug"]
+
+[switch.color]
+default = true
+aliases = ["colour"]
+
+[section.network.param.port]
+type = "u16"
+aliases = ["listen_port"]
+"#;
+
+    pub const SECRET: &str =
+r#"
+[general]
+env_prefix = "TEST_SECRET"
+
+[param.user]
+type = "String"
+default = "\"admin\".to_owned()"
+
+[param.password]
+type = "String"
+secret = true
+doc = "Password used to authenticate"
+
+[param.pin]
+type = "u32"
+default = "1234"
+secret = true
+
+[param.api_key]
+type = "String"
+multiple = true
+secret = true
+"#;
+
+    pub const OVERRIDES: &str =
+r#"
+[general]
+env_prefix = "TEST_OVERRIDES"
+
+[param.listen]
+type = "String"
+long = "listen-address"
+file_key = "listen-address"
+default = "\"127.0.0.1:8080\".to_owned()"
+doc = "Address to listen on"
+
+[param.proxy]
+type = "String"
+env = ["TEST_OVERRIDES_PROXY", "TEST_OVERRIDES_HTTP_PROXY"]
+doc = "Proxy used for outgoing connections"
+
+[param.log_filter]
+type = "String"
+env = "TEST_OVERRIDES_LOG"
+
+[switch.no_color]
+long = "plain"
+env = "TEST_OVERRIDES_NO_COLOR"
+doc = "Disable colored output"
+
+[section.db.param.url]
+type = "String"
+long = "database-url"
+env = "TEST_OVERRIDES_DATABASE_URL"
+"#;
+
+    pub const HIDDEN: &str =
+r#"
+[general]
+env_prefix = "TEST_HIDDEN"
+
+[param.host]
+type = "String"
+default = "\"localhost\".to_owned()"
+doc = "Host to connect to"
+category = "Networking"
+
+[param.port]
+type = "u16"
+default = "8080"
+doc = "Port to connect to"
+category = "Networking"
+
+[param.log_filter]
+type = "String"
+doc = "Which messages to log"
+
+[param.fail_after]
+type = "u32"
+hidden = true
+
+[switch.verbose]
+abbr = "v"
+doc = "Print more details"
+
+[switch.ipv6]
+doc = "Prefer IPv6"
+category = "Networking"
+
+[switch.debug_dump]
+hidden = true
+
+[section.cache.param.size]
+type = "u64"
+doc = "Maximum number of cached entries"
+category = "Caching"
+
+[section.cache.switch.disable]
+doc = "Disable caching"
+"#;
+
+    pub const NEGATABLE: &str =
+r#"
+[general]
+env_prefix = "TEST_NEGATABLE"
+
+[switch.verbose]
+abbr = "v"
+negatable = true
+doc = "Print more details"
+
+[switch.color]
+default = true
+negatable = true
+doc = "Colorize the output"
+
+[switch.fast]
+doc = "Skip slow checks"
+
+[section.cache.switch.enabled]
+negatable = true
+aliases = ["on"]
+doc = "Cache the results"
+"#;
+
+    pub const OPTIONAL_VALUE: &str =
+r#"
+[general]
+env_prefix = "TEST_OPTIONAL_VALUE"
+
+[param.color]
+type = "String"
+default = "\"auto\".to_owned()"
+optional_value = "\"always\".to_owned()"
+doc = "When to colorize the output"
+
+[param.log]
+type = "u8"
+abbr = "l"
+optional_value = "3"
+doc = "Verbosity of the log"
+
+[param.name]
+type = "String"
+doc = "Name of the instance"
+"#;
+
+    pub const CONF_FORMAT: &str =
+r#"
+[general]
+env_prefix = "TEST_CONF_FORMAT"
+conf_file_param = "config"
+conf_format = ["toml", "json", "yaml", "kv", "ini"]
+
+[param.port]
+type = "u16"
+doc = "Port to listen on"
+
+[param.connect]
+type = "String"
+multiple = true
+doc = "Peers to connect to"
+
+[switch.verbose]
+doc = "Log more details"
+
+[section.network]
+[section.network.param.bind_addr]
+type = "String"
+doc = "Address to bind to"
+"#;
+
+    pub const UNKNOWN_KEYS: &str =
+r#"
+[general]
+env_prefix = "TEST_UNKNOWN_KEYS"
+unknown_keys = "error"
+
+[param.bind_addr]
+type = "String"
+aliases = ["listen"]
+doc = "Address to bind to"
+
+[param.max_peers]
+type = "u32"
+file_key = "maxpeers"
+doc = "Maximum number of peers"
+
+[switch.verbose]
+doc = "Log more details"
+
+[section.network.param.port]
+type = "u16"
+doc = "Port to listen on"
+"#;
+
+    pub const UNKNOWN_KEYS_WARN: &str =
+r#"
+[general]
+env_prefix = "TEST_UNKNOWN_KEYS_WARN"
+unknown_keys = "warn"
+
+[param.bind_addr]
+type = "String"
+doc = "Address to bind to"
+"#;
+
+    pub const TYPES: &str =
+r#"
+[general]
+env_prefix = "TEST_TYPES"
+
+[param.timeout]
+type = "::configure_me::types::Duration"
+default = "::std::time::Duration::from_secs(30).into()"
+doc = "How long to wait for a response"
+
+[param.cache_size]
+type = "::configure_me::types::ByteSize"
+doc = "Maximum size of the cache"
+"#;
+
+    pub const PRINT_CONFIG: &str =
+r#"
+[general]
+env_prefix = "TEST_PRINT_CONFIG"
+print_config_switch = "print_config"
+check_config_switch = "check_config"
+
+[param.port]
+type = "u16"
+default = "8080"
+min = 1
+doc = "Port to listen on"
+
+[param.bind_addr]
+type = "String"
+file_key = "bind-addr"
+doc = "Address to bind to"
+
+[param.password]
+type = "String"
+secret = true
+doc = "Password of the admin"
+
+[param.timeout]
+type = "::configure_me::types::Duration"
+default = "::std::time::Duration::from_secs(30).into()"
+doc = "How long to wait for a response"
+
+[param.mode]
+values = ["fast", "slow"]
+default = "Mode::Fast"
+doc = "Mode of operation"
+
+[param.peer]
+type = "String"
+multiple = true
+doc = "Peer to connect to"
+
+[switch.verbose]
+doc = "Print more information"
+
+[section.network.param.max_peers]
+type = "u32"
+default = "8"
+doc = "Maximum number of peers"
+"#;
+
+    pub const RELOAD: &str =
+r#"
+[general]
+env_prefix = "TEST_RELOAD"
+reload = true
+
+[param.port]
+type = "u16"
+default = "8080"
+reloadable = false
+doc = "Port to listen on"
+
+[param.log_level]
+type = "String"
+default = "\"info\".to_owned()"
+doc = "Logging level"
+
+[switch.verbose]
+doc = "Print more information"
+
+[section.limits.param.max_peers]
+type = "u32"
+default = "8"
+doc = "Maximum number of peers"
+"#;
+
+    pub const RELOAD_SUBCOMMANDS: &str =
+r#"
+[general]
+env_prefix = "TEST_RELOAD_SUBCOMMANDS"
+reload = true
+
+[param.log_level]
+type = "String"
+default = "\"info\".to_owned()"
+doc = "Logging level"
+
+[subcommand.serve.param.port]
+type = "u16"
+default = "8080"
+reloadable = false
+doc = "Port to listen on"
+
+[subcommand.serve.param.workers]
+type = "u32"
+default = "4"
+doc = "Number of workers"
+
+[subcommand.check]
+doc = "Checks the configuration"
+"#;
+
+    pub const LINT: &str =
+r#"
+[general]
+env_prefix = "TEST_LINT"
+
+[param.port]
+type = "u16"
+doc = "Port to listen on"
+
+[param.host]
+type = "String"
+abbr = "H"
+argument = false
+doc = "Host to connect to"
+
+[param.log]
+type = "String"
+env = "Test_Lint_Port"
+
+[subcommand.run.switch.force]
+"#;
+
+    /// `MULTIPLE_PARAMS` split into files including each other.
+    pub const INCLUDE_FILES: &[(&str, &str)] = &[
+("main.toml", r#"
+include = ["common/params.toml"]
+
+[param.bar]
+type = "String"
+optional = true
+doc = "A very, very, very, very, very, very, very, very, very, very, very, very, very, very long documentation..."
+
+[param.baz]
+type = "String"
+optional = false
+doc = "A much, much, much, much, much, much, much, much, much, much, much, much, much, much, much, much, much, much, much, much, much, much, much, much, much, much, much, much, much, much, much, much, much, much, much, much, much, much, much, much, much, much longer documentation..."
+
+[switch.fast]
+default = true
+doc = "Determines whether to mine bitcoins fast or slowly"
+"#),
+("common/params.toml", r#"
+include = ["switches.toml"]
+
+[general]
+env_prefix = "TEST_APP"
+
+[param.foo]
+type = "u32"
+default = "42"
+doc = "A foo"
+"#),
+("common/switches.toml", r#"
+[switch.verbose]
+# doc intentionally missing, because it's obious...
+"#),
+    ];
+
     #[allow(unused)]
     pub struct ExpectedOutput {
         pub raw_config: &'static str,
@@ -738,17 +1580,21 @@ program_name = "required"
     };
 
     fn check(src: &str, expected: &str) {
-        use std::io::Write;
-
         let mut src = src.as_bytes();
         let mut out = Vec::new();
         generate_source(&mut src, &mut out).unwrap();
+        compare(&out, expected);
+    }
+
+    fn compare(out: &[u8], expected: &str) {
+        use std::io::Write;
+
         if out != expected.as_bytes() {
             let mut expected_temp = tempfile::Builder::new().prefix("expected").tempfile().unwrap();
             let mut out_temp = tempfile::Builder::new().prefix("output").tempfile().unwrap();
 
             expected_temp.write_all(expected.as_bytes()).unwrap();
-            out_temp.write_all(&out).unwrap();
+            out_temp.write_all(out).unwrap();
 
             std::process::Command::new("diff")
                 .arg("-C")
@@ -813,6 +1659,16 @@ program_name = "required"
         check(CONF_FILES, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/conf_files-config.rs")));
     }
 
+    #[test]
+    fn conf_dir() {
+        check(CONF_DIR, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/conf_dir-config.rs")));
+    }
+
+    #[test]
+    fn conf_dir_unfiltered() {
+        check(CONF_DIR_UNFILTERED, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/conf_dir_unfiltered-config.rs")));
+    }
+
     #[test]
     fn custom_merge_fn() {
         check(CUSTOM_MERGE_FN, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/with_custom_merge-config.rs")));
@@ -827,4 +1683,295 @@ program_name = "required"
     fn required_program_name() {
         check(REQUIRED_PROGRAM_NAME, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/required_program_name-config.rs")));
     }
+
+    #[test]
+    fn subcommands() {
+        check(SUBCOMMANDS, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/subcommands-config.rs")));
+    }
+
+    #[test]
+    fn multiple_values() {
+        check(MULTIPLE_VALUES, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/multiple_values-config.rs")));
+    }
+
+    #[test]
+    fn positionals() {
+        check(POSITIONALS, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/positionals-config.rs")));
+    }
+
+    #[test]
+    fn choice_values() {
+        check(CHOICE_VALUES, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/choice_values-config.rs")));
+    }
+
+    #[test]
+    fn sections() {
+        check(SECTIONS, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/sections-config.rs")));
+    }
+
+    #[test]
+    fn constraints() {
+        check(CONSTRAINTS, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/constraints-config.rs")));
+    }
+
+    #[test]
+    fn value_constraints() {
+        check(VALUE_CONSTRAINTS, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/value_constraints-config.rs")));
+    }
+
+    #[test]
+    fn validate_fn() {
+        check(VALIDATE_FN, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/validate_fn-config.rs")));
+    }
+
+    #[test]
+    fn aliases() {
+        check(ALIASES, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/aliases-config.rs")));
+    }
+
+    #[test]
+    fn secret() {
+        check(SECRET, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/secret-config.rs")));
+    }
+
+    #[test]
+    fn overrides() {
+        check(OVERRIDES, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/overrides-config.rs")));
+    }
+
+    #[test]
+    fn include() {
+        let dir = tempfile::tempdir().unwrap();
+        for (name, content) in INCLUDE_FILES {
+            let path = dir.path().join(name);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+        let spec = ::load_from_file(dir.path().join("main.toml")).unwrap();
+        assert_eq!(spec.included, [dir.path().join("common/switches.toml"), dir.path().join("common/params.toml")]);
+        let mut out = Vec::new();
+        ::fmt2io::write(&mut out, |out| ::codegen::generate_code(&spec.config, out)).unwrap();
+        compare(&out, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/multiple_params-config.rs")));
+    }
+
+    #[test]
+    fn include_diamond() {
+        let dir = tempfile::tempdir().unwrap();
+        let files = [
+            ("main.toml", "include = [\"b.toml\", \"c.toml\"]\n"),
+            ("b.toml", "include = [\"common.toml\"]\n\n[param.b]\ntype = \"u32\"\n"),
+            ("c.toml", "include = [\"./common.toml\"]\n\n[param.c]\ntype = \"u32\"\n"),
+            ("common.toml", "[general]\nenv_prefix = \"TEST_APP\"\n\n[param.common]\ntype = \"u32\"\n"),
+        ];
+        for (name, content) in &files {
+            std::fs::write(dir.path().join(name), content).unwrap();
+        }
+        let spec = ::load_from_file(dir.path().join("main.toml")).unwrap();
+        assert_eq!(spec.included, [dir.path().join("./common.toml"), dir.path().join("c.toml"), dir.path().join("b.toml")]);
+        let params = spec.config.params.iter().map(|param| param.name.as_snake_case()).collect::<Vec<_>>();
+        assert_eq!(params, ["b", "common", "c"]);
+    }
+
+    #[test]
+    fn include_cycle() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("main.toml"), "include = [\"other.toml\"]\n").unwrap();
+        std::fs::write(dir.path().join("other.toml"), "include = [\"main.toml\"]\n").unwrap();
+        let error = ::load_from_file(dir.path().join("main.toml")).err().unwrap().to_string();
+        assert_eq!(error, format!("the file {} includes itself", dir.path().join("main.toml").display()));
+    }
+
+    #[cfg(feature = "spanned")]
+    #[test]
+    fn include_error_location() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("main.toml"), "include = [\"other.toml\"]\n\n[param.foo]\ntype = \"u32\"\nabbr = \"x\"\n").unwrap();
+        std::fs::write(dir.path().join("other.toml"), "# Padding\n\n[param.bar]\ntype = \"u32\"\nabbr = \"x\"\n").unwrap();
+        let error = format!("{:?}", ::load_from_file(dir.path().join("main.toml")).err().unwrap());
+        let expected = format!(r#"invalid config specification:
+error: the option `x` appears more than once
+  ┌─ {other}:5:8
+  │
+5 │ abbr = "x"
+  │        --- the option was first defined here
+  │
+  ┌─ {main}:5:8
+  │
+5 │ abbr = "x"
+  │        ^^^ the option is repeated here
+
+"#, main = dir.path().join("main.toml").display(), other = dir.path().join("other.toml").display());
+        assert_eq!(error, expected);
+    }
+
+    #[test]
+    fn lint() {
+        let spec = ::load(LINT.as_bytes(), "lint", None).unwrap();
+        let warnings = spec.warnings.warnings.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(warnings, [
+            "the short option of host is unused because it can't be passed as an argument",
+            "the option log is not documented",
+            "the environment variables TEST_LINT_PORT and Test_Lint_Port only differ in case",
+            "the option force is not documented",
+        ]);
+    }
+
+    #[test]
+    fn deny_warnings() {
+        let spec = LINT.replace("[general]", "[general]\ndeny_warnings = true");
+        let error = generate_source(spec.as_bytes(), std::io::sink()).unwrap_err();
+        assert!(error.to_string().starts_with("the short option of host is unused because it can't be passed as an argument (warnings are denied)\n"));
+    }
+
+    #[test]
+    fn hidden() {
+        check(HIDDEN, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/hidden-config.rs")));
+    }
+
+    #[test]
+    fn negatable() {
+        check(NEGATABLE, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/negatable-config.rs")));
+    }
+
+    #[test]
+    fn optional_value() {
+        check(OPTIONAL_VALUE, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/optional_value-config.rs")));
+    }
+
+    #[test]
+    fn conf_format() {
+        check(CONF_FORMAT, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/conf_format-config.rs")));
+    }
+
+    #[test]
+    fn unknown_keys() {
+        check(UNKNOWN_KEYS, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/unknown_keys-config.rs")));
+    }
+
+    #[test]
+    fn unknown_keys_warn() {
+        check(UNKNOWN_KEYS_WARN, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/unknown_keys_warn-config.rs")));
+    }
+
+    #[test]
+    fn print_config() {
+        check(PRINT_CONFIG, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/print_config-config.rs")));
+    }
+
+    #[test]
+    fn reload() {
+        check(RELOAD, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/reload-config.rs")));
+    }
+
+    #[test]
+    fn reload_subcommands() {
+        check(RELOAD_SUBCOMMANDS, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/reload_subcommands-config.rs")));
+    }
+
+    #[test]
+    fn config_template() {
+        let config = ::load(PRINT_CONFIG.as_bytes(), "print_config", None).unwrap().config;
+        let mut template = String::new();
+        ::gen_config_template::generate_config_template(&config, &mut template).unwrap();
+        assert_eq!(template, "# Example configuration\n#\n# Uncomment and change the values you want to set.\n\n# Port to listen on\n#port = 8080\n\n# Address to bind to\n#bind-addr = \"\"\n\n# Password of the admin\n# This value is secret, make sure the file isn't readable by others.\n#password = \"\"\n\n# How long to wait for a response\n#timeout = \"30s\"\n\n# Mode of operation\n# Possible values: fast, slow\n#mode = \"fast\"\n\n# Peer to connect to\n#peer = []\n\n# Print more information\n#verbose = false\n\n[network]\n\n# Maximum number of peers\n#max_peers = 8\n");
+
+        let uncommented = template
+            .lines()
+            .map(|line| if line.starts_with("# ") || line == "#" { "" } else { line.trim_start_matches('#') })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let parsed = ::toml::from_str::<::toml::value::Table>(&uncommented).unwrap();
+        assert_eq!(parsed["network"]["max_peers"].as_integer(), Some(8));
+    }
+
+    #[test]
+    fn invalid_rust_code() {
+        let spec = r##"
+[param.bind]
+type = "std:net::Ipv4Addr"
+default = "::std::net::Ipv4Addr::new(0,0,0"
+
+[param.ports]
+type = "Vec<u16"
+convert_into = "std::collections::HashSet<u16>>"
+
+[param.name]
+type = "String"
+default = "\"foo.to_owned()"
+merge_fn = "crate::merge::"
+
+[param.callback]
+type = "Box<dyn Fn(&'static str) -> [u8; 4] + Send>"
+default = "Box::new(|_| [b'a', '\\'' as u8, 0, r#\"x\"#.len() as u8])"
+merge_fn = "(|a: &mut Box<dyn Fn(&'static str) -> [u8; 4] + Send>, b| *a = b)"
+"##;
+        let error = generate_source(spec.as_bytes(), std::io::sink()).unwrap_err().to_string();
+        let errors = error.lines().collect::<Vec<_>>();
+        assert_eq!(errors, [
+            "invalid configuration for field bind: invalid Rust code in type: single `:` in a path, did you mean `::`?",
+            "invalid configuration for field bind: invalid Rust code in default: `(` is never closed",
+            "invalid configuration for field ports: invalid Rust code in type: `<` is never closed",
+            "invalid configuration for field ports: invalid Rust code in convert_into: `>` doesn't close anything",
+            "invalid configuration for field name: invalid Rust code in default: unterminated string or character literal",
+            "invalid configuration for field name: invalid Rust code in merge_fn: `::` is not followed by a name",
+        ]);
+    }
+
+    #[test]
+    fn valid_rust_code() {
+        let spec = r##"
+[param.array_len]
+type = "[u8; N - 1]"
+
+[param.array_block]
+type = "[u8; { 4 }]"
+
+[param.array_char]
+type = "[u8; 'a' as usize]"
+
+[param.const_generic]
+type = "crate::Buffer<{ N + 1 }, -1>"
+
+[param.raw_ident]
+type = "crate::r#type::r#Kind"
+default = "crate::r#type::r#Kind::default()"
+
+[param.callback]
+type = "Box<dyn Fn([u8; 2 + 2]) -> [u8; { 4 }] + Send>"
+"##;
+        generate_source(spec.as_bytes(), std::io::sink()).unwrap();
+    }
+
+    #[test]
+    fn merge_fn_with_multiple() {
+        let spec = r#"
+[param.peer]
+type = "String"
+multiple = true
+merge_fn = "(|a: &mut Vec<String>, b: Vec<String>| a.extend(b))"
+"#;
+        let error = generate_source(spec.as_bytes(), std::io::sink()).unwrap_err().to_string();
+        assert_eq!(error, "invalid configuration for field peer: merge_fn can't be used with parameters accepting multiple values\n");
+    }
+
+    #[test]
+    fn invalid_conf_format() {
+        let error = generate_source(&b"[general]\nconf_format = [\"toml\", \"xml\"]\n"[..], std::io::sink()).unwrap_err().to_string();
+        assert_eq!(error, "the string `xml` is not a valid config file format\n");
+        let error = generate_source(&b"[general]\nconf_format = []\n"[..], std::io::sink()).unwrap_err().to_string();
+        assert_eq!(error, "the list of config file formats is empty\n");
+    }
+
+    #[test]
+    fn invalid_conf_dir_extension() {
+        let error = generate_source(&b"[general]\nconf_dir_param = \"conf_dir\"\nconf_dir_extension = \".\"\n"[..], std::io::sink()).unwrap_err().to_string();
+        assert_eq!(error, "the string `.` is not a valid extension of files in the config directory\n");
+        let error = generate_source(&b"[general]\nconf_dir_param = \"conf_dir\"\nconf_dir_extension = \"d/conf\"\n"[..], std::io::sink()).unwrap_err().to_string();
+        assert_eq!(error, "the string `d/conf` is not a valid extension of files in the config directory\n");
+    }
+
+    #[test]
+    fn types() {
+        check(TYPES, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/types-config.rs")));
+    }
 }
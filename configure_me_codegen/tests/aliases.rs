@@ -0,0 +1,90 @@
+macro_rules! test_name { () => { "aliases" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+
+// The env var test runs in parallel with the others so their warnings from env vars are ignored.
+fn warnings(metadata: &config::Metadata) -> Vec<String> {
+    metadata
+        .warnings
+        .iter()
+        .filter(|warning| match warning {
+            config::Warning::Alias { source: config::Source::EnvVar(_), .. } => false,
+            _ => true,
+        })
+        .map(ToString::to_string)
+        .collect()
+}
+
+#[test]
+fn current_names() {
+    let (config, _remaining, metadata) = config::Config::custom_args_and_optional_files(&["aliases", "--bind-addr", "0.0.0.0", "--verbose", "--no-color", "--network-port", "1234"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.bind_addr.as_ref().map(AsRef::as_ref), Some("0.0.0.0"));
+    assert!(config.verbose);
+    assert!(!config.color);
+    assert_eq!(config.network.port, Some(1234));
+    assert!(warnings(&metadata).is_empty());
+}
+
+#[test]
+fn args() {
+    let (config, _remaining, metadata) = config::Config::custom_args_and_optional_files(&["aliases", "--listen", "0.0.0.0", "--debug", "--no-colour", "--network-listen-port", "1234"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.bind_addr.as_ref().map(AsRef::as_ref), Some("0.0.0.0"));
+    assert!(config.verbose);
+    assert!(!config.color);
+    assert_eq!(config.network.port, Some(1234));
+    assert_eq!(warnings(&metadata), [
+        "Configuration parameter 'listen' from argument --listen was renamed to 'bind_addr'.",
+        "Configuration parameter 'debug' from argument --debug was renamed to 'verbose'.",
+        "Configuration parameter 'colour' from argument --no-colour was renamed to 'color'.",
+        "Configuration parameter 'network.listen_port' from argument --network-listen-port was renamed to 'network.port'.",
+    ]);
+}
+
+#[test]
+fn deprecated() {
+    let (config, _remaining, metadata) = config::Config::custom_args_and_optional_files(&["aliases", "-j", "4"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.threads, Some(4));
+    assert_eq!(warnings(&metadata), ["Configuration parameter 'threads' from argument -j is deprecated: the number of threads is chosen automatically"]);
+}
+
+#[test]
+fn config_file() {
+    let config_files = [concat!(env!("CARGO_MANIFEST_DIR"), "/tests/config_files/aliases.toml")];
+    let (config, _remaining, metadata) = config::Config::custom_args_and_optional_files(&["aliases"], &config_files).unwrap();
+    assert_eq!(config.bind_addr.as_ref().map(AsRef::as_ref), Some("127.0.0.1"));
+    assert_eq!(config.threads, Some(4));
+    assert!(!config.color);
+    assert_eq!(config.network.port, Some(9000));
+    assert_eq!(warnings(&metadata), [
+        format!("Configuration parameter 'listen' from config file {} was renamed to 'bind_addr'.", config_files[0]),
+        format!("Configuration parameter 'network.listen_port' from config file {} was renamed to 'network.port'.", config_files[0]),
+        format!("Configuration parameter 'colour' from config file {} was renamed to 'color'.", config_files[0]),
+        format!("Configuration parameter 'threads' from config file {} is deprecated: the number of threads is chosen automatically", config_files[0]),
+    ]);
+}
+
+#[test]
+fn current_name_takes_precedence() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["aliases", "--bind-addr", "0.0.0.0", "--listen", "127.0.0.1"], iter::empty::<PathBuf>()).unwrap();
+    // Arguments are processed in order, so the last one wins
+    assert_eq!(config.bind_addr.as_ref().map(AsRef::as_ref), Some("127.0.0.1"));
+
+    let config_files = [concat!(env!("CARGO_MANIFEST_DIR"), "/tests/config_files/aliases.toml")];
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["aliases", "--bind-addr", "0.0.0.0"], &config_files).unwrap();
+    assert_eq!(config.bind_addr.as_ref().map(AsRef::as_ref), Some("0.0.0.0"));
+}
+
+// Other tests don't check whether the switch is on.
+#[test]
+fn env_var() {
+    std::env::set_var("TEST_ALIASES_DEBUG", "1");
+    let result = config::Config::custom_args_and_optional_files(&["aliases"], iter::empty::<PathBuf>());
+    std::env::remove_var("TEST_ALIASES_DEBUG");
+    let (config, _remaining, metadata) = result.unwrap();
+    assert!(config.verbose);
+    let warnings = metadata.warnings.iter().map(ToString::to_string).collect::<Vec<_>>();
+    assert_eq!(warnings, ["Configuration parameter 'debug' from env var TEST_ALIASES_DEBUG was renamed to 'verbose'."]);
+}
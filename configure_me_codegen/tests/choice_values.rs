@@ -0,0 +1,76 @@
+macro_rules! test_name { () => { "choice_values" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+
+#[test]
+fn args() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["choice_values", "--mode", "fast", "--log-level", "very-verbose", "--feature", "foo", "run", "--mode", "forever"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.mode, config::Mode::Fast);
+    assert_eq!(config.log_level, config::LogLevel::VeryVerbose);
+    assert_eq!(config.feature, [config::Feature::Foo]);
+    match config.subcommand {
+        config::Subcommand::Run(run) => assert_eq!(run.mode, Some(config::RunMode::Forever)),
+    }
+}
+
+#[test]
+fn default_value() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["choice_values", "--log-level", "info", "run", "--mode", "once"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.mode, config::Mode::Auto);
+    assert!(config.feature.is_empty());
+}
+
+#[test]
+fn config_file() {
+    let config_files = [concat!(env!("CARGO_MANIFEST_DIR"), "/tests/config_files/modes.toml")];
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["choice_values", "--log-level", "warn", "run", "--mode", "once"], &config_files).unwrap();
+    assert_eq!(config.mode, config::Mode::Slow);
+    assert_eq!(config.feature, [config::Feature::BarBaz, config::Feature::Foo]);
+}
+
+// Other tests always pass the log level on the command line so the env var doesn't affect them.
+#[test]
+fn env_var() {
+    std::env::set_var("TEST_CHOICE_VALUES_LOG_LEVEL", "error");
+    let result = config::Config::custom_args_and_optional_files(&["choice_values", "run", "--mode", "once"], iter::empty::<PathBuf>());
+    std::env::remove_var("TEST_CHOICE_VALUES_LOG_LEVEL");
+    let (config, _remaining, _metadata) = result.unwrap();
+    assert_eq!(config.log_level, config::LogLevel::Error);
+}
+
+#[test]
+fn display() {
+    assert_eq!(config::LogLevel::VeryVerbose.to_string(), "very-verbose");
+    assert_eq!(config::Feature::BarBaz.to_string(), "bar_baz");
+}
+
+#[test]
+fn invalid_arg() {
+    let result = config::Config::custom_args_and_optional_files(&["choice_values", "--log-level", "info", "--mode", "turbo", "run", "--mode", "once"], iter::empty::<PathBuf>());
+    match result {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => assert_eq!(err.to_string(), "Failed to parse argument '--mode': invalid value 'turbo'.\n\nHint: the value must be one of `fast`, `slow`, `auto`."),
+    }
+}
+
+#[test]
+fn invalid_config_file() {
+    let config_files = [concat!(env!("CARGO_MANIFEST_DIR"), "/tests/config_files/invalid_mode.toml")];
+    let result = config::Config::custom_args_and_optional_files(&["choice_values", "--log-level", "info", "run", "--mode", "once"], &config_files);
+    match result {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => assert!(err.to_string().contains("unknown variant `turbo`, expected one of `fast`, `slow`, `auto`")),
+    }
+}
+
+#[test]
+fn print_help() {
+    let result = config::Config::custom_args_and_optional_files(&["choice_values", "--help"], iter::empty::<PathBuf>());
+    match result {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => assert_eq!(err.to_string(), "Usage: choice_values [--mode MODE] [--log-level LOG_LEVEL] [--feature FEATURE]... SUBCOMMAND\n\nArguments:\n        --mode         How fast to go (possible values: fast, slow, auto)\n        --log-level    Possible values: error, warn, info, very-verbose\n        --feature      Possible values: foo, bar_baz\n\nSubcommands:\n        run"),
+    }
+}
@@ -0,0 +1,23 @@
+macro_rules! test_name { () => { "conf_dir" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+
+#[test]
+fn sorted_and_filtered() {
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/config_files/conf_dir");
+    let args = ["conf_dir", "--conf-dir", dir];
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&args, iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.order.as_deref(), Some("abc"));
+}
+
+#[test]
+fn missing_dir() {
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/config_files/nonexistent");
+    match config::Config::custom_args_and_optional_files(&["conf_dir", "--conf-dir", dir], iter::empty::<PathBuf>()) {
+        Ok(_) => panic!("Loading a nonexistent directory shouldn't succeed"),
+        Err(err) => assert!(err.to_string().starts_with("Failed to open configuration directory")),
+    }
+}
@@ -0,0 +1,14 @@
+macro_rules! test_name { () => { "conf_dir_unfiltered" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+
+#[test]
+fn skips_backups() {
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/config_files/conf_dir");
+    let args = ["conf_dir_unfiltered", "--conf-dir", dir];
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&args, iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.order.as_deref(), Some("abc"));
+}
@@ -0,0 +1,56 @@
+macro_rules! test_name { () => { "conf_format" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+
+fn load(file: &str) -> config::Config {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["conf_format"], iter::once(file)).unwrap();
+    config
+}
+
+fn check(config: config::Config, port: u16) {
+    assert_eq!(config.port, Some(port));
+    assert_eq!(config.connect, ["a", "b"]);
+    assert!(config.verbose);
+    assert_eq!(config.network.bind_addr.as_ref().map(String::as_str), Some("127.0.0.1"));
+}
+
+#[test]
+fn toml() {
+    check(load("tests/config_files/conf_format.toml"), 8080);
+}
+
+#[test]
+fn json() {
+    check(load("tests/config_files/conf_format.json"), 8081);
+}
+
+#[test]
+fn yaml() {
+    check(load("tests/config_files/conf_format.yml"), 8082);
+}
+
+#[test]
+fn key_value() {
+    check(load("tests/config_files/conf_format.conf"), 8083);
+}
+
+#[test]
+fn ini() {
+    check(load("tests/config_files/conf_format.ini"), 8084);
+}
+
+#[test]
+fn unknown_extension_falls_back_to_first() {
+    check(load("tests/config_files/conf_format.cfg"), 8080);
+}
+
+#[test]
+fn invalid_key_value() {
+    let error = match config::Config::custom_args_and_optional_files(&["conf_format"], iter::once("tests/config_files/conf_format_invalid.conf")) {
+        Ok(_) => panic!("invalid config file accepted"),
+        Err(error) => error,
+    };
+    assert_eq!(error.to_string(), "Failed to parse configuration file tests/config_files/conf_format_invalid.conf: line 2: expected `verbose=value`");
+}
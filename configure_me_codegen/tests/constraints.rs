@@ -0,0 +1,52 @@
+macro_rules! test_name { () => { "constraints" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+
+fn validation_error(args: &[&str]) -> String {
+    match config::Config::custom_args_and_optional_files(args, iter::empty::<PathBuf>()) {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => err.to_string(),
+    }
+}
+
+#[test]
+fn satisfied() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["constraints", "--tls-cert", "cert.pem", "--tls-key", "key.pem", "--user", "satoshi", "-vv"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.tls_cert, Some("cert.pem".to_owned()));
+    assert_eq!(config.tls_key, Some("key.pem".to_owned()));
+    assert_eq!(config.user, Some("satoshi".to_owned()));
+    assert_eq!(config.verbose, 2);
+}
+
+#[test]
+fn requires() {
+    let err = validation_error(&["constraints", "--tls-cert", "cert.pem", "--user", "satoshi"]);
+    assert_eq!(err, "Invalid configuration: Configuration parameter 'tls_cert' requires 'tls_key' to be specified as well.");
+}
+
+#[test]
+fn requires_is_one_way() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["constraints", "--tls-key", "key.pem", "--user", "satoshi"], iter::empty::<PathBuf>()).unwrap();
+    assert!(config.tls_cert.is_none());
+}
+
+#[test]
+fn conflicts_with() {
+    let err = validation_error(&["constraints", "--anonymous", "--user", "satoshi"]);
+    assert_eq!(err, "Invalid configuration: Configuration parameters 'anonymous' and 'user' can't be specified at the same time.");
+    let err = validation_error(&["constraints", "--user", "satoshi", "--quiet", "-v"]);
+    assert_eq!(err, "Invalid configuration: Configuration parameters 'quiet' and 'verbose' can't be specified at the same time.");
+}
+
+#[test]
+fn required_unless() {
+    let err = validation_error(&["constraints"]);
+    assert_eq!(err, "Invalid configuration: Configuration parameter 'user' not specified, it's required unless 'token' or 'anonymous' is specified.");
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["constraints", "--token", "secret"], iter::empty::<PathBuf>()).unwrap();
+    assert!(config.user.is_none());
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["constraints", "--anonymous", "--quiet"], iter::empty::<PathBuf>()).unwrap();
+    assert!(config.anonymous);
+}
@@ -0,0 +1,5 @@
+    pub bind_addr: Option<String>,
+    pub threads: Option<u32>,
+    pub verbose: bool,
+    pub color: bool,
+    pub network: NetworkSection,
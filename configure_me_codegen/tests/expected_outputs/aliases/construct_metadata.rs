@@ -0,0 +1,8 @@
+            warnings: ::std::mem::take(&mut config.warnings),
+            sources: Sources {
+                bind_addr: config.bind_addr_source.clone(),
+                threads: config.threads_source.clone(),
+                network_port: config.network.port_source.clone(),
+                verbose: config.verbose_source.clone().unwrap_or(Source::Default),
+                color: config.color_source.clone().unwrap_or(Source::Default),
+            },
@@ -0,0 +1,20 @@
+            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &["--help", "--bind-addr", "--listen", "--threads", "--network-port", "--network-listen-port", "--verbose", "--debug", "--no-color", "--no-colour"]) {
+                Some(known) => write!(f, "An unknown argument '{}' was specified, did you mean '{}'?", arg, known),
+                None => write!(f, "An unknown argument '{}' was specified.", arg),
+            },
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [ARGUMENTS...]\n\nArguments:\n        --bind-addr       Address to bind to\n        -j, --threads\n\n        --verbose\nno-color\n\n\nArguments in section network:\n        --network-port\n", program_name),
+        ArgParseError::FieldBindAddr(err) => {
+            write!(f, "Failed to parse argument '--bind-addr': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldThreads(err) => {
+            write!(f, "Failed to parse argument '--threads': {}.\n\nHint: the value must be ", err)?;
+            <u32 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldNetworkPort(err) => {
+            write!(f, "Failed to parse argument '--network-port': {}.\n\nHint: the value must be ", err)?;
+            <u16 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
@@ -0,0 +1,21 @@
+        EnvParseError::FieldBindAddr(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_ALIASES_BIND_ADDR': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldThreads(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_ALIASES_THREADS': {}.\n\nHint: the value must be ", err)?;
+            <u32 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldNetworkPort(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_ALIASES_NETWORK_PORT': {}.\n\nHint: the value must be ", err)?;
+            <u16 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldVerbose(ref err) => {
+            write!(f, "Invalid value '{:?}' for 'TEST_ALIASES_VERBOSE'.\n\nHint: the allowed values are 0, false, 1, true.", err)
+        },
+        EnvParseError::FieldColor(ref err) => {
+            write!(f, "Invalid value '{:?}' for 'TEST_ALIASES_COLOR'.\n\nHint: the allowed values are 0, false, 1, true.", err)
+        },
@@ -0,0 +1,5 @@
+    FieldBindAddr(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldThreads(<u32 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldVerbose(::std::ffi::OsString),
+    FieldColor(::std::ffi::OsString),
+    FieldNetworkPort(<u16 as ::configure_me::parse_arg::ParseArg>::Error),
@@ -0,0 +1,44 @@
+            let mut config: Self = ::configure_me::toml::from_slice(&config_content).map_err(|error| super::Error::ConfigParsing { file: config_file_name.as_ref().into(), error })?;
+            if let Some(value) = config.listen.take() {
+                config.warnings.push(super::Warning::Alias { alias: "listen", name: "bind_addr", source: super::Source::ConfigFile(config_file_name.as_ref().into()) });
+                if config.bind_addr.is_none() {
+                    config.bind_addr = Some(value);
+                }
+            }
+            if let Some(value) = config.network.listen_port.take() {
+                config.warnings.push(super::Warning::Alias { alias: "network.listen_port", name: "network.port", source: super::Source::ConfigFile(config_file_name.as_ref().into()) });
+                if config.network.port.is_none() {
+                    config.network.port = Some(value);
+                }
+            }
+            if let Some(value) = config.debug.take() {
+                config.warnings.push(super::Warning::Alias { alias: "debug", name: "verbose", source: super::Source::ConfigFile(config_file_name.as_ref().into()) });
+                if config.verbose.is_none() {
+                    config.verbose = Some(value);
+                }
+            }
+            if let Some(value) = config.colour.take() {
+                config.warnings.push(super::Warning::Alias { alias: "colour", name: "color", source: super::Source::ConfigFile(config_file_name.as_ref().into()) });
+                if config.color.is_none() {
+                    config.color = Some(value);
+                }
+            }
+            if config.threads.is_some() {
+                config.warnings.push(super::Warning::Deprecated { name: "threads", source: super::Source::ConfigFile(config_file_name.as_ref().into()), message: "the number of threads is chosen automatically" });
+            }
+            if config.bind_addr.is_some() {
+                config.bind_addr_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.threads.is_some() {
+                config.threads_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.network.port.is_some() {
+                config.network.port_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.verbose.is_some() {
+                config.verbose_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.color.is_some() {
+                config.color_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            Ok(config)
@@ -0,0 +1,42 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--bind-addr", &arg, &mut iter) {
+                    let bind_addr = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--bind-addr"), ArgParseError::FieldBindAddr))?;
+
+                    self.bind_addr = Some(bind_addr);
+                    self.bind_addr_source = Some(super::Source::Argument("--bind-addr"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--listen", &arg, &mut iter) {
+                    let bind_addr = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--listen"), ArgParseError::FieldBindAddr))?;
+
+                    self.warnings.push(super::Warning::Alias { alias: "listen", name: "bind_addr", source: super::Source::Argument("--listen") });
+                    self.bind_addr = Some(bind_addr);
+                    self.bind_addr_source = Some(super::Source::Argument("--listen"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--threads", &arg, &mut iter) {
+                    let threads = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--threads"), ArgParseError::FieldThreads))?;
+
+                    self.warnings.push(super::Warning::Deprecated { name: "threads", source: super::Source::Argument("--threads"), message: "the number of threads is chosen automatically" });
+                    self.threads = Some(threads);
+                    self.threads_source = Some(super::Source::Argument("--threads"));
+                } else if arg == *"--verbose" {
+                    self.verbose = Some(true);
+                    self.verbose_source = Some(super::Source::Argument("--verbose"));
+                } else if arg == *"--debug" {
+                    self.warnings.push(super::Warning::Alias { alias: "debug", name: "verbose", source: super::Source::Argument("--debug") });
+                    self.verbose = Some(true);
+                    self.verbose_source = Some(super::Source::Argument("--debug"));
+                } else if arg == *"--no-color" {
+                    self.color = Some(false);
+                    self.color_source = Some(super::Source::Argument("--no-color"));
+                } else if arg == *"--no-colour" {
+                    self.warnings.push(super::Warning::Alias { alias: "colour", name: "color", source: super::Source::Argument("--no-colour") });
+                    self.color = Some(false);
+                    self.color_source = Some(super::Source::Argument("--no-colour"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--network-port", &arg, &mut iter) {
+                    let network_port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--network-port"), ArgParseError::FieldNetworkPort))?;
+
+                    self.network.port = Some(network_port);
+                    self.network.port_source = Some(super::Source::Argument("--network-port"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--network-listen-port", &arg, &mut iter) {
+                    let network_port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--network-listen-port"), ArgParseError::FieldNetworkPort))?;
+
+                    self.warnings.push(super::Warning::Alias { alias: "network.listen_port", name: "network.port", source: super::Source::Argument("--network-listen-port") });
+                    self.network.port = Some(network_port);
+                    self.network.port_source = Some(super::Source::Argument("--network-listen-port"));
@@ -0,0 +1,70 @@
+        if let Some(val) = ::std::env::var_os("TEST_ALIASES_LISTEN") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldBindAddr)?;
+            self.warnings.push(super::Warning::Alias { alias: "listen", name: "bind_addr", source: super::Source::EnvVar("TEST_ALIASES_LISTEN") });
+            self.bind_addr = Some(val);
+            self.bind_addr_source = Some(super::Source::EnvVar("TEST_ALIASES_LISTEN"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_ALIASES_BIND_ADDR") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldBindAddr)?;
+            self.bind_addr = Some(val);
+            self.bind_addr_source = Some(super::Source::EnvVar("TEST_ALIASES_BIND_ADDR"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_ALIASES_THREADS") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldThreads)?;
+            self.warnings.push(super::Warning::Deprecated { name: "threads", source: super::Source::EnvVar("TEST_ALIASES_THREADS"), message: "the number of threads is chosen automatically" });
+            self.threads = Some(val);
+            self.threads_source = Some(super::Source::EnvVar("TEST_ALIASES_THREADS"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_ALIASES_NETWORK_LISTEN_PORT") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldNetworkPort)?;
+            self.warnings.push(super::Warning::Alias { alias: "network.listen_port", name: "network.port", source: super::Source::EnvVar("TEST_ALIASES_NETWORK_LISTEN_PORT") });
+            self.network.port = Some(val);
+            self.network.port_source = Some(super::Source::EnvVar("TEST_ALIASES_NETWORK_LISTEN_PORT"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_ALIASES_NETWORK_PORT") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldNetworkPort)?;
+            self.network.port = Some(val);
+            self.network.port_source = Some(super::Source::EnvVar("TEST_ALIASES_NETWORK_PORT"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_ALIASES_DEBUG") {
+            self.warnings.push(super::Warning::Alias { alias: "debug", name: "verbose", source: super::Source::EnvVar("TEST_ALIASES_DEBUG") });
+            if val == *"1" || val == *"true" {
+                self.verbose = Some(true);
+            } else if val == *"0" || val == *"false" {
+                self.verbose = Some(false);
+            } else {
+                return Err(super::EnvParseError::FieldVerbose(val).into());
+            }
+            self.verbose_source = Some(super::Source::EnvVar("TEST_ALIASES_DEBUG"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_ALIASES_VERBOSE") {
+            if val == *"1" || val == *"true" {
+                self.verbose = Some(true);
+            } else if val == *"0" || val == *"false" {
+                self.verbose = Some(false);
+            } else {
+                return Err(super::EnvParseError::FieldVerbose(val).into());
+            }
+            self.verbose_source = Some(super::Source::EnvVar("TEST_ALIASES_VERBOSE"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_ALIASES_COLOUR") {
+            self.warnings.push(super::Warning::Alias { alias: "colour", name: "color", source: super::Source::EnvVar("TEST_ALIASES_COLOUR") });
+            if val == *"1" || val == *"true" {
+                self.color = Some(true);
+            } else if val == *"0" || val == *"false" {
+                self.color = Some(false);
+            } else {
+                return Err(super::EnvParseError::FieldColor(val).into());
+            }
+            self.color_source = Some(super::Source::EnvVar("TEST_ALIASES_COLOUR"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_ALIASES_COLOR") {
+            if val == *"1" || val == *"true" {
+                self.color = Some(true);
+            } else if val == *"0" || val == *"false" {
+                self.color = Some(false);
+            } else {
+                return Err(super::EnvParseError::FieldColor(val).into());
+            }
+            self.color_source = Some(super::Source::EnvVar("TEST_ALIASES_COLOR"));
+        }
@@ -0,0 +1,26 @@
+            if other.bind_addr.is_some() {
+                self.bind_addr = other.bind_addr;
+            }
+            if other.bind_addr_source.is_some() {
+                self.bind_addr_source = other.bind_addr_source;
+            }
+            if other.threads.is_some() {
+                self.threads = other.threads;
+            }
+            if other.threads_source.is_some() {
+                self.threads_source = other.threads_source;
+            }
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
+            if other.verbose_source.is_some() {
+                self.verbose_source = other.verbose_source;
+            }
+            if other.color.is_some() {
+                self.color = other.color;
+            }
+            if other.color_source.is_some() {
+                self.color_source = other.color_source;
+            }
+            self.network.merge_in(other.network);
+            self.warnings.extend(other.warnings);
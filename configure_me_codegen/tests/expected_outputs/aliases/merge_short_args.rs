@@ -0,0 +1,5 @@
+                        } else if short == 'j' {
+                            self.threads = Some(shorts.parse_remaining(&mut iter).map_err(|err| err.map_or(ArgParseError::MissingArgument("-j"), ArgParseError::FieldThreads))?);
+                            self.threads_source = Some(super::Source::Argument("-j"));
+                            self.warnings.push(super::Warning::Deprecated { name: "threads", source: super::Source::Argument("-j"), message: "the number of threads is chosen automatically" });
+                            break;
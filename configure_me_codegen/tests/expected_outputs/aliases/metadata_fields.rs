@@ -0,0 +1,4 @@
+    /// Problems found in the configuration, such as use of deprecated options
+    pub warnings: Vec<Warning>,
+    /// Where the value of each parameter and switch came from
+    pub sources: Sources,
@@ -0,0 +1,19 @@
+        bind_addr: Option<String>,
+        threads: Option<u32>,
+        verbose: Option<bool>,
+        color: Option<bool>,
+        listen: Option<String>,
+        debug: Option<bool>,
+        colour: Option<bool>,
+        #[serde(skip)]
+        pub bind_addr_source: Option<super::Source>,
+        #[serde(skip)]
+        pub threads_source: Option<super::Source>,
+        #[serde(skip)]
+        pub verbose_source: Option<super::Source>,
+        #[serde(skip)]
+        pub color_source: Option<super::Source>,
+        #[serde(default)]
+        pub network: NetworkSection,
+        #[serde(skip)]
+        pub warnings: Vec<super::Warning>,
@@ -0,0 +1,29 @@
+
+    #[derive(Deserialize, Default)]
+    #[serde(crate = "crate::configure_me::serde")]
+    pub struct NetworkSection {
+        port: Option<u16>,
+        listen_port: Option<u16>,
+        #[serde(skip)]
+        pub port_source: Option<super::Source>,
+    }
+
+    #[automatically_derived]
+    impl NetworkSection {
+        pub fn validate(self) -> Result<super::NetworkSection, ValidationError> {
+            let port = self.port;
+
+            Ok(super::NetworkSection {
+                port,
+            })
+        }
+
+        pub fn merge_in(&mut self, other: Self) {
+            if other.port.is_some() {
+                self.port = other.port;
+            }
+            if other.port_source.is_some() {
+                self.port_source = other.port_source;
+            }
+        }
+    }
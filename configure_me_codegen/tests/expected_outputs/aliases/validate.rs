@@ -0,0 +1,11 @@
+            let bind_addr = self.bind_addr;
+            let threads = self.threads;
+            let network = self.network.validate()?;
+
+            Ok(super::Config {
+                bind_addr,
+                threads,
+                verbose: self.verbose.unwrap_or(false),
+                color: self.color.unwrap_or(true),
+                network,
+            })
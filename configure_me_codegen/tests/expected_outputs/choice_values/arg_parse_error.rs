@@ -0,0 +1,5 @@
+    FieldMode(<Mode as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldLogLevel(<LogLevel as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldFeature(<Feature as ::configure_me::parse_arg::ParseArg>::Error),
+    UnknownSubcommand(String),
+    SubcommandRun(RunArgParseError),
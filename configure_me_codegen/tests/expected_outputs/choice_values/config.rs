@@ -0,0 +1,4 @@
+    pub mode: Mode,
+    pub log_level: LogLevel,
+    pub feature: Vec<Feature>,
+    pub subcommand: Subcommand,
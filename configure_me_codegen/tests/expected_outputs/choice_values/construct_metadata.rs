@@ -0,0 +1,11 @@
+            sources: Sources {
+                mode: config.mode_source.clone().unwrap_or(Source::Default),
+                log_level: config.log_level_source.clone().unwrap_or(Source::Default),
+                feature: config.feature_source.clone().unwrap_or(Source::Default),
+                subcommand: match &config.subcommand {
+                    Some(raw::Subcommand::Run(config)) => SubcommandSources::Run(RunSources {
+                        mode: config.mode_source.clone(),
+                    }),
+                    None => return Err(ValidationError::MissingSubcommand.into()),
+                },
+            },
@@ -0,0 +1,22 @@
+            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &["--help", "--mode", "--log-level", "--feature"]) {
+                Some(known) => write!(f, "An unknown argument '{}' was specified, did you mean '{}'?", arg, known),
+                None => write!(f, "An unknown argument '{}' was specified.", arg),
+            },
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--mode MODE] [--log-level LOG_LEVEL] [--feature FEATURE]... SUBCOMMAND\n\nArguments:\n        --mode         How fast to go (possible values: fast, slow, auto)\n        --log-level    Possible values: error, warn, info, very-verbose\n        --feature      Possible values: foo, bar_baz\n\nSubcommands:\n        run", program_name),
+        ArgParseError::FieldMode(err) => {
+            write!(f, "Failed to parse argument '--mode': {}.\n\nHint: the value must be ", err)?;
+            <Mode as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldLogLevel(err) => {
+            write!(f, "Failed to parse argument '--log-level': {}.\n\nHint: the value must be ", err)?;
+            <LogLevel as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldFeature(err) => {
+            write!(f, "Failed to parse argument '--feature': {}.\n\nHint: the value must be ", err)?;
+            <Feature as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::UnknownSubcommand(subcommand) => write!(f, "An unknown subcommand '{}' was specified.", subcommand),
+        ArgParseError::SubcommandRun(err) => ::std::fmt::Display::fmt(err, f),
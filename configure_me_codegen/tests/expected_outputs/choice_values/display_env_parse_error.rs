@@ -0,0 +1,15 @@
+        EnvParseError::FieldMode(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_CHOICE_VALUES_MODE': {}.\n\nHint: the value must be ", err)?;
+            <Mode as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldLogLevel(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_CHOICE_VALUES_LOG_LEVEL': {}.\n\nHint: the value must be ", err)?;
+            <LogLevel as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldFeature(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_CHOICE_VALUES_FEATURE': {}.\n\nHint: the value must be ", err)?;
+            <Feature as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
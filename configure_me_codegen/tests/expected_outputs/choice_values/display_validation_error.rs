@@ -0,0 +1,2 @@
+            ValidationError::MissingField(field) => write!(f, "Configuration parameter '{}' not specified.", field),
+            ValidationError::MissingSubcommand => write!(f, "Missing subcommand."),
@@ -0,0 +1,3 @@
+    FieldMode(<Mode as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldLogLevel(<LogLevel as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldFeature(<Feature as ::configure_me::parse_arg::ParseArg>::Error),
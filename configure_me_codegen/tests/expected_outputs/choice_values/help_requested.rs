@@ -0,0 +1 @@
+            Err(err @ Error::Arguments(ArgParseError::SubcommandRun(RunArgParseError::HelpRequested(_)))) |
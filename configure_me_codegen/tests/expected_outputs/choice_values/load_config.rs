@@ -0,0 +1,11 @@
+            let mut config: Self = ::configure_me::toml::from_slice(&config_content).map_err(|error| super::Error::ConfigParsing { file: config_file_name.as_ref().into(), error })?;
+            if config.mode.is_some() {
+                config.mode_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.log_level.is_some() {
+                config.log_level_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.feature.is_some() {
+                config.feature_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            Ok(config)
@@ -0,0 +1,15 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--mode", &arg, &mut iter) {
+                    let mode = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--mode"), ArgParseError::FieldMode))?;
+
+                    self.mode = Some(mode);
+                    self.mode_source = Some(super::Source::Argument("--mode"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--log-level", &arg, &mut iter) {
+                    let log_level = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--log-level"), ArgParseError::FieldLogLevel))?;
+
+                    self.log_level = Some(log_level);
+                    self.log_level_source = Some(super::Source::Argument("--log-level"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--feature", &arg, &mut iter) {
+                    let feature = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--feature"), ArgParseError::FieldFeature))?;
+
+                    self.feature.get_or_insert_with(Vec::new).push(feature);
+                    self.feature_source = Some(super::Source::Argument("--feature"));
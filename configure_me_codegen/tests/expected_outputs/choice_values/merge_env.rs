@@ -0,0 +1,19 @@
+        if let Some(val) = ::std::env::var_os("TEST_CHOICE_VALUES_MODE") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldMode)?;
+            self.mode = Some(val);
+            self.mode_source = Some(super::Source::EnvVar("TEST_CHOICE_VALUES_MODE"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_CHOICE_VALUES_LOG_LEVEL") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldLogLevel)?;
+            self.log_level = Some(val);
+            self.log_level_source = Some(super::Source::EnvVar("TEST_CHOICE_VALUES_LOG_LEVEL"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_CHOICE_VALUES_FEATURE") {
+            let val = match val.into_string() {
+                Ok(val) => val.split(",").map(|item| <Feature as ::configure_me::parse_arg::ParseArg>::parse_arg(item.as_ref())).collect::<Result<Vec<_>, _>>(),
+                // Values that aren't valid UTF-8 can't be split
+                Err(val) => <Feature as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(val).map(|item| vec![item]),
+            }.map_err(super::EnvParseError::FieldFeature)?;
+            self.feature = Some(val);
+            self.feature_source = Some(super::Source::EnvVar("TEST_CHOICE_VALUES_FEATURE"));
+        }
@@ -0,0 +1,21 @@
+            if other.mode.is_some() {
+                self.mode = other.mode;
+            }
+            if other.mode_source.is_some() {
+                self.mode_source = other.mode_source;
+            }
+            if other.log_level.is_some() {
+                self.log_level = other.log_level;
+            }
+            if other.log_level_source.is_some() {
+                self.log_level_source = other.log_level_source;
+            }
+            if other.feature.is_some() {
+                self.feature = other.feature;
+            }
+            if other.feature_source.is_some() {
+                self.feature_source = other.feature_source;
+            }
+            if other.subcommand.is_some() {
+                self.subcommand = other.subcommand;
+            }
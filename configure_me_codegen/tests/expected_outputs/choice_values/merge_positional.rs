@@ -0,0 +1,7 @@
+                } else if arg == *"run" {
+                    let mut subcommand = super::raw_run::Config::default();
+                    let first_remaining = subcommand.merge_args(&program_path, &mut iter)?;
+                    self.subcommand = Some(Subcommand::Run(subcommand));
+                    return Ok((program_path, first_remaining.into_iter().chain(iter)));
+                } else {
+                    return Err(ArgParseError::UnknownSubcommand(arg.to_string_lossy().into_owned()).into());
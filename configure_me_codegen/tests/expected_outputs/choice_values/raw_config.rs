@@ -0,0 +1,11 @@
+        mode: Option<Mode>,
+        log_level: Option<LogLevel>,
+        feature: Option<Vec<Feature>>,
+        #[serde(skip)]
+        pub mode_source: Option<super::Source>,
+        #[serde(skip)]
+        pub log_level_source: Option<super::Source>,
+        #[serde(skip)]
+        pub feature_source: Option<super::Source>,
+        #[serde(skip)]
+        pub subcommand: Option<Subcommand>,
@@ -0,0 +1 @@
+    use super::{Mode, LogLevel, Feature};
@@ -0,0 +1,13 @@
+
+    pub enum Subcommand {
+        Run(super::raw_run::Config),
+    }
+
+    #[automatically_derived]
+    impl Subcommand {
+        pub fn validate(self) -> Result<super::Subcommand, ValidationError> {
+            match self {
+                Subcommand::Run(config) => config.validate().map(super::Subcommand::Run),
+            }
+        }
+    }
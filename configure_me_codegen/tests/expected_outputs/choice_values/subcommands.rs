@@ -0,0 +1,152 @@
+/// Subcommand selected on the command line
+pub enum Subcommand {
+    Run(RunConfig),
+}
+
+/// Possible values of the `mode` parameter
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RunMode {
+    Once,
+    Forever,
+}
+
+#[automatically_derived]
+impl ::std::str::FromStr for RunMode {
+    type Err = InvalidValue;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "once" => Ok(RunMode::Once),
+            "forever" => Ok(RunMode::Forever),
+            _ => Err(InvalidValue(s.to_owned())),
+        }
+    }
+}
+
+#[automatically_derived]
+impl ::configure_me::parse_arg::ParseArgFromStr for RunMode {
+    fn describe_type<W: ::std::fmt::Write>(mut writer: W) -> ::std::fmt::Result {
+        write!(writer, "one of `once`, `forever`")
+    }
+}
+
+#[automatically_derived]
+impl ::std::fmt::Display for RunMode {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match self {
+            RunMode::Once => f.write_str("once"),
+            RunMode::Forever => f.write_str("forever"),
+        }
+    }
+}
+
+#[automatically_derived]
+impl<'de> ::configure_me::serde::Deserialize<'de> for RunMode {
+    fn deserialize<D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = <String as ::configure_me::serde::Deserialize>::deserialize(deserializer)?;
+        value.parse().map_err(|_| <D::Error as ::configure_me::serde::de::Error>::unknown_variant(&value, &["once", "forever"]))
+    }
+}
+
+pub enum RunArgParseError {
+    MissingArgument(&'static str),
+    UnknownArgument(String),
+    HelpRequested(String),
+
+    FieldMode(<RunMode as ::configure_me::parse_arg::ParseArg>::Error),
+}
+
+#[automatically_derived]
+impl ::std::fmt::Display for RunArgParseError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use self::RunArgParseError as ArgParseError;
+
+        match self {
+            ArgParseError::MissingArgument(arg) => write!(f, "A value to argument '{}' is missing.", arg),
+            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &["--help", "--mode"]) {
+                Some(known) => write!(f, "An unknown argument '{}' was specified, did you mean '{}'?", arg, known),
+                None => write!(f, "An unknown argument '{}' was specified.", arg),
+            },
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--mode MODE]\n\nArguments:\n        --mode    Possible values: once, forever", program_name),
+        ArgParseError::FieldMode(err) => {
+            write!(f, "Failed to parse argument '--mode': {}.\n\nHint: the value must be ", err)?;
+            <RunMode as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        }
+    }
+}
+
+#[automatically_derived]
+impl ::std::fmt::Debug for RunArgParseError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::std::fmt::Display::fmt(self, f)
+    }
+}
+
+#[automatically_derived]
+impl From<RunArgParseError> for Error {
+    fn from(err: RunArgParseError) -> Self {
+        Error::Arguments(ArgParseError::SubcommandRun(err))
+    }
+}
+
+mod raw_run {
+    use super::{RunArgParseError as ArgParseError, ValidationError};
+    use super::RunMode;
+
+    #[derive(Default)]
+    pub struct Config {
+        mode: Option<RunMode>,
+        pub mode_source: Option<super::Source>,
+    }
+
+    #[automatically_derived]
+    impl Config {
+        pub fn validate(self) -> Result<super::RunConfig, ValidationError> {
+            let mode = self.mode;
+
+            Ok(super::RunConfig {
+                mode,
+            })
+        }
+
+        pub fn merge_args<I: Iterator<Item=::std::ffi::OsString>>(&mut self, program_path: &Option<std::path::PathBuf>, mut iter: I) -> Result<Option<::std::ffi::OsString>, super::Error> {
+            while let Some(arg) = iter.next() {
+                if arg == *"--" {
+                    return Ok(None);
+                } else if (arg == *"--help") || (arg == *"-h") {
+                    return Err(ArgParseError::HelpRequested(format!("{} run", program_path.as_ref().unwrap().to_string_lossy())).into());
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--mode", &arg, &mut iter) {
+                    let mode = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--mode"), ArgParseError::FieldMode))?;
+
+                    self.mode = Some(mode);
+                    self.mode_source = Some(super::Source::Argument("--mode"));
+                } else if let Some(mut shorts) = ::configure_me::parse_arg::iter_short(&arg) {
+                    for short in &mut shorts {
+                        if short == 'h' {
+                            return Err(ArgParseError::HelpRequested(format!("{} run", program_path.as_ref().unwrap().to_string_lossy())).into())
+                        } else {
+                            let mut arg = String::with_capacity(2);
+                            arg.push('-');
+                            arg.push(short);
+                            return Err(ArgParseError::UnknownArgument(arg).into());
+                        }
+                    }
+                } else if arg.to_str().unwrap_or("").starts_with("--") {
+                    return Err(ArgParseError::UnknownArgument(arg.into_string().unwrap()).into());
+                } else {
+                    return Ok(Some(arg));
+                }
+            }
+
+            Ok(None)
+        }
+    }
+}
+
+/// Configuration of the `run` subcommand
+pub struct RunConfig {
+    pub mode: Option<RunMode>,
+}
+
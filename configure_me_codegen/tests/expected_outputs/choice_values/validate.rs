@@ -0,0 +1,11 @@
+            let mode = self.mode.unwrap_or_else(|| { Mode::Auto });
+            let log_level = self.log_level.ok_or(ValidationError::MissingField("log_level"))?;
+            let feature = self.feature.unwrap_or_default();
+            let subcommand = self.subcommand.ok_or(ValidationError::MissingSubcommand)?.validate()?;
+
+            Ok(super::Config {
+                mode,
+                log_level,
+                feature,
+                subcommand,
+            })
@@ -0,0 +1,155 @@
+/// Error returned when parsing a value that is not allowed
+#[derive(Debug, Clone)]
+pub struct InvalidValue(String);
+
+#[automatically_derived]
+impl ::std::fmt::Display for InvalidValue {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "invalid value '{}'", self.0)
+    }
+}
+
+/// Possible values of the `mode` parameter
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Mode {
+    Fast,
+    Slow,
+    Auto,
+}
+
+#[automatically_derived]
+impl ::std::str::FromStr for Mode {
+    type Err = InvalidValue;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fast" => Ok(Mode::Fast),
+            "slow" => Ok(Mode::Slow),
+            "auto" => Ok(Mode::Auto),
+            _ => Err(InvalidValue(s.to_owned())),
+        }
+    }
+}
+
+#[automatically_derived]
+impl ::configure_me::parse_arg::ParseArgFromStr for Mode {
+    fn describe_type<W: ::std::fmt::Write>(mut writer: W) -> ::std::fmt::Result {
+        write!(writer, "one of `fast`, `slow`, `auto`")
+    }
+}
+
+#[automatically_derived]
+impl ::std::fmt::Display for Mode {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match self {
+            Mode::Fast => f.write_str("fast"),
+            Mode::Slow => f.write_str("slow"),
+            Mode::Auto => f.write_str("auto"),
+        }
+    }
+}
+
+#[automatically_derived]
+impl<'de> ::configure_me::serde::Deserialize<'de> for Mode {
+    fn deserialize<D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = <String as ::configure_me::serde::Deserialize>::deserialize(deserializer)?;
+        value.parse().map_err(|_| <D::Error as ::configure_me::serde::de::Error>::unknown_variant(&value, &["fast", "slow", "auto"]))
+    }
+}
+
+/// Possible values of the `log_level` parameter
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    VeryVerbose,
+}
+
+#[automatically_derived]
+impl ::std::str::FromStr for LogLevel {
+    type Err = InvalidValue;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "error" => Ok(LogLevel::Error),
+            "warn" => Ok(LogLevel::Warn),
+            "info" => Ok(LogLevel::Info),
+            "very-verbose" => Ok(LogLevel::VeryVerbose),
+            _ => Err(InvalidValue(s.to_owned())),
+        }
+    }
+}
+
+#[automatically_derived]
+impl ::configure_me::parse_arg::ParseArgFromStr for LogLevel {
+    fn describe_type<W: ::std::fmt::Write>(mut writer: W) -> ::std::fmt::Result {
+        write!(writer, "one of `error`, `warn`, `info`, `very-verbose`")
+    }
+}
+
+#[automatically_derived]
+impl ::std::fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match self {
+            LogLevel::Error => f.write_str("error"),
+            LogLevel::Warn => f.write_str("warn"),
+            LogLevel::Info => f.write_str("info"),
+            LogLevel::VeryVerbose => f.write_str("very-verbose"),
+        }
+    }
+}
+
+#[automatically_derived]
+impl<'de> ::configure_me::serde::Deserialize<'de> for LogLevel {
+    fn deserialize<D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = <String as ::configure_me::serde::Deserialize>::deserialize(deserializer)?;
+        value.parse().map_err(|_| <D::Error as ::configure_me::serde::de::Error>::unknown_variant(&value, &["error", "warn", "info", "very-verbose"]))
+    }
+}
+
+/// Possible values of the `feature` parameter
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Feature {
+    Foo,
+    BarBaz,
+}
+
+#[automatically_derived]
+impl ::std::str::FromStr for Feature {
+    type Err = InvalidValue;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "foo" => Ok(Feature::Foo),
+            "bar_baz" => Ok(Feature::BarBaz),
+            _ => Err(InvalidValue(s.to_owned())),
+        }
+    }
+}
+
+#[automatically_derived]
+impl ::configure_me::parse_arg::ParseArgFromStr for Feature {
+    fn describe_type<W: ::std::fmt::Write>(mut writer: W) -> ::std::fmt::Result {
+        write!(writer, "one of `foo`, `bar_baz`")
+    }
+}
+
+#[automatically_derived]
+impl ::std::fmt::Display for Feature {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match self {
+            Feature::Foo => f.write_str("foo"),
+            Feature::BarBaz => f.write_str("bar_baz"),
+        }
+    }
+}
+
+#[automatically_derived]
+impl<'de> ::configure_me::serde::Deserialize<'de> for Feature {
+    fn deserialize<D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = <String as ::configure_me::serde::Deserialize>::deserialize(deserializer)?;
+        value.parse().map_err(|_| <D::Error as ::configure_me::serde::de::Error>::unknown_variant(&value, &["foo", "bar_baz"]))
+    }
+}
+
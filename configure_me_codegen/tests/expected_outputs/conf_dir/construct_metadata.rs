@@ -0,0 +1,3 @@
+            sources: Sources {
+                order: config.order_source.clone(),
+            },
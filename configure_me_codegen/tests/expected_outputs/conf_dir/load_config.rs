@@ -0,0 +1,5 @@
+            let mut config: Self = ::configure_me::toml::from_slice(&config_content).map_err(|error| super::Error::ConfigParsing { file: config_file_name.as_ref().into(), error })?;
+            if config.order.is_some() {
+                config.order_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            Ok(config)
@@ -0,0 +1,10 @@
+            if let Some(order) = other.order {
+                if let Some(order_old) = &mut self.order {
+                    (|a: &mut String, b: String| a.push_str(&b))(order_old, order);
+                } else {
+                    self.order = Some(order);
+                }
+            }
+            if other.order_source.is_some() {
+                self.order_source = other.order_source;
+            }
@@ -0,0 +1 @@
+        let _ = program_name;
@@ -0,0 +1,3 @@
+        order: Option<String>,
+        #[serde(skip)]
+        pub order_source: Option<super::Source>,
@@ -0,0 +1,5 @@
+            let order = self.order;
+
+            Ok(super::Config {
+                order,
+            })
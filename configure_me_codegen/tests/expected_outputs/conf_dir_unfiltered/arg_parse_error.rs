@@ -0,0 +1,3 @@
+    FieldOrder(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    OpenConfDir(std::io::Error, std::path::PathBuf),
+    ReadConfDir(std::io::Error, std::path::PathBuf),
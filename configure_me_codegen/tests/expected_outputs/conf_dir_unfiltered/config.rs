@@ -0,0 +1 @@
+    pub order: Option<String>,
@@ -0,0 +1,12 @@
+            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &["--help", "--conf-dir", "--order"]) {
+                Some(known) => write!(f, "An unknown argument '{}' was specified, did you mean '{}'?", arg, known),
+                None => write!(f, "An unknown argument '{}' was specified.", arg),
+            },
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--conf-dir CONF_DIR] [--order ORDER]\n\nArguments:\n        --conf-dir    Load configuration from files in this directory.\n        --order       Records the order in which the files were loaded", program_name),
+        ArgParseError::FieldOrder(err) => {
+            write!(f, "Failed to parse argument '--order': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::OpenConfDir(err, dir) => write!(f, "Failed to open configuration directory {}: {}", dir.display(), err),
+        ArgParseError::ReadConfDir(err, dir) => write!(f, "Failed to read configuration directory {}: {}", dir.display(), err),
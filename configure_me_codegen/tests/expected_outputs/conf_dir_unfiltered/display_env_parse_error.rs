@@ -0,0 +1,5 @@
+        EnvParseError::FieldOrder(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_CONF_DIR_UNFILTERED_ORDER': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
@@ -0,0 +1 @@
+    FieldOrder(<String as ::configure_me::parse_arg::ParseArg>::Error),
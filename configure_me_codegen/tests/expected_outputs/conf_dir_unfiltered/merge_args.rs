@@ -0,0 +1,40 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--conf-dir", &arg, &mut iter) {
+                    let dir_path: std::path::PathBuf = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--conf-dir"), |never| match never {}))?;
+
+                    let dir = match std::fs::read_dir(&dir_path) {
+                        Ok(dir) => dir,
+                        Err(err) => return Err(ArgParseError::OpenConfDir(err, dir_path).into()),
+                    };
+
+                    let mut files = Vec::new();
+                    for file in dir {
+                        let file = match file {
+                            Ok(file) => file,
+                            Err(err) => return Err(ArgParseError::ReadConfDir(err, dir_path).into()),
+                        };
+
+                        let path = file.path();
+                        let name = file.file_name();
+                        let name = name.to_string_lossy();
+                        // Skips hidden files, backups, leftovers of package upgrades and anything else that isn't a file
+                        if name.starts_with('.') || name.ends_with('~') || name.contains(".dpkg-") || !path.is_file() {
+                            continue;
+                        }
+                        files.push(path);
+                    }
+                    // Sorted so that later files override earlier ones regardless of the filesystem
+                    files.sort();
+
+                    for file in files {
+                        let config = Config::load(file)?;
+                        self.merge_in(config);
+                    }
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--order", &arg, &mut iter) {
+                    let order = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--order"), ArgParseError::FieldOrder))?;
+
+                    if let Some(order_old) = &mut self.order {
+                        (|a: &mut String, b: String| a.push_str(&b))(order_old, order);
+                    } else {
+                        self.order = Some(order);
+                    }
+                    self.order_source = Some(super::Source::Argument("--order"));
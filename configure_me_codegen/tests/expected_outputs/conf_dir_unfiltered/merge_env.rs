@@ -0,0 +1,9 @@
+        if let Some(val) = ::std::env::var_os("TEST_CONF_DIR_UNFILTERED_ORDER") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldOrder)?;
+            if let Some(order_old) = &mut self.order {
+                (|a: &mut String, b: String| a.push_str(&b))(order_old, val);
+            } else {
+                self.order = Some(val);
+            }
+            self.order_source = Some(super::Source::EnvVar("TEST_CONF_DIR_UNFILTERED_ORDER"));
+        }
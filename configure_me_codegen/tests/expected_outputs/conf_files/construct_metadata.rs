@@ -0,0 +1,3 @@
+            sources: Sources {
+                foo: config.foo_source.clone(),
+            },
@@ -10,16 +10,31 @@
                         Err(err) => return Err(ArgParseError::OpenConfDir(err, dir_path).into()),
                     };
 
+                    let mut files = Vec::new();
                     for file in dir {
                         let file = match file {
                             Ok(file) => file,
                             Err(err) => return Err(ArgParseError::ReadConfDir(err, dir_path).into()),
                         };
 
-                        let config = Config::load(file.path())?;
+                        let path = file.path();
+                        let name = file.file_name();
+                        let name = name.to_string_lossy();
+                        // Skips hidden files, backups, leftovers of package upgrades and anything else that isn't a file
+                        if name.starts_with('.') || name.ends_with('~') || name.contains(".dpkg-") || !path.is_file() {
+                            continue;
+                        }
+                        files.push(path);
+                    }
+                    // Sorted so that later files override earlier ones regardless of the filesystem
+                    files.sort();
+
+                    for file in files {
+                        let config = Config::load(file)?;
                         self.merge_in(config);
                     }
                 } else if let Some(value) = ::configure_me::parse_arg::match_arg("--foo", &arg, &mut iter) {
                     let foo = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--foo"), ArgParseError::FieldFoo))?;
 
                     self.foo = Some(foo);
+                    self.foo_source = Some(super::Source::Argument("--foo"));
@@ -1 +1,3 @@
         foo: Option<u32>,
+        #[serde(skip)]
+        pub foo_source: Option<super::Source>,
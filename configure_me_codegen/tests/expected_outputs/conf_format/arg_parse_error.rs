@@ -0,0 +1,3 @@
+    FieldPort(<u16 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldConnect(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldNetworkBindAddr(<String as ::configure_me::parse_arg::ParseArg>::Error),
@@ -0,0 +1,4 @@
+    pub port: Option<u16>,
+    pub connect: Vec<String>,
+    pub verbose: bool,
+    pub network: NetworkSection,
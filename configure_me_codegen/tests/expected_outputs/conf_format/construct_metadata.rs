@@ -0,0 +1,6 @@
+            sources: Sources {
+                port: config.port_source.clone(),
+                connect: config.connect_source.clone().unwrap_or(Source::Default),
+                network_bind_addr: config.network.bind_addr_source.clone(),
+                verbose: config.verbose_source.clone().unwrap_or(Source::Default),
+            },
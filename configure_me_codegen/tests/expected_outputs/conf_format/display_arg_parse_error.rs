@@ -0,0 +1,20 @@
+            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &["--help", "--config", "--port", "--connect", "--network-bind-addr", "--verbose"]) {
+                Some(known) => write!(f, "An unknown argument '{}' was specified, did you mean '{}'?", arg, known),
+                None => write!(f, "An unknown argument '{}' was specified.", arg),
+            },
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [ARGUMENTS...]\n\nArguments:\n        --config               Load configuration from this file.\n        --port                 Port to listen on\n        --connect              Peers to connect to\n        --verbose              Log more details\n\nArguments in section network:\n        --network-bind-addr    Address to bind to", program_name),
+        ArgParseError::FieldPort(err) => {
+            write!(f, "Failed to parse argument '--port': {}.\n\nHint: the value must be ", err)?;
+            <u16 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldConnect(err) => {
+            write!(f, "Failed to parse argument '--connect': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldNetworkBindAddr(err) => {
+            write!(f, "Failed to parse argument '--network-bind-addr': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
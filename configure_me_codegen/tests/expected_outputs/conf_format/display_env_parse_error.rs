@@ -0,0 +1,18 @@
+        EnvParseError::FieldPort(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_CONF_FORMAT_PORT': {}.\n\nHint: the value must be ", err)?;
+            <u16 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldConnect(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_CONF_FORMAT_CONNECT': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldNetworkBindAddr(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_CONF_FORMAT_NETWORK_BIND_ADDR': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldVerbose(ref err) => {
+            write!(f, "Invalid value '{:?}' for 'TEST_CONF_FORMAT_VERBOSE'.\n\nHint: the allowed values are 0, false, 1, true.", err)
+        },
@@ -0,0 +1,4 @@
+    FieldPort(<u16 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldConnect(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldVerbose(::std::ffi::OsString),
+    FieldNetworkBindAddr(<String as ::configure_me::parse_arg::ParseArg>::Error),
@@ -0,0 +1,14 @@
+            let mut config: Self = ::configure_me::format::Format::detect(config_file_name.as_ref(), &[::configure_me::format::Format::Toml, ::configure_me::format::Format::Json, ::configure_me::format::Format::Yaml, ::configure_me::format::Format::KeyValue, ::configure_me::format::Format::Ini]).deserialize(&config_content).map_err(|error| super::Error::ConfigParsing { file: config_file_name.as_ref().into(), error })?;
+            if config.port.is_some() {
+                config.port_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.connect.is_some() {
+                config.connect_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.network.bind_addr.is_some() {
+                config.network.bind_addr_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.verbose.is_some() {
+                config.verbose_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            Ok(config)
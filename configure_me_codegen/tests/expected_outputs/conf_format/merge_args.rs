@@ -0,0 +1,22 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--config", &arg, &mut iter) {
+                    let file_path: std::path::PathBuf = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--config"), |never| match never {}))?;
+                    let config = Config::load(file_path)?;
+                    self.merge_in(config);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--port", &arg, &mut iter) {
+                    let port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--port"), ArgParseError::FieldPort))?;
+
+                    self.port = Some(port);
+                    self.port_source = Some(super::Source::Argument("--port"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--connect", &arg, &mut iter) {
+                    let connect = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--connect"), ArgParseError::FieldConnect))?;
+
+                    self.connect.get_or_insert_with(Vec::new).push(connect);
+                    self.connect_source = Some(super::Source::Argument("--connect"));
+                } else if arg == *"--verbose" {
+                    self.verbose = Some(true);
+                    self.verbose_source = Some(super::Source::Argument("--verbose"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--network-bind-addr", &arg, &mut iter) {
+                    let network_bind_addr = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--network-bind-addr"), ArgParseError::FieldNetworkBindAddr))?;
+
+                    self.network.bind_addr = Some(network_bind_addr);
+                    self.network.bind_addr_source = Some(super::Source::Argument("--network-bind-addr"));
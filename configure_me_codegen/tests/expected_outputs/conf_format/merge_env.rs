@@ -0,0 +1,29 @@
+        if let Some(val) = ::std::env::var_os("TEST_CONF_FORMAT_PORT") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldPort)?;
+            self.port = Some(val);
+            self.port_source = Some(super::Source::EnvVar("TEST_CONF_FORMAT_PORT"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_CONF_FORMAT_CONNECT") {
+            let val = match val.into_string() {
+                Ok(val) => val.split(",").map(|item| <String as ::configure_me::parse_arg::ParseArg>::parse_arg(item.as_ref())).collect::<Result<Vec<_>, _>>(),
+                // Values that aren't valid UTF-8 can't be split
+                Err(val) => <String as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(val).map(|item| vec![item]),
+            }.map_err(super::EnvParseError::FieldConnect)?;
+            self.connect = Some(val);
+            self.connect_source = Some(super::Source::EnvVar("TEST_CONF_FORMAT_CONNECT"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_CONF_FORMAT_NETWORK_BIND_ADDR") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldNetworkBindAddr)?;
+            self.network.bind_addr = Some(val);
+            self.network.bind_addr_source = Some(super::Source::EnvVar("TEST_CONF_FORMAT_NETWORK_BIND_ADDR"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_CONF_FORMAT_VERBOSE") {
+            if val == *"1" || val == *"true" {
+                self.verbose = Some(true);
+            } else if val == *"0" || val == *"false" {
+                self.verbose = Some(false);
+            } else {
+                return Err(super::EnvParseError::FieldVerbose(val).into());
+            }
+            self.verbose_source = Some(super::Source::EnvVar("TEST_CONF_FORMAT_VERBOSE"));
+        }
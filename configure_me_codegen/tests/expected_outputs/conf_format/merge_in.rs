@@ -0,0 +1,19 @@
+            if other.port.is_some() {
+                self.port = other.port;
+            }
+            if other.port_source.is_some() {
+                self.port_source = other.port_source;
+            }
+            if other.connect.is_some() {
+                self.connect = other.connect;
+            }
+            if other.connect_source.is_some() {
+                self.connect_source = other.connect_source;
+            }
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
+            if other.verbose_source.is_some() {
+                self.verbose_source = other.verbose_source;
+            }
+            self.network.merge_in(other.network);
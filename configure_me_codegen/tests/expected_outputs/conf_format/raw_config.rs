@@ -0,0 +1,11 @@
+        port: Option<u16>,
+        connect: Option<Vec<String>>,
+        verbose: Option<bool>,
+        #[serde(skip)]
+        pub port_source: Option<super::Source>,
+        #[serde(skip)]
+        pub connect_source: Option<super::Source>,
+        #[serde(skip)]
+        pub verbose_source: Option<super::Source>,
+        #[serde(default)]
+        pub network: NetworkSection,
@@ -0,0 +1,28 @@
+
+    #[derive(Deserialize, Default)]
+    #[serde(crate = "crate::configure_me::serde")]
+    pub struct NetworkSection {
+        bind_addr: Option<String>,
+        #[serde(skip)]
+        pub bind_addr_source: Option<super::Source>,
+    }
+
+    #[automatically_derived]
+    impl NetworkSection {
+        pub fn validate(self) -> Result<super::NetworkSection, ValidationError> {
+            let bind_addr = self.bind_addr;
+
+            Ok(super::NetworkSection {
+                bind_addr,
+            })
+        }
+
+        pub fn merge_in(&mut self, other: Self) {
+            if other.bind_addr.is_some() {
+                self.bind_addr = other.bind_addr;
+            }
+            if other.bind_addr_source.is_some() {
+                self.bind_addr_source = other.bind_addr_source;
+            }
+        }
+    }
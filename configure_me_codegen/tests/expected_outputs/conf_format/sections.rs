@@ -0,0 +1,5 @@
+/// Configuration of the `network` section
+pub struct NetworkSection {
+    pub bind_addr: Option<String>,
+}
+
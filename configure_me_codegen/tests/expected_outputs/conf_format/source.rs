@@ -0,0 +1,33 @@
+/// Where the value of a parameter came from.
+#[derive(Debug, Clone)]
+pub enum Source {
+    Default,
+    ConfigFile(::std::path::PathBuf),
+    EnvVar(&'static str),
+    Argument(&'static str),
+}
+
+#[automatically_derived]
+impl ::std::fmt::Display for Source {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match self {
+            Source::Default => write!(f, "the default value"),
+            Source::ConfigFile(file) => write!(f, "config file {}", file.display()),
+            Source::EnvVar(var) => write!(f, "env var {}", var),
+            Source::Argument(arg) => write!(f, "argument {}", arg),
+        }
+    }
+}
+
+/// Where the value of each parameter and switch came from.
+///
+/// The fields of sections are prefixed with the name of the section.
+/// Optional parameters that weren't set have no source.
+#[derive(Debug)]
+pub struct Sources {
+    pub port: Option<Source>,
+    pub connect: Source,
+    pub network_bind_addr: Option<Source>,
+    pub verbose: Source,
+}
+
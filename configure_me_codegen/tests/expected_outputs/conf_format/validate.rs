@@ -0,0 +1,10 @@
+            let port = self.port;
+            let connect = self.connect.unwrap_or_default();
+            let network = self.network.validate()?;
+
+            Ok(super::Config {
+                port,
+                connect,
+                verbose: self.verbose.unwrap_or(false),
+                network,
+            })
@@ -15,7 +15,6 @@ impl ::std::fmt::Display for ArgParseError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match self {
             ArgParseError::MissingArgument(arg) => write!(f, "A value to argument '{}' is missing.", arg),
-            ArgParseError::UnknownArgument(arg) => write!(f, "An unknown argument '{}' was specified.", arg),
 <<"display_arg_parse_error.rs">>
         }
     }
@@ -68,9 +67,11 @@ impl ::std::fmt::Debug for ValidationError {
     }
 }
 
+<<"source.rs">>
+<<"warning.rs">>
 pub enum Error {
     Reading { file: ::std::path::PathBuf, error: ::std::io::Error },
-    ConfigParsing { file: ::std::path::PathBuf, error: ::configure_me::toml::de::Error },
+<<"config_parsing_error.rs">>
     Arguments(ArgParseError),
     Environment(EnvParseError),
     Validation(ValidationError),
@@ -103,6 +104,7 @@ impl ::std::fmt::Display for Error {
         match self {
             Error::Reading { file, error } => write!(f, "Failed to read configuration file {}: {}", file.display(), error),
             Error::ConfigParsing { file, error } => write!(f, "Failed to parse configuration file {}: {}", file.display(), error),
+<<"display_config_parsing_error.rs">>
             Error::Arguments(err) => write!(f, "{}", err),
             Error::Environment(err) => write!(f, "{}", err),
             Error::Validation(err) => write!(f, "Invalid configuration: {}", err),
@@ -117,8 +119,10 @@ impl ::std::fmt::Debug for Error {
     }
 }
 
+<<"value_enums.rs">>
 mod raw {
     use super::{ArgParseError, ValidationError};
+<<"raw_imports.rs">>
 
     #[derive(Deserialize, Default)]
     #[serde(crate = "crate::configure_me::serde")]
@@ -134,7 +138,7 @@ mod raw {
             let mut config_file = ::std::fs::File::open(&config_file_name).map_err(|error| super::Error::Reading { file: config_file_name.as_ref().into(), error })?;
             let mut config_content = Vec::new();
             config_file.read_to_end(&mut config_content).map_err(|error| super::Error::Reading { file: config_file_name.as_ref().into(), error })?;
-            ::configure_me::toml::from_slice(&config_content).map_err(|error| super::Error::ConfigParsing { file: config_file_name.as_ref().into(), error })
+<<"load_config.rs">>
         }
 
         pub fn validate(self) -> Result<super::Config, ValidationError> {
@@ -152,7 +156,7 @@ mod raw {
 
             while let Some(arg) = iter.next() {
                 if arg == *"--" {
-                    return Ok((program_path, None.into_iter().chain(iter)));
+<<"merge_double_dash.rs">>
                 } else if (arg == *"--help") || (arg == *"-h") {
                     return Err(ArgParseError::HelpRequested(program_path.as_ref().unwrap().to_string_lossy().into()).into());
 <<"merge_args.rs">>
@@ -170,8 +174,7 @@ mod raw {
                     }
                 } else if arg.to_str().unwrap_or("").starts_with("--") {
                     return Err(ArgParseError::UnknownArgument(arg.into_string().unwrap()).into());
-                } else {
-                    return Ok((program_path, Some(arg).into_iter().chain(iter)))
+<<"merge_positional.rs">>
                 }
             }
 
@@ -182,10 +185,16 @@ mod raw {
 <<"merge_env.rs">>
             Ok(())
         }
+<<"positional_fn.rs">>
     }
+<<"raw_sections.rs">>
+<<"raw_subcommand.rs">>
 }
 
+<<"subcommands.rs">>
+<<"sections.rs">>
 /// Configuration of the application
+<<"config_attrs.rs">>
 pub struct Config {
 <<"config.rs">>
 }
@@ -227,17 +236,20 @@ impl Config {
 <<"construct_metadata.rs">>
         };
 
+<<"print_config.rs">>
         config
             .validate()
             .map(|cfg| (cfg, remaining_args, metadata))
             .map_err(Into::into)
     }
+<<"reload.rs">>
 }
 
 /// Metadata of the configuration.
 ///
 /// This struct provides some additional information regarding the configuration.
-/// Currently it only contains program name but more items could be available in the future.
+/// It contains the sources of the values and, depending on the specification, the program name
+/// and warnings. More items could be available in the future.
 #[non_exhaustive]
 pub struct Metadata {
 <<"metadata_fields.rs">>
@@ -258,6 +270,7 @@ impl<T> ResultExt for Result<T, Error> {
 
         match self {
             Ok(item) => item,
+<<"help_requested.rs">>
             Err(err @ Error::Arguments(ArgParseError::HelpRequested(_))) => {
                 println!("{}", err);
                 std::io::stdout().flush().expect("failed to flush stdout");
@@ -0,0 +1,4 @@
+    FieldTlsCert(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldTlsKey(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldUser(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldToken(<String as ::configure_me::parse_arg::ParseArg>::Error),
@@ -0,0 +1,7 @@
+    pub tls_cert: Option<String>,
+    pub tls_key: Option<String>,
+    pub user: Option<String>,
+    pub token: Option<String>,
+    pub anonymous: bool,
+    pub quiet: bool,
+    pub verbose: u32,
@@ -0,0 +1,9 @@
+            sources: Sources {
+                tls_cert: config.tls_cert_source.clone(),
+                tls_key: config.tls_key_source.clone(),
+                user: config.user_source.clone(),
+                token: config.token_source.clone(),
+                anonymous: config.anonymous_source.clone().unwrap_or(Source::Default),
+                quiet: config.quiet_source.clone().unwrap_or(Source::Default),
+                verbose: config.verbose_source.clone().unwrap_or(Source::Default),
+            },
@@ -0,0 +1,25 @@
+            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &["--help", "--tls-cert", "--tls-key", "--user", "--token", "--anonymous", "--quiet", "--verbose"]) {
+                Some(known) => write!(f, "An unknown argument '{}' was specified, did you mean '{}'?", arg, known),
+                None => write!(f, "An unknown argument '{}' was specified.", arg),
+            },
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [ARGUMENTS...]\n\nArguments:\n        --tls-cert\n\n        --tls-key\n\n        --user\n\n        --token\n\n        --anonymous\n\n        --quiet\n\n        -v, --verbose\n", program_name),
+        ArgParseError::FieldTlsCert(err) => {
+            write!(f, "Failed to parse argument '--tls-cert': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldTlsKey(err) => {
+            write!(f, "Failed to parse argument '--tls-key': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldUser(err) => {
+            write!(f, "Failed to parse argument '--user': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldToken(err) => {
+            write!(f, "Failed to parse argument '--token': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
@@ -0,0 +1,31 @@
+        EnvParseError::FieldTlsCert(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_CONSTRAINTS_TLS_CERT': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldTlsKey(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_CONSTRAINTS_TLS_KEY': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldUser(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_CONSTRAINTS_USER': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldToken(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_CONSTRAINTS_TOKEN': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldAnonymous(ref err) => {
+            write!(f, "Invalid value '{:?}' for 'TEST_CONSTRAINTS_ANONYMOUS'.\n\nHint: the allowed values are 0, false, 1, true.", err)
+        },
+        EnvParseError::FieldQuiet(ref err) => {
+            write!(f, "Invalid value '{:?}' for 'TEST_CONSTRAINTS_QUIET'.\n\nHint: the allowed values are 0, false, 1, true.", err)
+        },
+        EnvParseError::FieldVerbose(ref err) => {
+            write!(f, "Invalid value for 'TEST_CONSTRAINTS_VERBOSE': {}.\n\nHint: the value must be ", err)?;
+            <u32 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
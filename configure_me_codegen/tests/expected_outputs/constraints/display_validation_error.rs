@@ -0,0 +1,12 @@
+            ValidationError::Requires { field, required } => write!(f, "Configuration parameter '{}' requires '{}' to be specified as well.", field, required),
+            ValidationError::ConflictsWith { field, conflicting } => write!(f, "Configuration parameters '{}' and '{}' can't be specified at the same time.", field, conflicting),
+            ValidationError::RequiredUnless { field, alternatives } => {
+                write!(f, "Configuration parameter '{}' not specified, it's required unless ", field)?;
+                for (i, alternative) in alternatives.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " or ")?;
+                    }
+                    write!(f, "'{}'", alternative)?;
+                }
+                write!(f, " is specified.")
+            },
@@ -0,0 +1,7 @@
+    FieldTlsCert(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldTlsKey(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldUser(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldToken(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldAnonymous(::std::ffi::OsString),
+    FieldQuiet(::std::ffi::OsString),
+    FieldVerbose(<u32 as ::configure_me::parse_arg::ParseArg>::Error),
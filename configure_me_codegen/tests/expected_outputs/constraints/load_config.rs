@@ -0,0 +1,23 @@
+            let mut config: Self = ::configure_me::toml::from_slice(&config_content).map_err(|error| super::Error::ConfigParsing { file: config_file_name.as_ref().into(), error })?;
+            if config.tls_cert.is_some() {
+                config.tls_cert_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.tls_key.is_some() {
+                config.tls_key_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.user.is_some() {
+                config.user_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.token.is_some() {
+                config.token_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.anonymous.is_some() {
+                config.anonymous_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.quiet.is_some() {
+                config.quiet_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.verbose.is_some() {
+                config.verbose_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            Ok(config)
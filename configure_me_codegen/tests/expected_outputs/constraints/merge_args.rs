@@ -0,0 +1,29 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--tls-cert", &arg, &mut iter) {
+                    let tls_cert = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--tls-cert"), ArgParseError::FieldTlsCert))?;
+
+                    self.tls_cert = Some(tls_cert);
+                    self.tls_cert_source = Some(super::Source::Argument("--tls-cert"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--tls-key", &arg, &mut iter) {
+                    let tls_key = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--tls-key"), ArgParseError::FieldTlsKey))?;
+
+                    self.tls_key = Some(tls_key);
+                    self.tls_key_source = Some(super::Source::Argument("--tls-key"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--user", &arg, &mut iter) {
+                    let user = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--user"), ArgParseError::FieldUser))?;
+
+                    self.user = Some(user);
+                    self.user_source = Some(super::Source::Argument("--user"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--token", &arg, &mut iter) {
+                    let token = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--token"), ArgParseError::FieldToken))?;
+
+                    self.token = Some(token);
+                    self.token_source = Some(super::Source::Argument("--token"));
+                } else if arg == *"--anonymous" {
+                    self.anonymous = Some(true);
+                    self.anonymous_source = Some(super::Source::Argument("--anonymous"));
+                } else if arg == *"--quiet" {
+                    self.quiet = Some(true);
+                    self.quiet_source = Some(super::Source::Argument("--quiet"));
+                } else if arg == *"--verbose" {
+                    *(self.verbose.get_or_insert(0)) += 1;
+                    self.verbose_source = Some(super::Source::Argument("--verbose"));
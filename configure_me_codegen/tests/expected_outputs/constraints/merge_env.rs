@@ -0,0 +1,44 @@
+        if let Some(val) = ::std::env::var_os("TEST_CONSTRAINTS_TLS_CERT") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldTlsCert)?;
+            self.tls_cert = Some(val);
+            self.tls_cert_source = Some(super::Source::EnvVar("TEST_CONSTRAINTS_TLS_CERT"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_CONSTRAINTS_TLS_KEY") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldTlsKey)?;
+            self.tls_key = Some(val);
+            self.tls_key_source = Some(super::Source::EnvVar("TEST_CONSTRAINTS_TLS_KEY"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_CONSTRAINTS_USER") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldUser)?;
+            self.user = Some(val);
+            self.user_source = Some(super::Source::EnvVar("TEST_CONSTRAINTS_USER"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_CONSTRAINTS_TOKEN") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldToken)?;
+            self.token = Some(val);
+            self.token_source = Some(super::Source::EnvVar("TEST_CONSTRAINTS_TOKEN"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_CONSTRAINTS_ANONYMOUS") {
+            if val == *"1" || val == *"true" {
+                self.anonymous = Some(true);
+            } else if val == *"0" || val == *"false" {
+                self.anonymous = Some(false);
+            } else {
+                return Err(super::EnvParseError::FieldAnonymous(val).into());
+            }
+            self.anonymous_source = Some(super::Source::EnvVar("TEST_CONSTRAINTS_ANONYMOUS"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_CONSTRAINTS_QUIET") {
+            if val == *"1" || val == *"true" {
+                self.quiet = Some(true);
+            } else if val == *"0" || val == *"false" {
+                self.quiet = Some(false);
+            } else {
+                return Err(super::EnvParseError::FieldQuiet(val).into());
+            }
+            self.quiet_source = Some(super::Source::EnvVar("TEST_CONSTRAINTS_QUIET"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_CONSTRAINTS_VERBOSE") {
+            let val= <u32 as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(val).map_err(super::EnvParseError::FieldVerbose)?;            self.verbose = Some(val);
+            self.verbose_source = Some(super::Source::EnvVar("TEST_CONSTRAINTS_VERBOSE"));
+        }
@@ -0,0 +1,42 @@
+            if other.tls_cert.is_some() {
+                self.tls_cert = other.tls_cert;
+            }
+            if other.tls_cert_source.is_some() {
+                self.tls_cert_source = other.tls_cert_source;
+            }
+            if other.tls_key.is_some() {
+                self.tls_key = other.tls_key;
+            }
+            if other.tls_key_source.is_some() {
+                self.tls_key_source = other.tls_key_source;
+            }
+            if other.user.is_some() {
+                self.user = other.user;
+            }
+            if other.user_source.is_some() {
+                self.user_source = other.user_source;
+            }
+            if other.token.is_some() {
+                self.token = other.token;
+            }
+            if other.token_source.is_some() {
+                self.token_source = other.token_source;
+            }
+            if other.anonymous.is_some() {
+                self.anonymous = other.anonymous;
+            }
+            if other.anonymous_source.is_some() {
+                self.anonymous_source = other.anonymous_source;
+            }
+            if other.quiet.is_some() {
+                self.quiet = other.quiet;
+            }
+            if other.quiet_source.is_some() {
+                self.quiet_source = other.quiet_source;
+            }
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
+            if other.verbose_source.is_some() {
+                self.verbose_source = other.verbose_source;
+            }
@@ -0,0 +1,3 @@
+                        } else if short == 'v' {
+                            *(self.verbose.get_or_insert(0)) += 1;
+                            self.verbose_source = Some(super::Source::Argument("-v"));
@@ -0,0 +1,21 @@
+        tls_cert: Option<String>,
+        tls_key: Option<String>,
+        user: Option<String>,
+        token: Option<String>,
+        anonymous: Option<bool>,
+        quiet: Option<bool>,
+        verbose: Option<u32>,
+        #[serde(skip)]
+        pub tls_cert_source: Option<super::Source>,
+        #[serde(skip)]
+        pub tls_key_source: Option<super::Source>,
+        #[serde(skip)]
+        pub user_source: Option<super::Source>,
+        #[serde(skip)]
+        pub token_source: Option<super::Source>,
+        #[serde(skip)]
+        pub anonymous_source: Option<super::Source>,
+        #[serde(skip)]
+        pub quiet_source: Option<super::Source>,
+        #[serde(skip)]
+        pub verbose_source: Option<super::Source>,
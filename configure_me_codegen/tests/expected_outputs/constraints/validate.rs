@@ -0,0 +1,26 @@
+            if self.tls_cert.is_some() && self.tls_key.is_none() {
+                return Err(ValidationError::Requires { field: "tls_cert", required: "tls_key" });
+            }
+            if self.user.is_none() && self.token.is_none() && self.anonymous != Some(true) {
+                return Err(ValidationError::RequiredUnless { field: "user", alternatives: &["token", "anonymous"] });
+            }
+            if self.anonymous == Some(true) && self.user.is_some() {
+                return Err(ValidationError::ConflictsWith { field: "anonymous", conflicting: "user" });
+            }
+            if self.quiet == Some(true) && self.verbose.unwrap_or(0) > 0 {
+                return Err(ValidationError::ConflictsWith { field: "quiet", conflicting: "verbose" });
+            }
+            let tls_cert = self.tls_cert;
+            let tls_key = self.tls_key;
+            let user = self.user;
+            let token = self.token;
+
+            Ok(super::Config {
+                tls_cert,
+                tls_key,
+                user,
+                token,
+                anonymous: self.anonymous.unwrap_or(false),
+                quiet: self.quiet.unwrap_or(false),
+                verbose: self.verbose.unwrap_or(0),
+            })
@@ -0,0 +1,3 @@
+    Requires { field: &'static str, required: &'static str },
+    ConflictsWith { field: &'static str, conflicting: &'static str },
+    RequiredUnless { field: &'static str, alternatives: &'static [&'static str] },
@@ -0,0 +1,5 @@
+            sources: Sources {
+                foo: config.foo_source.clone().unwrap_or(Source::Default),
+                bar: config.bar_source.clone(),
+                baz: config.baz_source.clone().unwrap_or(Source::Default),
+            },
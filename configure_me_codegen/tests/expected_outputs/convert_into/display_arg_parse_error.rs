@@ -1,3 +1,7 @@
+            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &["--help", "--foo", "--bar", "--baz"]) {
+                Some(known) => write!(f, "An unknown argument '{}' was specified, did you mean '{}'?", arg, known),
+                None => write!(f, "An unknown argument '{}' was specified.", arg),
+            },
         ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--foo FOO] [--bar BAR] [--baz BAZ]\n\nArguments:\n        --foo    A foo\n        --bar    A very, very, very, very, very, very, very, very, very, very, \n                 very, very, very, very long documentation...\n        --baz    A much, much, much, much, much, much, much, much, much, much, \n                 much, much, much, much, much, much, much, much, much, much, \n                 much, much, much, much, much, much, much, much, much, much, \n                 much, much, much, much, much, much, much, much, much, much, \n                 much, much longer documentation...", program_name),
         ArgParseError::FieldFoo(err) => {
             write!(f, "Failed to parse argument '--foo': {}.\n\nHint: the value must be ", err)?;
@@ -2,11 +2,14 @@
                     let foo = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--foo"), ArgParseError::FieldFoo))?;
 
                     self.foo = Some(foo);
+                    self.foo_source = Some(super::Source::Argument("--foo"));
                 } else if let Some(value) = ::configure_me::parse_arg::match_arg("--bar", &arg, &mut iter) {
                     let bar = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--bar"), ArgParseError::FieldBar))?;
 
                     self.bar = Some(bar);
+                    self.bar_source = Some(super::Source::Argument("--bar"));
                 } else if let Some(value) = ::configure_me::parse_arg::match_arg("--baz", &arg, &mut iter) {
                     let baz = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--baz"), ArgParseError::FieldBaz))?;
 
                     self.baz = Some(baz);
+                    self.baz_source = Some(super::Source::Argument("--baz"));
@@ -1,12 +1,15 @@
         if let Some(val) = ::std::env::var_os("TEST_APP_FOO") {
             let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldFoo)?;
             self.foo = Some(val);
+            self.foo_source = Some(super::Source::EnvVar("TEST_APP_FOO"));
         }
         if let Some(val) = ::std::env::var_os("TEST_APP_BAR") {
             let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldBar)?;
             self.bar = Some(val);
+            self.bar_source = Some(super::Source::EnvVar("TEST_APP_BAR"));
         }
         if let Some(val) = ::std::env::var_os("TEST_APP_BAZ") {
             let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldBaz)?;
             self.baz = Some(val);
+            self.baz_source = Some(super::Source::EnvVar("TEST_APP_BAZ"));
         }
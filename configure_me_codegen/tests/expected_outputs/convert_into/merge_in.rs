@@ -1,9 +1,18 @@
             if other.foo.is_some() {
                 self.foo = other.foo;
             }
+            if other.foo_source.is_some() {
+                self.foo_source = other.foo_source;
+            }
             if other.bar.is_some() {
                 self.bar = other.bar;
             }
+            if other.bar_source.is_some() {
+                self.bar_source = other.bar_source;
+            }
             if other.baz.is_some() {
                 self.baz = other.baz;
             }
+            if other.baz_source.is_some() {
+                self.baz_source = other.baz_source;
+            }
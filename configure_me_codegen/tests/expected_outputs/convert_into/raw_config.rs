@@ -1,3 +1,9 @@
         foo: Option<u32>,
         bar: Option<String>,
         baz: Option<String>,
+        #[serde(skip)]
+        pub foo_source: Option<super::Source>,
+        #[serde(skip)]
+        pub bar_source: Option<super::Source>,
+        #[serde(skip)]
+        pub baz_source: Option<super::Source>,
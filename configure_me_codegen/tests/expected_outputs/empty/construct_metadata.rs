@@ -0,0 +1,2 @@
+            sources: Sources {
+            },
@@ -0,0 +1,5 @@
+    FieldHost(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldPort(<u16 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldLogFilter(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldFailAfter(<u32 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldCacheSize(<u64 as ::configure_me::parse_arg::ParseArg>::Error),
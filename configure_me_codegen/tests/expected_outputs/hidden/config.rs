@@ -0,0 +1,8 @@
+    pub host: String,
+    pub port: u16,
+    pub log_filter: Option<String>,
+    pub fail_after: Option<u32>,
+    pub verbose: bool,
+    pub ipv6: bool,
+    pub debug_dump: bool,
+    pub cache: CacheSection,
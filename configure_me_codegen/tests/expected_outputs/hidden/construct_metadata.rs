@@ -0,0 +1,11 @@
+            sources: Sources {
+                host: config.host_source.clone().unwrap_or(Source::Default),
+                port: config.port_source.clone().unwrap_or(Source::Default),
+                log_filter: config.log_filter_source.clone(),
+                fail_after: config.fail_after_source.clone(),
+                cache_size: config.cache.size_source.clone(),
+                verbose: config.verbose_source.clone().unwrap_or(Source::Default),
+                ipv6: config.ipv6_source.clone().unwrap_or(Source::Default),
+                debug_dump: config.debug_dump_source.clone().unwrap_or(Source::Default),
+                cache_disable: config.cache.disable_source.clone().unwrap_or(Source::Default),
+            },
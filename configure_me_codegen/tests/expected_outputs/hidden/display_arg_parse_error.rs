@@ -0,0 +1,30 @@
+            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &["--help", "--host", "--port", "--log-filter", "--cache-size", "--verbose", "--ipv6", "--cache-disable"]) {
+                Some(known) => write!(f, "An unknown argument '{}' was specified, did you mean '{}'?", arg, known),
+                None => write!(f, "An unknown argument '{}' was specified.", arg),
+            },
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [ARGUMENTS...]\n\nArguments:\n        --log-filter       Which messages to log\n        -v, --verbose      Print more details\n\nArguments in section cache:\n        --cache-disable    Disable caching\n\nNetworking:\n        --host             Host to connect to\n        --port             Port to connect to\n        --ipv6             Prefer IPv6\n\nCaching:\n        --cache-size       Maximum number of cached entries", program_name),
+        ArgParseError::FieldHost(err) => {
+            write!(f, "Failed to parse argument '--host': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldPort(err) => {
+            write!(f, "Failed to parse argument '--port': {}.\n\nHint: the value must be ", err)?;
+            <u16 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldLogFilter(err) => {
+            write!(f, "Failed to parse argument '--log-filter': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldFailAfter(err) => {
+            write!(f, "Failed to parse argument '--fail-after': {}.\n\nHint: the value must be ", err)?;
+            <u32 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldCacheSize(err) => {
+            write!(f, "Failed to parse argument '--cache-size': {}.\n\nHint: the value must be ", err)?;
+            <u64 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
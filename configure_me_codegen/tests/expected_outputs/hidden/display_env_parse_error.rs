@@ -0,0 +1,37 @@
+        EnvParseError::FieldHost(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_HIDDEN_HOST': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldPort(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_HIDDEN_PORT': {}.\n\nHint: the value must be ", err)?;
+            <u16 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldLogFilter(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_HIDDEN_LOG_FILTER': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldFailAfter(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_HIDDEN_FAIL_AFTER': {}.\n\nHint: the value must be ", err)?;
+            <u32 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldCacheSize(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_HIDDEN_CACHE_SIZE': {}.\n\nHint: the value must be ", err)?;
+            <u64 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldVerbose(ref err) => {
+            write!(f, "Invalid value '{:?}' for 'TEST_HIDDEN_VERBOSE'.\n\nHint: the allowed values are 0, false, 1, true.", err)
+        },
+        EnvParseError::FieldIpv6(ref err) => {
+            write!(f, "Invalid value '{:?}' for 'TEST_HIDDEN_IPV6'.\n\nHint: the allowed values are 0, false, 1, true.", err)
+        },
+        EnvParseError::FieldDebugDump(ref err) => {
+            write!(f, "Invalid value '{:?}' for 'TEST_HIDDEN_DEBUG_DUMP'.\n\nHint: the allowed values are 0, false, 1, true.", err)
+        },
+        EnvParseError::FieldCacheDisable(ref err) => {
+            write!(f, "Invalid value '{:?}' for 'TEST_HIDDEN_CACHE_DISABLE'.\n\nHint: the allowed values are 0, false, 1, true.", err)
+        },
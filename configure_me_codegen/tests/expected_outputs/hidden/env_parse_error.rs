@@ -0,0 +1,9 @@
+    FieldHost(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldPort(<u16 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldLogFilter(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldFailAfter(<u32 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldVerbose(::std::ffi::OsString),
+    FieldIpv6(::std::ffi::OsString),
+    FieldDebugDump(::std::ffi::OsString),
+    FieldCacheSize(<u64 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldCacheDisable(::std::ffi::OsString),
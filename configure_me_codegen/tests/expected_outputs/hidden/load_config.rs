@@ -0,0 +1,29 @@
+            let mut config: Self = ::configure_me::toml::from_slice(&config_content).map_err(|error| super::Error::ConfigParsing { file: config_file_name.as_ref().into(), error })?;
+            if config.host.is_some() {
+                config.host_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.port.is_some() {
+                config.port_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.log_filter.is_some() {
+                config.log_filter_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.fail_after.is_some() {
+                config.fail_after_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.cache.size.is_some() {
+                config.cache.size_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.verbose.is_some() {
+                config.verbose_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.ipv6.is_some() {
+                config.ipv6_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.debug_dump.is_some() {
+                config.debug_dump_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.cache.disable.is_some() {
+                config.cache.disable_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            Ok(config)
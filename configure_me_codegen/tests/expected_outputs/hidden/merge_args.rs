@@ -0,0 +1,37 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--host", &arg, &mut iter) {
+                    let host = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--host"), ArgParseError::FieldHost))?;
+
+                    self.host = Some(host);
+                    self.host_source = Some(super::Source::Argument("--host"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--port", &arg, &mut iter) {
+                    let port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--port"), ArgParseError::FieldPort))?;
+
+                    self.port = Some(port);
+                    self.port_source = Some(super::Source::Argument("--port"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--log-filter", &arg, &mut iter) {
+                    let log_filter = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--log-filter"), ArgParseError::FieldLogFilter))?;
+
+                    self.log_filter = Some(log_filter);
+                    self.log_filter_source = Some(super::Source::Argument("--log-filter"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--fail-after", &arg, &mut iter) {
+                    let fail_after = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--fail-after"), ArgParseError::FieldFailAfter))?;
+
+                    self.fail_after = Some(fail_after);
+                    self.fail_after_source = Some(super::Source::Argument("--fail-after"));
+                } else if arg == *"--verbose" {
+                    self.verbose = Some(true);
+                    self.verbose_source = Some(super::Source::Argument("--verbose"));
+                } else if arg == *"--ipv6" {
+                    self.ipv6 = Some(true);
+                    self.ipv6_source = Some(super::Source::Argument("--ipv6"));
+                } else if arg == *"--debug-dump" {
+                    self.debug_dump = Some(true);
+                    self.debug_dump_source = Some(super::Source::Argument("--debug-dump"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--cache-size", &arg, &mut iter) {
+                    let cache_size = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--cache-size"), ArgParseError::FieldCacheSize))?;
+
+                    self.cache.size = Some(cache_size);
+                    self.cache.size_source = Some(super::Source::Argument("--cache-size"));
+                } else if arg == *"--cache-disable" {
+                    self.cache.disable = Some(true);
+                    self.cache.disable_source = Some(super::Source::Argument("--cache-disable"));
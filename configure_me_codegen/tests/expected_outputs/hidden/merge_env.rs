@@ -0,0 +1,65 @@
+        if let Some(val) = ::std::env::var_os("TEST_HIDDEN_HOST") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldHost)?;
+            self.host = Some(val);
+            self.host_source = Some(super::Source::EnvVar("TEST_HIDDEN_HOST"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_HIDDEN_PORT") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldPort)?;
+            self.port = Some(val);
+            self.port_source = Some(super::Source::EnvVar("TEST_HIDDEN_PORT"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_HIDDEN_LOG_FILTER") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldLogFilter)?;
+            self.log_filter = Some(val);
+            self.log_filter_source = Some(super::Source::EnvVar("TEST_HIDDEN_LOG_FILTER"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_HIDDEN_FAIL_AFTER") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldFailAfter)?;
+            self.fail_after = Some(val);
+            self.fail_after_source = Some(super::Source::EnvVar("TEST_HIDDEN_FAIL_AFTER"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_HIDDEN_CACHE_SIZE") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldCacheSize)?;
+            self.cache.size = Some(val);
+            self.cache.size_source = Some(super::Source::EnvVar("TEST_HIDDEN_CACHE_SIZE"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_HIDDEN_VERBOSE") {
+            if val == *"1" || val == *"true" {
+                self.verbose = Some(true);
+            } else if val == *"0" || val == *"false" {
+                self.verbose = Some(false);
+            } else {
+                return Err(super::EnvParseError::FieldVerbose(val).into());
+            }
+            self.verbose_source = Some(super::Source::EnvVar("TEST_HIDDEN_VERBOSE"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_HIDDEN_IPV6") {
+            if val == *"1" || val == *"true" {
+                self.ipv6 = Some(true);
+            } else if val == *"0" || val == *"false" {
+                self.ipv6 = Some(false);
+            } else {
+                return Err(super::EnvParseError::FieldIpv6(val).into());
+            }
+            self.ipv6_source = Some(super::Source::EnvVar("TEST_HIDDEN_IPV6"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_HIDDEN_DEBUG_DUMP") {
+            if val == *"1" || val == *"true" {
+                self.debug_dump = Some(true);
+            } else if val == *"0" || val == *"false" {
+                self.debug_dump = Some(false);
+            } else {
+                return Err(super::EnvParseError::FieldDebugDump(val).into());
+            }
+            self.debug_dump_source = Some(super::Source::EnvVar("TEST_HIDDEN_DEBUG_DUMP"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_HIDDEN_CACHE_DISABLE") {
+            if val == *"1" || val == *"true" {
+                self.cache.disable = Some(true);
+            } else if val == *"0" || val == *"false" {
+                self.cache.disable = Some(false);
+            } else {
+                return Err(super::EnvParseError::FieldCacheDisable(val).into());
+            }
+            self.cache.disable_source = Some(super::Source::EnvVar("TEST_HIDDEN_CACHE_DISABLE"));
+        }
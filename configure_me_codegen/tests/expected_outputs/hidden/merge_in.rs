@@ -0,0 +1,43 @@
+            if other.host.is_some() {
+                self.host = other.host;
+            }
+            if other.host_source.is_some() {
+                self.host_source = other.host_source;
+            }
+            if other.port.is_some() {
+                self.port = other.port;
+            }
+            if other.port_source.is_some() {
+                self.port_source = other.port_source;
+            }
+            if other.log_filter.is_some() {
+                self.log_filter = other.log_filter;
+            }
+            if other.log_filter_source.is_some() {
+                self.log_filter_source = other.log_filter_source;
+            }
+            if other.fail_after.is_some() {
+                self.fail_after = other.fail_after;
+            }
+            if other.fail_after_source.is_some() {
+                self.fail_after_source = other.fail_after_source;
+            }
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
+            if other.verbose_source.is_some() {
+                self.verbose_source = other.verbose_source;
+            }
+            if other.ipv6.is_some() {
+                self.ipv6 = other.ipv6;
+            }
+            if other.ipv6_source.is_some() {
+                self.ipv6_source = other.ipv6_source;
+            }
+            if other.debug_dump.is_some() {
+                self.debug_dump = other.debug_dump;
+            }
+            if other.debug_dump_source.is_some() {
+                self.debug_dump_source = other.debug_dump_source;
+            }
+            self.cache.merge_in(other.cache);
@@ -0,0 +1,23 @@
+        host: Option<String>,
+        port: Option<u16>,
+        log_filter: Option<String>,
+        fail_after: Option<u32>,
+        verbose: Option<bool>,
+        ipv6: Option<bool>,
+        debug_dump: Option<bool>,
+        #[serde(skip)]
+        pub host_source: Option<super::Source>,
+        #[serde(skip)]
+        pub port_source: Option<super::Source>,
+        #[serde(skip)]
+        pub log_filter_source: Option<super::Source>,
+        #[serde(skip)]
+        pub fail_after_source: Option<super::Source>,
+        #[serde(skip)]
+        pub verbose_source: Option<super::Source>,
+        #[serde(skip)]
+        pub ipv6_source: Option<super::Source>,
+        #[serde(skip)]
+        pub debug_dump_source: Option<super::Source>,
+        #[serde(default)]
+        pub cache: CacheSection,
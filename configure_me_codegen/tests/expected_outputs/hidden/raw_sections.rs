@@ -0,0 +1,38 @@
+
+    #[derive(Deserialize, Default)]
+    #[serde(crate = "crate::configure_me::serde")]
+    pub struct CacheSection {
+        size: Option<u64>,
+        disable: Option<bool>,
+        #[serde(skip)]
+        pub size_source: Option<super::Source>,
+        #[serde(skip)]
+        pub disable_source: Option<super::Source>,
+    }
+
+    #[automatically_derived]
+    impl CacheSection {
+        pub fn validate(self) -> Result<super::CacheSection, ValidationError> {
+            let size = self.size;
+
+            Ok(super::CacheSection {
+                size,
+                disable: self.disable.unwrap_or(false),
+            })
+        }
+
+        pub fn merge_in(&mut self, other: Self) {
+            if other.size.is_some() {
+                self.size = other.size;
+            }
+            if other.size_source.is_some() {
+                self.size_source = other.size_source;
+            }
+            if other.disable.is_some() {
+                self.disable = other.disable;
+            }
+            if other.disable_source.is_some() {
+                self.disable_source = other.disable_source;
+            }
+        }
+    }
@@ -0,0 +1,6 @@
+/// Configuration of the `cache` section
+pub struct CacheSection {
+    pub size: Option<u64>,
+    pub disable: bool,
+}
+
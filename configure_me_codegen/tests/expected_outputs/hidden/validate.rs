@@ -0,0 +1,16 @@
+            let host = self.host.unwrap_or_else(|| { "localhost".to_owned() });
+            let port = self.port.unwrap_or_else(|| { 8080 });
+            let log_filter = self.log_filter;
+            let fail_after = self.fail_after;
+            let cache = self.cache.validate()?;
+
+            Ok(super::Config {
+                host,
+                port,
+                log_filter,
+                fail_after,
+                verbose: self.verbose.unwrap_or(false),
+                ipv6: self.ipv6.unwrap_or(false),
+                debug_dump: self.debug_dump.unwrap_or(false),
+                cache,
+            })
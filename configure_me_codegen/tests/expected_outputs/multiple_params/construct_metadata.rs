@@ -0,0 +1,7 @@
+            sources: Sources {
+                foo: config.foo_source.clone().unwrap_or(Source::Default),
+                bar: config.bar_source.clone(),
+                baz: config.baz_source.clone().unwrap_or(Source::Default),
+                verbose: config.verbose_source.clone().unwrap_or(Source::Default),
+                fast: config.fast_source.clone().unwrap_or(Source::Default),
+            },
@@ -1,14 +1,17 @@
         if let Some(val) = ::std::env::var_os("TEST_APP_FOO") {
             let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldFoo)?;
             self.foo = Some(val);
+            self.foo_source = Some(super::Source::EnvVar("TEST_APP_FOO"));
         }
         if let Some(val) = ::std::env::var_os("TEST_APP_BAR") {
             let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldBar)?;
             self.bar = Some(val);
+            self.bar_source = Some(super::Source::EnvVar("TEST_APP_BAR"));
         }
         if let Some(val) = ::std::env::var_os("TEST_APP_BAZ") {
             let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldBaz)?;
             self.baz = Some(val);
+            self.baz_source = Some(super::Source::EnvVar("TEST_APP_BAZ"));
         }
         if let Some(val) = ::std::env::var_os("TEST_APP_VERBOSE") {
             if val == *"1" || val == *"true" {
@@ -18,6 +21,7 @@
             } else {
                 return Err(super::EnvParseError::FieldVerbose(val).into());
             }
+            self.verbose_source = Some(super::Source::EnvVar("TEST_APP_VERBOSE"));
         }
         if let Some(val) = ::std::env::var_os("TEST_APP_FAST") {
             if val == *"1" || val == *"true" {
@@ -27,4 +31,5 @@
             } else {
                 return Err(super::EnvParseError::FieldFast(val).into());
             }
+            self.fast_source = Some(super::Source::EnvVar("TEST_APP_FAST"));
         }
@@ -1,15 +1,30 @@
             if other.foo.is_some() {
                 self.foo = other.foo;
             }
+            if other.foo_source.is_some() {
+                self.foo_source = other.foo_source;
+            }
             if other.bar.is_some() {
                 self.bar = other.bar;
             }
+            if other.bar_source.is_some() {
+                self.bar_source = other.bar_source;
+            }
             if other.baz.is_some() {
                 self.baz = other.baz;
             }
+            if other.baz_source.is_some() {
+                self.baz_source = other.baz_source;
+            }
             if other.verbose.is_some() {
                 self.verbose = other.verbose;
             }
+            if other.verbose_source.is_some() {
+                self.verbose_source = other.verbose_source;
+            }
             if other.fast.is_some() {
                 self.fast = other.fast;
             }
+            if other.fast_source.is_some() {
+                self.fast_source = other.fast_source;
+            }
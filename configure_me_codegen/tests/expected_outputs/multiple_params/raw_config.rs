@@ -3,3 +3,13 @@
         baz: Option<String>,
         verbose: Option<bool>,
         fast: Option<bool>,
+        #[serde(skip)]
+        pub foo_source: Option<super::Source>,
+        #[serde(skip)]
+        pub bar_source: Option<super::Source>,
+        #[serde(skip)]
+        pub baz_source: Option<super::Source>,
+        #[serde(skip)]
+        pub verbose_source: Option<super::Source>,
+        #[serde(skip)]
+        pub fast_source: Option<super::Source>,
@@ -0,0 +1,3 @@
+    pub peer: Vec<String>,
+    pub port: Vec<u16>,
+    pub level: Vec<u32>,
@@ -0,0 +1,5 @@
+            sources: Sources {
+                peer: config.peer_source.clone().unwrap_or(Source::Default),
+                port: config.port_source.clone().unwrap_or(Source::Default),
+                level: config.level_source.clone().unwrap_or(Source::Default),
+            },
@@ -0,0 +1,20 @@
+            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &["--help", "--peer", "--port", "--level"]) {
+                Some(known) => write!(f, "An unknown argument '{}' was specified, did you mean '{}'?", arg, known),
+                None => write!(f, "An unknown argument '{}' was specified.", arg),
+            },
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [-p PEER|--peer PEER]... [--port PORT]... [--level LEVEL]...\n\nArguments:\n        -p, --peer    A peer to connect to", program_name),
+        ArgParseError::FieldPeer(err) => {
+            write!(f, "Failed to parse argument '--peer': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldPort(err) => {
+            write!(f, "Failed to parse argument '--port': {}.\n\nHint: the value must be ", err)?;
+            <u16 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldLevel(err) => {
+            write!(f, "Failed to parse argument '--level': {}.\n\nHint: the value must be ", err)?;
+            <u8 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
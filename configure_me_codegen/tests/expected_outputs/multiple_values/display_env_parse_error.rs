@@ -0,0 +1,15 @@
+        EnvParseError::FieldPeer(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_MULTIPLE_VALUES_PEER': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldPort(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_MULTIPLE_VALUES_PORT': {}.\n\nHint: the value must be ", err)?;
+            <u16 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldLevel(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_MULTIPLE_VALUES_LEVEL': {}.\n\nHint: the value must be ", err)?;
+            <u8 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
@@ -0,0 +1 @@
+            ValidationError::MissingField(field) => write!(f, "Configuration parameter '{}' not specified.", field),
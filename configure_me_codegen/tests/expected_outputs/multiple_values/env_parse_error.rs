@@ -0,0 +1,3 @@
+    FieldPeer(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldPort(<u16 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldLevel(<u8 as ::configure_me::parse_arg::ParseArg>::Error),
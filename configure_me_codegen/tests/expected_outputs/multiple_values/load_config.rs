@@ -0,0 +1,11 @@
+            let mut config: Self = ::configure_me::toml::from_slice(&config_content).map_err(|error| super::Error::ConfigParsing { file: config_file_name.as_ref().into(), error })?;
+            if config.peer.is_some() {
+                config.peer_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.port.is_some() {
+                config.port_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.level.is_some() {
+                config.level_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            Ok(config)
@@ -0,0 +1,15 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--peer", &arg, &mut iter) {
+                    let peer = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--peer"), ArgParseError::FieldPeer))?;
+
+                    self.peer.get_or_insert_with(Vec::new).push(peer);
+                    self.peer_source = Some(super::Source::Argument("--peer"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--port", &arg, &mut iter) {
+                    let port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--port"), ArgParseError::FieldPort))?;
+
+                    self.port.get_or_insert_with(Vec::new).push(port);
+                    self.port_source = Some(super::Source::Argument("--port"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--level", &arg, &mut iter) {
+                    let level = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--level"), ArgParseError::FieldLevel))?;
+
+                    self.level.get_or_insert_with(Vec::new).push(level);
+                    self.level_source = Some(super::Source::Argument("--level"));
@@ -0,0 +1,27 @@
+        if let Some(val) = ::std::env::var_os("TEST_MULTIPLE_VALUES_PEER") {
+            let val = match val.into_string() {
+                Ok(val) => val.split(",").map(|item| <String as ::configure_me::parse_arg::ParseArg>::parse_arg(item.as_ref())).collect::<Result<Vec<_>, _>>(),
+                // Values that aren't valid UTF-8 can't be split
+                Err(val) => <String as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(val).map(|item| vec![item]),
+            }.map_err(super::EnvParseError::FieldPeer)?;
+            self.peer = Some(val);
+            self.peer_source = Some(super::Source::EnvVar("TEST_MULTIPLE_VALUES_PEER"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_MULTIPLE_VALUES_PORT") {
+            let val = match val.into_string() {
+                Ok(val) => val.split(":").map(|item| <u16 as ::configure_me::parse_arg::ParseArg>::parse_arg(item.as_ref())).collect::<Result<Vec<_>, _>>(),
+                // Values that aren't valid UTF-8 can't be split
+                Err(val) => <u16 as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(val).map(|item| vec![item]),
+            }.map_err(super::EnvParseError::FieldPort)?;
+            self.port = Some(val);
+            self.port_source = Some(super::Source::EnvVar("TEST_MULTIPLE_VALUES_PORT"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_MULTIPLE_VALUES_LEVEL") {
+            let val = match val.into_string() {
+                Ok(val) => val.split(",").map(|item| <u8 as ::configure_me::parse_arg::ParseArg>::parse_arg(item.as_ref())).collect::<Result<Vec<_>, _>>(),
+                // Values that aren't valid UTF-8 can't be split
+                Err(val) => <u8 as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(val).map(|item| vec![item]),
+            }.map_err(super::EnvParseError::FieldLevel)?;
+            self.level = Some(val);
+            self.level_source = Some(super::Source::EnvVar("TEST_MULTIPLE_VALUES_LEVEL"));
+        }
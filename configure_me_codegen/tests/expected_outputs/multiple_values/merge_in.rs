@@ -0,0 +1,18 @@
+            if other.peer.is_some() {
+                self.peer = other.peer;
+            }
+            if other.peer_source.is_some() {
+                self.peer_source = other.peer_source;
+            }
+            if other.port.is_some() {
+                self.port = other.port;
+            }
+            if other.port_source.is_some() {
+                self.port_source = other.port_source;
+            }
+            if other.level.is_some() {
+                self.level = other.level;
+            }
+            if other.level_source.is_some() {
+                self.level_source = other.level_source;
+            }
@@ -0,0 +1,4 @@
+                        } else if short == 'p' {
+                            self.peer.get_or_insert_with(Vec::new).push(shorts.parse_remaining(&mut iter).map_err(|err| err.map_or(ArgParseError::MissingArgument("-p"), ArgParseError::FieldPeer))?);
+                            self.peer_source = Some(super::Source::Argument("-p"));
+                            break;
@@ -0,0 +1,9 @@
+        peer: Option<Vec<String>>,
+        port: Option<Vec<u16>>,
+        level: Option<Vec<u8>>,
+        #[serde(skip)]
+        pub peer_source: Option<super::Source>,
+        #[serde(skip)]
+        pub port_source: Option<super::Source>,
+        #[serde(skip)]
+        pub level_source: Option<super::Source>,
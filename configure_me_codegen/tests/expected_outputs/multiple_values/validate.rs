@@ -0,0 +1,9 @@
+            let peer = self.peer.unwrap_or_default();
+            let port = self.port.ok_or(ValidationError::MissingField("port"))?;
+            let level = self.level.unwrap_or_else(|| { vec![1, 2] });
+
+            Ok(super::Config {
+                peer,
+                port,
+                level: level.into_iter().map(Into::into).collect(),
+            })
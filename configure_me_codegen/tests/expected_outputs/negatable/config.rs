@@ -0,0 +1,4 @@
+    pub verbose: bool,
+    pub color: bool,
+    pub fast: bool,
+    pub cache: CacheSection,
@@ -0,0 +1,7 @@
+            warnings: ::std::mem::take(&mut config.warnings),
+            sources: Sources {
+                verbose: config.verbose_source.clone().unwrap_or(Source::Default),
+                color: config.color_source.clone().unwrap_or(Source::Default),
+                fast: config.fast_source.clone().unwrap_or(Source::Default),
+                cache_enabled: config.cache.enabled_source.clone().unwrap_or(Source::Default),
+            },
@@ -0,0 +1,5 @@
+            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &["--help", "--verbose", "--no-verbose", "--color", "--no-color", "--fast", "--cache-enabled", "--no-cache-enabled", "--cache-on", "--no-cache-on"]) {
+                Some(known) => write!(f, "An unknown argument '{}' was specified, did you mean '{}'?", arg, known),
+                None => write!(f, "An unknown argument '{}' was specified.", arg),
+            },
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [-v|--[no-]verbose] [--[no-]color] [--fast] [--[no-]cache-enabled]\n\nArguments:\n        -v, --[no-]verbose      Print more details\n        --[no-]color            Colorize the output\n        --fast                  Skip slow checks\n\nArguments in section cache:\n        --[no-]cache-enabled    Cache the results", program_name),
@@ -0,0 +1,12 @@
+        EnvParseError::FieldVerbose(ref err) => {
+            write!(f, "Invalid value '{:?}' for 'TEST_NEGATABLE_VERBOSE'.\n\nHint: the allowed values are 0, false, 1, true.", err)
+        },
+        EnvParseError::FieldColor(ref err) => {
+            write!(f, "Invalid value '{:?}' for 'TEST_NEGATABLE_COLOR'.\n\nHint: the allowed values are 0, false, 1, true.", err)
+        },
+        EnvParseError::FieldFast(ref err) => {
+            write!(f, "Invalid value '{:?}' for 'TEST_NEGATABLE_FAST'.\n\nHint: the allowed values are 0, false, 1, true.", err)
+        },
+        EnvParseError::FieldCacheEnabled(ref err) => {
+            write!(f, "Invalid value '{:?}' for 'TEST_NEGATABLE_CACHE_ENABLED'.\n\nHint: the allowed values are 0, false, 1, true.", err)
+        },
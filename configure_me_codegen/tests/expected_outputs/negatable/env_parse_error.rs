@@ -0,0 +1,4 @@
+    FieldVerbose(::std::ffi::OsString),
+    FieldColor(::std::ffi::OsString),
+    FieldFast(::std::ffi::OsString),
+    FieldCacheEnabled(::std::ffi::OsString),
@@ -0,0 +1,20 @@
+            let mut config: Self = ::configure_me::toml::from_slice(&config_content).map_err(|error| super::Error::ConfigParsing { file: config_file_name.as_ref().into(), error })?;
+            if let Some(value) = config.cache.on.take() {
+                config.warnings.push(super::Warning::Alias { alias: "cache.on", name: "cache.enabled", source: super::Source::ConfigFile(config_file_name.as_ref().into()) });
+                if config.cache.enabled.is_none() {
+                    config.cache.enabled = Some(value);
+                }
+            }
+            if config.verbose.is_some() {
+                config.verbose_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.color.is_some() {
+                config.color_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.fast.is_some() {
+                config.fast_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.cache.enabled.is_some() {
+                config.cache.enabled_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            Ok(config)
@@ -0,0 +1,29 @@
+                } else if arg == *"--verbose" {
+                    self.verbose = Some(true);
+                    self.verbose_source = Some(super::Source::Argument("--verbose"));
+                } else if arg == *"--no-verbose" {
+                    self.verbose = Some(false);
+                    self.verbose_source = Some(super::Source::Argument("--no-verbose"));
+                } else if arg == *"--color" {
+                    self.color = Some(true);
+                    self.color_source = Some(super::Source::Argument("--color"));
+                } else if arg == *"--no-color" {
+                    self.color = Some(false);
+                    self.color_source = Some(super::Source::Argument("--no-color"));
+                } else if arg == *"--fast" {
+                    self.fast = Some(true);
+                    self.fast_source = Some(super::Source::Argument("--fast"));
+                } else if arg == *"--cache-enabled" {
+                    self.cache.enabled = Some(true);
+                    self.cache.enabled_source = Some(super::Source::Argument("--cache-enabled"));
+                } else if arg == *"--no-cache-enabled" {
+                    self.cache.enabled = Some(false);
+                    self.cache.enabled_source = Some(super::Source::Argument("--no-cache-enabled"));
+                } else if arg == *"--cache-on" {
+                    self.warnings.push(super::Warning::Alias { alias: "cache.on", name: "cache.enabled", source: super::Source::Argument("--cache-on") });
+                    self.cache.enabled = Some(true);
+                    self.cache.enabled_source = Some(super::Source::Argument("--cache-on"));
+                } else if arg == *"--no-cache-on" {
+                    self.warnings.push(super::Warning::Alias { alias: "cache.on", name: "cache.enabled", source: super::Source::Argument("--no-cache-on") });
+                    self.cache.enabled = Some(false);
+                    self.cache.enabled_source = Some(super::Source::Argument("--no-cache-on"));
@@ -0,0 +1,51 @@
+        if let Some(val) = ::std::env::var_os("TEST_NEGATABLE_VERBOSE") {
+            if val == *"1" || val == *"true" {
+                self.verbose = Some(true);
+            } else if val == *"0" || val == *"false" {
+                self.verbose = Some(false);
+            } else {
+                return Err(super::EnvParseError::FieldVerbose(val).into());
+            }
+            self.verbose_source = Some(super::Source::EnvVar("TEST_NEGATABLE_VERBOSE"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_NEGATABLE_COLOR") {
+            if val == *"1" || val == *"true" {
+                self.color = Some(true);
+            } else if val == *"0" || val == *"false" {
+                self.color = Some(false);
+            } else {
+                return Err(super::EnvParseError::FieldColor(val).into());
+            }
+            self.color_source = Some(super::Source::EnvVar("TEST_NEGATABLE_COLOR"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_NEGATABLE_FAST") {
+            if val == *"1" || val == *"true" {
+                self.fast = Some(true);
+            } else if val == *"0" || val == *"false" {
+                self.fast = Some(false);
+            } else {
+                return Err(super::EnvParseError::FieldFast(val).into());
+            }
+            self.fast_source = Some(super::Source::EnvVar("TEST_NEGATABLE_FAST"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_NEGATABLE_CACHE_ON") {
+            self.warnings.push(super::Warning::Alias { alias: "cache.on", name: "cache.enabled", source: super::Source::EnvVar("TEST_NEGATABLE_CACHE_ON") });
+            if val == *"1" || val == *"true" {
+                self.cache.enabled = Some(true);
+            } else if val == *"0" || val == *"false" {
+                self.cache.enabled = Some(false);
+            } else {
+                return Err(super::EnvParseError::FieldCacheEnabled(val).into());
+            }
+            self.cache.enabled_source = Some(super::Source::EnvVar("TEST_NEGATABLE_CACHE_ON"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_NEGATABLE_CACHE_ENABLED") {
+            if val == *"1" || val == *"true" {
+                self.cache.enabled = Some(true);
+            } else if val == *"0" || val == *"false" {
+                self.cache.enabled = Some(false);
+            } else {
+                return Err(super::EnvParseError::FieldCacheEnabled(val).into());
+            }
+            self.cache.enabled_source = Some(super::Source::EnvVar("TEST_NEGATABLE_CACHE_ENABLED"));
+        }
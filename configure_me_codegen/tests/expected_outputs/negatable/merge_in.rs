@@ -0,0 +1,20 @@
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
+            if other.verbose_source.is_some() {
+                self.verbose_source = other.verbose_source;
+            }
+            if other.color.is_some() {
+                self.color = other.color;
+            }
+            if other.color_source.is_some() {
+                self.color_source = other.color_source;
+            }
+            if other.fast.is_some() {
+                self.fast = other.fast;
+            }
+            if other.fast_source.is_some() {
+                self.fast_source = other.fast_source;
+            }
+            self.cache.merge_in(other.cache);
+            self.warnings.extend(other.warnings);
@@ -0,0 +1,13 @@
+        verbose: Option<bool>,
+        color: Option<bool>,
+        fast: Option<bool>,
+        #[serde(skip)]
+        pub verbose_source: Option<super::Source>,
+        #[serde(skip)]
+        pub color_source: Option<super::Source>,
+        #[serde(skip)]
+        pub fast_source: Option<super::Source>,
+        #[serde(default)]
+        pub cache: CacheSection,
+        #[serde(skip)]
+        pub warnings: Vec<super::Warning>,
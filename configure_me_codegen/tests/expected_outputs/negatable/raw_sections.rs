@@ -0,0 +1,28 @@
+
+    #[derive(Deserialize, Default)]
+    #[serde(crate = "crate::configure_me::serde")]
+    pub struct CacheSection {
+        enabled: Option<bool>,
+        on: Option<bool>,
+        #[serde(skip)]
+        pub enabled_source: Option<super::Source>,
+    }
+
+    #[automatically_derived]
+    impl CacheSection {
+        pub fn validate(self) -> Result<super::CacheSection, ValidationError> {
+
+            Ok(super::CacheSection {
+                enabled: self.enabled.unwrap_or(false),
+            })
+        }
+
+        pub fn merge_in(&mut self, other: Self) {
+            if other.enabled.is_some() {
+                self.enabled = other.enabled;
+            }
+            if other.enabled_source.is_some() {
+                self.enabled_source = other.enabled_source;
+            }
+        }
+    }
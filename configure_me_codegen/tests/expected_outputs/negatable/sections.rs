@@ -0,0 +1,5 @@
+/// Configuration of the `cache` section
+pub struct CacheSection {
+    pub enabled: bool,
+}
+
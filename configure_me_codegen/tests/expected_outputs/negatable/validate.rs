@@ -0,0 +1,8 @@
+            let cache = self.cache.validate()?;
+
+            Ok(super::Config {
+                verbose: self.verbose.unwrap_or(false),
+                color: self.color.unwrap_or(true),
+                fast: self.fast.unwrap_or(false),
+                cache,
+            })
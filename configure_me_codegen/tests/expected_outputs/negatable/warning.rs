@@ -0,0 +1,19 @@
+/// A problem with the configuration that doesn't prevent the program from running.
+#[derive(Debug)]
+pub enum Warning {
+    /// A param or switch was specified using its old name.
+    Alias { alias: &'static str, name: &'static str, source: Source },
+    /// A deprecated param or switch was specified.
+    Deprecated { name: &'static str, source: Source, message: &'static str },
+}
+
+#[automatically_derived]
+impl ::std::fmt::Display for Warning {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match self {
+            Warning::Alias { alias, name, source } => write!(f, "Configuration parameter '{}' from {} was renamed to '{}'.", alias, source, name),
+            Warning::Deprecated { name, source, message } => write!(f, "Configuration parameter '{}' from {} is deprecated: {}", name, source, message),
+        }
+    }
+}
+
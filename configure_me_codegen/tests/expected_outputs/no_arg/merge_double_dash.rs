@@ -0,0 +1 @@
+                    return Ok((program_path, None.into_iter().chain(iter)));
@@ -0,0 +1,3 @@
+    pub color: String,
+    pub log: Option<u8>,
+    pub name: Option<String>,
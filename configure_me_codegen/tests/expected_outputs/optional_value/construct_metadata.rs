@@ -0,0 +1,5 @@
+            sources: Sources {
+                color: config.color_source.clone().unwrap_or(Source::Default),
+                log: config.log_source.clone(),
+                name: config.name_source.clone(),
+            },
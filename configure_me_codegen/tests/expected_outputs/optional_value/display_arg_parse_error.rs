@@ -0,0 +1,20 @@
+            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &["--help", "--color", "--log", "--name"]) {
+                Some(known) => write!(f, "An unknown argument '{}' was specified, did you mean '{}'?", arg, known),
+                None => write!(f, "An unknown argument '{}' was specified.", arg),
+            },
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--color[=COLOR]] [-l[LOG]|--log[=LOG]] [--name NAME]\n\nArguments:\n        --color[=COLOR]    When to colorize the output\n        -l, --log[=LOG]    Verbosity of the log\n        --name             Name of the instance", program_name),
+        ArgParseError::FieldColor(err) => {
+            write!(f, "Failed to parse argument '--color': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldLog(err) => {
+            write!(f, "Failed to parse argument '--log': {}.\n\nHint: the value must be ", err)?;
+            <u8 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldName(err) => {
+            write!(f, "Failed to parse argument '--name': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
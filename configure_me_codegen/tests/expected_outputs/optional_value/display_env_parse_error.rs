@@ -0,0 +1,15 @@
+        EnvParseError::FieldColor(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_OPTIONAL_VALUE_COLOR': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldLog(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_OPTIONAL_VALUE_LOG': {}.\n\nHint: the value must be ", err)?;
+            <u8 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldName(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_OPTIONAL_VALUE_NAME': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
@@ -0,0 +1,3 @@
+    FieldColor(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldLog(<u8 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldName(<String as ::configure_me::parse_arg::ParseArg>::Error),
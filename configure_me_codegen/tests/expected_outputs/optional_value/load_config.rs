@@ -0,0 +1,11 @@
+            let mut config: Self = ::configure_me::toml::from_slice(&config_content).map_err(|error| super::Error::ConfigParsing { file: config_file_name.as_ref().into(), error })?;
+            if config.color.is_some() {
+                config.color_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.log.is_some() {
+                config.log_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.name.is_some() {
+                config.name_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            Ok(config)
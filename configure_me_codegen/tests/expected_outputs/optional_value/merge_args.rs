@@ -0,0 +1,15 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--color", &arg, ::std::iter::empty::<&str>()) {
+                    let color = value.or_else(|err| err.map_or(Ok("always".to_owned()), |err| Err(ArgParseError::FieldColor(err))))?;
+
+                    self.color = Some(color);
+                    self.color_source = Some(super::Source::Argument("--color"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--log", &arg, ::std::iter::empty::<&str>()) {
+                    let log = value.or_else(|err| err.map_or(Ok(3), |err| Err(ArgParseError::FieldLog(err))))?;
+
+                    self.log = Some(log);
+                    self.log_source = Some(super::Source::Argument("--log"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--name", &arg, &mut iter) {
+                    let name = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--name"), ArgParseError::FieldName))?;
+
+                    self.name = Some(name);
+                    self.name_source = Some(super::Source::Argument("--name"));
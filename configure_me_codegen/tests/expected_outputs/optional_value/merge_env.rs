@@ -0,0 +1,15 @@
+        if let Some(val) = ::std::env::var_os("TEST_OPTIONAL_VALUE_COLOR") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldColor)?;
+            self.color = Some(val);
+            self.color_source = Some(super::Source::EnvVar("TEST_OPTIONAL_VALUE_COLOR"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_OPTIONAL_VALUE_LOG") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldLog)?;
+            self.log = Some(val);
+            self.log_source = Some(super::Source::EnvVar("TEST_OPTIONAL_VALUE_LOG"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_OPTIONAL_VALUE_NAME") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldName)?;
+            self.name = Some(val);
+            self.name_source = Some(super::Source::EnvVar("TEST_OPTIONAL_VALUE_NAME"));
+        }
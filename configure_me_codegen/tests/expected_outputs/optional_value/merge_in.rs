@@ -0,0 +1,18 @@
+            if other.color.is_some() {
+                self.color = other.color;
+            }
+            if other.color_source.is_some() {
+                self.color_source = other.color_source;
+            }
+            if other.log.is_some() {
+                self.log = other.log;
+            }
+            if other.log_source.is_some() {
+                self.log_source = other.log_source;
+            }
+            if other.name.is_some() {
+                self.name = other.name;
+            }
+            if other.name_source.is_some() {
+                self.name_source = other.name_source;
+            }
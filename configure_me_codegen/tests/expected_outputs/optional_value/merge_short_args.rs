@@ -0,0 +1,4 @@
+                        } else if short == 'l' {
+                            self.log = Some(shorts.parse_remaining(::std::iter::empty::<&str>()).or_else(|err| err.map_or(Ok(3), |err| Err(ArgParseError::FieldLog(err))))?);
+                            self.log_source = Some(super::Source::Argument("-l"));
+                            break;
@@ -0,0 +1,9 @@
+        color: Option<String>,
+        log: Option<u8>,
+        name: Option<String>,
+        #[serde(skip)]
+        pub color_source: Option<super::Source>,
+        #[serde(skip)]
+        pub log_source: Option<super::Source>,
+        #[serde(skip)]
+        pub name_source: Option<super::Source>,
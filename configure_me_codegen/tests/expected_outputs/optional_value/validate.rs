@@ -0,0 +1,9 @@
+            let color = self.color.unwrap_or_else(|| { "auto".to_owned() });
+            let log = self.log;
+            let name = self.name;
+
+            Ok(super::Config {
+                color,
+                log,
+                name,
+            })
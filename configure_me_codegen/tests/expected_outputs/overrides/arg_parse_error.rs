@@ -0,0 +1,4 @@
+    FieldListen(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldProxy(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldLogFilter(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldDbUrl(<String as ::configure_me::parse_arg::ParseArg>::Error),
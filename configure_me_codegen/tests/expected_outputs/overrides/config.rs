@@ -0,0 +1,5 @@
+    pub listen: String,
+    pub proxy: Option<String>,
+    pub log_filter: Option<String>,
+    pub no_color: bool,
+    pub db: DbSection,
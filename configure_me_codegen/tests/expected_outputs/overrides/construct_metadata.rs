@@ -0,0 +1,7 @@
+            sources: Sources {
+                listen: config.listen_source.clone().unwrap_or(Source::Default),
+                proxy: config.proxy_source.clone(),
+                log_filter: config.log_filter_source.clone(),
+                db_url: config.db.url_source.clone(),
+                no_color: config.no_color_source.clone().unwrap_or(Source::Default),
+            },
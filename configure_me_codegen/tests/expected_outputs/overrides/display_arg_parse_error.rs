@@ -0,0 +1,25 @@
+            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &["--help", "--listen-address", "--proxy", "--log-filter", "--database-url", "--plain"]) {
+                Some(known) => write!(f, "An unknown argument '{}' was specified, did you mean '{}'?", arg, known),
+                None => write!(f, "An unknown argument '{}' was specified.", arg),
+            },
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [ARGUMENTS...]\n\nArguments:\n        --listen-address    Address to listen on\n        --proxy             Proxy used for outgoing connections\n        --log-filter\n\n        --plain             Disable colored output\n\nArguments in section db:\n        --database-url\n", program_name),
+        ArgParseError::FieldListen(err) => {
+            write!(f, "Failed to parse argument '--listen-address': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldProxy(err) => {
+            write!(f, "Failed to parse argument '--proxy': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldLogFilter(err) => {
+            write!(f, "Failed to parse argument '--log-filter': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldDbUrl(err) => {
+            write!(f, "Failed to parse argument '--database-url': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
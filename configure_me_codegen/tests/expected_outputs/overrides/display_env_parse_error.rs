@@ -0,0 +1,23 @@
+        EnvParseError::FieldListen(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_OVERRIDES_LISTEN': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldProxy(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_OVERRIDES_PROXY': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldLogFilter(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_OVERRIDES_LOG': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldDbUrl(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_OVERRIDES_DATABASE_URL': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldNoColor(ref err) => {
+            write!(f, "Invalid value '{:?}' for 'TEST_OVERRIDES_NO_COLOR'.\n\nHint: the allowed values are 0, false, 1, true.", err)
+        },
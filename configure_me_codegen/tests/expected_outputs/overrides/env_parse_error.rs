@@ -0,0 +1,5 @@
+    FieldListen(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldProxy(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldLogFilter(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldNoColor(::std::ffi::OsString),
+    FieldDbUrl(<String as ::configure_me::parse_arg::ParseArg>::Error),
@@ -0,0 +1,17 @@
+            let mut config: Self = ::configure_me::toml::from_slice(&config_content).map_err(|error| super::Error::ConfigParsing { file: config_file_name.as_ref().into(), error })?;
+            if config.listen.is_some() {
+                config.listen_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.proxy.is_some() {
+                config.proxy_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.log_filter.is_some() {
+                config.log_filter_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.db.url.is_some() {
+                config.db.url_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.no_color.is_some() {
+                config.no_color_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            Ok(config)
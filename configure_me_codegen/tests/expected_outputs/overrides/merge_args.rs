@@ -0,0 +1,23 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--listen-address", &arg, &mut iter) {
+                    let listen = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--listen-address"), ArgParseError::FieldListen))?;
+
+                    self.listen = Some(listen);
+                    self.listen_source = Some(super::Source::Argument("--listen-address"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--proxy", &arg, &mut iter) {
+                    let proxy = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--proxy"), ArgParseError::FieldProxy))?;
+
+                    self.proxy = Some(proxy);
+                    self.proxy_source = Some(super::Source::Argument("--proxy"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--log-filter", &arg, &mut iter) {
+                    let log_filter = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--log-filter"), ArgParseError::FieldLogFilter))?;
+
+                    self.log_filter = Some(log_filter);
+                    self.log_filter_source = Some(super::Source::Argument("--log-filter"));
+                } else if arg == *"--plain" {
+                    self.no_color = Some(true);
+                    self.no_color_source = Some(super::Source::Argument("--plain"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--database-url", &arg, &mut iter) {
+                    let db_url = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--database-url"), ArgParseError::FieldDbUrl))?;
+
+                    self.db.url = Some(db_url);
+                    self.db.url_source = Some(super::Source::Argument("--database-url"));
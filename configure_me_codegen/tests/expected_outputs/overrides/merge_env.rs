@@ -0,0 +1,34 @@
+        if let Some(val) = ::std::env::var_os("TEST_OVERRIDES_LISTEN") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldListen)?;
+            self.listen = Some(val);
+            self.listen_source = Some(super::Source::EnvVar("TEST_OVERRIDES_LISTEN"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_OVERRIDES_PROXY") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldProxy)?;
+            self.proxy = Some(val);
+            self.proxy_source = Some(super::Source::EnvVar("TEST_OVERRIDES_PROXY"));
+        } else if let Some(val) = ::std::env::var_os("TEST_OVERRIDES_HTTP_PROXY") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldProxy)?;
+            self.proxy = Some(val);
+            self.proxy_source = Some(super::Source::EnvVar("TEST_OVERRIDES_HTTP_PROXY"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_OVERRIDES_LOG") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldLogFilter)?;
+            self.log_filter = Some(val);
+            self.log_filter_source = Some(super::Source::EnvVar("TEST_OVERRIDES_LOG"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_OVERRIDES_DATABASE_URL") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldDbUrl)?;
+            self.db.url = Some(val);
+            self.db.url_source = Some(super::Source::EnvVar("TEST_OVERRIDES_DATABASE_URL"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_OVERRIDES_NO_COLOR") {
+            if val == *"1" || val == *"true" {
+                self.no_color = Some(true);
+            } else if val == *"0" || val == *"false" {
+                self.no_color = Some(false);
+            } else {
+                return Err(super::EnvParseError::FieldNoColor(val).into());
+            }
+            self.no_color_source = Some(super::Source::EnvVar("TEST_OVERRIDES_NO_COLOR"));
+        }
@@ -0,0 +1,25 @@
+            if other.listen.is_some() {
+                self.listen = other.listen;
+            }
+            if other.listen_source.is_some() {
+                self.listen_source = other.listen_source;
+            }
+            if other.proxy.is_some() {
+                self.proxy = other.proxy;
+            }
+            if other.proxy_source.is_some() {
+                self.proxy_source = other.proxy_source;
+            }
+            if other.log_filter.is_some() {
+                self.log_filter = other.log_filter;
+            }
+            if other.log_filter_source.is_some() {
+                self.log_filter_source = other.log_filter_source;
+            }
+            if other.no_color.is_some() {
+                self.no_color = other.no_color;
+            }
+            if other.no_color_source.is_some() {
+                self.no_color_source = other.no_color_source;
+            }
+            self.db.merge_in(other.db);
@@ -0,0 +1,15 @@
+        #[serde(rename = "listen-address")]
+        listen: Option<String>,
+        proxy: Option<String>,
+        log_filter: Option<String>,
+        no_color: Option<bool>,
+        #[serde(skip)]
+        pub listen_source: Option<super::Source>,
+        #[serde(skip)]
+        pub proxy_source: Option<super::Source>,
+        #[serde(skip)]
+        pub log_filter_source: Option<super::Source>,
+        #[serde(skip)]
+        pub no_color_source: Option<super::Source>,
+        #[serde(default)]
+        pub db: DbSection,
@@ -0,0 +1,28 @@
+
+    #[derive(Deserialize, Default)]
+    #[serde(crate = "crate::configure_me::serde")]
+    pub struct DbSection {
+        url: Option<String>,
+        #[serde(skip)]
+        pub url_source: Option<super::Source>,
+    }
+
+    #[automatically_derived]
+    impl DbSection {
+        pub fn validate(self) -> Result<super::DbSection, ValidationError> {
+            let url = self.url;
+
+            Ok(super::DbSection {
+                url,
+            })
+        }
+
+        pub fn merge_in(&mut self, other: Self) {
+            if other.url.is_some() {
+                self.url = other.url;
+            }
+            if other.url_source.is_some() {
+                self.url_source = other.url_source;
+            }
+        }
+    }
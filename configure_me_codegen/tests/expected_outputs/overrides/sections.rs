@@ -0,0 +1,5 @@
+/// Configuration of the `db` section
+pub struct DbSection {
+    pub url: Option<String>,
+}
+
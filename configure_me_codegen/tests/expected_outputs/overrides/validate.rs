@@ -0,0 +1,12 @@
+            let listen = self.listen.unwrap_or_else(|| { "127.0.0.1:8080".to_owned() });
+            let proxy = self.proxy;
+            let log_filter = self.log_filter;
+            let db = self.db.validate()?;
+
+            Ok(super::Config {
+                listen,
+                proxy,
+                log_filter,
+                no_color: self.no_color.unwrap_or(false),
+                db,
+            })
@@ -0,0 +1,4 @@
+    FieldFoo(<u32 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldInput(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldOutput(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldNumbers(<u32 as ::configure_me::parse_arg::ParseArg>::Error),
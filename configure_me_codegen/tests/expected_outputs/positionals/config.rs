@@ -0,0 +1,5 @@
+    pub foo: Option<u32>,
+    pub verbose: bool,
+    pub input: String,
+    pub output: Option<String>,
+    pub numbers: Vec<u32>,
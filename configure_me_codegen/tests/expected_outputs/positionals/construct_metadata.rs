@@ -0,0 +1,4 @@
+            sources: Sources {
+                foo: config.foo_source.clone(),
+                verbose: config.verbose_source.clone().unwrap_or(Source::Default),
+            },
@@ -0,0 +1,25 @@
+            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &["--help", "--foo", "--verbose"]) {
+                Some(known) => write!(f, "An unknown argument '{}' was specified, did you mean '{}'?", arg, known),
+                None => write!(f, "An unknown argument '{}' was specified.", arg),
+            },
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--foo FOO] [-v|--verbose] INPUT [OUTPUT] [NUMBERS...]\n\nPositional arguments:\n        INPUT    The input", program_name),
+        ArgParseError::FieldFoo(err) => {
+            write!(f, "Failed to parse argument '--foo': {}.\n\nHint: the value must be ", err)?;
+            <u32 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldInput(err) => {
+            write!(f, "Failed to parse positional argument 'INPUT': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldOutput(err) => {
+            write!(f, "Failed to parse positional argument 'OUTPUT': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldNumbers(err) => {
+            write!(f, "Failed to parse positional argument 'NUMBERS': {}.\n\nHint: the value must be ", err)?;
+            <u32 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
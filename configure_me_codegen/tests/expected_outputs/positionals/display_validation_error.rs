@@ -0,0 +1 @@
+            ValidationError::MissingPositional(positional) => write!(f, "Positional argument '{}' not specified.", positional),
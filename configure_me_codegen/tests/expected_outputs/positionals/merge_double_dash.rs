@@ -0,0 +1,6 @@
+                    while let Some(arg) = iter.next() {
+                        if let Some(arg) = self.merge_positional(arg)? {
+                            return Ok((program_path, Some(arg).into_iter().chain(iter)));
+                        }
+                    }
+                    return Ok((program_path, None.into_iter().chain(iter)));
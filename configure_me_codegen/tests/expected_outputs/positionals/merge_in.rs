@@ -0,0 +1,21 @@
+            if other.foo.is_some() {
+                self.foo = other.foo;
+            }
+            if other.foo_source.is_some() {
+                self.foo_source = other.foo_source;
+            }
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
+            if other.verbose_source.is_some() {
+                self.verbose_source = other.verbose_source;
+            }
+            if other.input.is_some() {
+                self.input = other.input;
+            }
+            if other.output.is_some() {
+                self.output = other.output;
+            }
+            if !other.numbers.is_empty() {
+                self.numbers = other.numbers;
+            }
@@ -0,0 +1,2 @@
+                } else if let Some(arg) = self.merge_positional(arg)? {
+                    return Ok((program_path, Some(arg).into_iter().chain(iter)))
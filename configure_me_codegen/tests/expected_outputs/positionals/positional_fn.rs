@@ -0,0 +1,12 @@
+
+        fn merge_positional(&mut self, arg: ::std::ffi::OsString) -> Result<Option<::std::ffi::OsString>, super::Error> {
+            if self.input.is_none() {
+                self.input = Some(<String as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(arg).map_err(ArgParseError::FieldInput)?);
+            } else if self.output.is_none() {
+                self.output = Some(<String as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(arg).map_err(ArgParseError::FieldOutput)?);
+            } else {
+                self.numbers.push(<u32 as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(arg).map_err(ArgParseError::FieldNumbers)?);
+            }
+
+            Ok(None)
+        }
@@ -0,0 +1,12 @@
+        foo: Option<u32>,
+        verbose: Option<bool>,
+        #[serde(skip)]
+        input: Option<String>,
+        #[serde(skip)]
+        output: Option<String>,
+        #[serde(skip)]
+        numbers: Vec<u32>,
+        #[serde(skip)]
+        pub foo_source: Option<super::Source>,
+        #[serde(skip)]
+        pub verbose_source: Option<super::Source>,
@@ -0,0 +1,12 @@
+            let foo = self.foo;
+            let input = self.input.ok_or(ValidationError::MissingPositional("INPUT"))?;
+            let output = self.output;
+            let numbers = self.numbers;
+
+            Ok(super::Config {
+                foo,
+                verbose: self.verbose.unwrap_or(false),
+                input,
+                output,
+                numbers,
+            })
@@ -0,0 +1 @@
+    MissingPositional(&'static str),
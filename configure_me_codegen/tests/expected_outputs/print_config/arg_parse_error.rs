@@ -0,0 +1,8 @@
+    FieldPort(<u16 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldBindAddr(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldPassword(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldTimeout(<::configure_me::types::Duration as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldMode(<Mode as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldPeer(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldNetworkMaxPeers(<u32 as ::configure_me::parse_arg::ParseArg>::Error),
+    ReadSecretFile(std::io::Error, std::path::PathBuf),
@@ -0,0 +1,11 @@
+    pub port: u16,
+    #[serde(rename = "bind-addr")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bind_addr: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<::configure_me::secret::Secret<String>>,
+    pub timeout: ::configure_me::types::Duration,
+    pub mode: Mode,
+    pub peer: Vec<String>,
+    pub verbose: bool,
+    pub network: NetworkSection,
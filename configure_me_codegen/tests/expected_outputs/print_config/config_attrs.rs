@@ -0,0 +1,2 @@
+#[derive(Serialize)]
+#[serde(crate = "crate::configure_me::serde")]
@@ -0,0 +1,6 @@
+    ConfigParsing { file: ::std::path::PathBuf, error: ::configure_me::toml::de::Error },
+    /// The configuration requested by the print config switch, not an actual error
+    PrintConfig(String),
+    SerializingConfig(::configure_me::toml::ser::Error),
+    /// The configuration passed the check requested by the check config switch, not an actual error
+    ConfigValid,
@@ -0,0 +1,10 @@
+            sources: Sources {
+                port: config.port_source.clone().unwrap_or(Source::Default),
+                bind_addr: config.bind_addr_source.clone(),
+                password: config.password_source.clone(),
+                timeout: config.timeout_source.clone().unwrap_or(Source::Default),
+                mode: config.mode_source.clone().unwrap_or(Source::Default),
+                peer: config.peer_source.clone().unwrap_or(Source::Default),
+                network_max_peers: config.network.max_peers_source.clone().unwrap_or(Source::Default),
+                verbose: config.verbose_source.clone().unwrap_or(Source::Default),
+            },
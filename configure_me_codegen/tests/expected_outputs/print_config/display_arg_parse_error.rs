@@ -0,0 +1,41 @@
+            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &["--help", "--print-config", "--check-config", "--port", "--bind-addr", "--password", "--password-file", "--timeout", "--mode", "--peer", "--network-max-peers", "--verbose"]) {
+                Some(known) => write!(f, "An unknown argument '{}' was specified, did you mean '{}'?", arg, known),
+                None => write!(f, "An unknown argument '{}' was specified.", arg),
+            },
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [ARGUMENTS...]\n\nArguments:\n        --print-config         Print the configuration and exit.\n        --check-config         Check the configuration and exit.\n        --port                 Port to listen on (must be at least 1)\n        --bind-addr            Address to bind to\n        --password             Password of the admin (sensitive, can be read \n                               from a file using --password-file)\n        --timeout              How long to wait for a response (a duration with \n                               units ms, s, m, h or d, e.g. 1h30m)\n        --mode                 Mode of operation (possible values: fast, slow)\n        --peer                 Peer to connect to\n        --verbose              Print more information\n\nArguments in section network:\n        --network-max-peers    Maximum number of peers", program_name),
+        ArgParseError::FieldPort(err) => {
+            write!(f, "Failed to parse argument '--port': {}.\n\nHint: the value must be ", err)?;
+            <u16 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldBindAddr(err) => {
+            write!(f, "Failed to parse argument '--bind-addr': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldPassword(_) => {
+            write!(f, "Failed to parse argument '--password'.\n\nHint: the value must be ")?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldTimeout(err) => {
+            write!(f, "Failed to parse argument '--timeout': {}.\n\nHint: the value must be ", err)?;
+            <::configure_me::types::Duration as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldMode(err) => {
+            write!(f, "Failed to parse argument '--mode': {}.\n\nHint: the value must be ", err)?;
+            <Mode as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldPeer(err) => {
+            write!(f, "Failed to parse argument '--peer': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldNetworkMaxPeers(err) => {
+            write!(f, "Failed to parse argument '--network-max-peers': {}.\n\nHint: the value must be ", err)?;
+            <u32 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::ReadSecretFile(err, file) => write!(f, "Failed to read secret file {}: {}", file.display(), err),
@@ -0,0 +1,3 @@
+            Error::PrintConfig(config) => write!(f, "{}", config.trim_end()),
+            Error::SerializingConfig(error) => write!(f, "Failed to serialize the configuration: {}", error),
+            Error::ConfigValid => write!(f, "The configuration is valid."),
@@ -0,0 +1,39 @@
+        EnvParseError::FieldPort(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_PRINT_CONFIG_PORT': {}.\n\nHint: the value must be ", err)?;
+            <u16 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldBindAddr(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_PRINT_CONFIG_BIND_ADDR': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldPassword(_) => {
+            write!(f, "Failed to parse environment variable 'TEST_PRINT_CONFIG_PASSWORD'.\n\nHint: the value must be ")?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldTimeout(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_PRINT_CONFIG_TIMEOUT': {}.\n\nHint: the value must be ", err)?;
+            <::configure_me::types::Duration as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldMode(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_PRINT_CONFIG_MODE': {}.\n\nHint: the value must be ", err)?;
+            <Mode as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldPeer(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_PRINT_CONFIG_PEER': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldNetworkMaxPeers(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_PRINT_CONFIG_NETWORK_MAX_PEERS': {}.\n\nHint: the value must be ", err)?;
+            <u32 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldVerbose(ref err) => {
+            write!(f, "Invalid value '{:?}' for 'TEST_PRINT_CONFIG_VERBOSE'.\n\nHint: the allowed values are 0, false, 1, true.", err)
+        },
+        EnvParseError::ReadSecretFile(ref err, ref file) => write!(f, "Failed to read secret file {}: {}", file.display(), err),
@@ -0,0 +1 @@
+            ValidationError::OutOfRange { field, ref source, constraint } => write!(f, "Value of configuration parameter '{}' from {} must be {}.", field, source, constraint),
@@ -0,0 +1,2 @@
+            Err(err @ Error::PrintConfig(_)) |
+            Err(err @ Error::ConfigValid) |
@@ -0,0 +1,26 @@
+            let mut config: Self = ::configure_me::toml::from_slice(&config_content).map_err(|error| super::Error::ConfigParsing { file: config_file_name.as_ref().into(), error })?;
+            if config.port.is_some() {
+                config.port_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.bind_addr.is_some() {
+                config.bind_addr_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.password.is_some() {
+                config.password_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.timeout.is_some() {
+                config.timeout_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.mode.is_some() {
+                config.mode_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.peer.is_some() {
+                config.peer_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.network.max_peers.is_some() {
+                config.network.max_peers_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.verbose.is_some() {
+                config.verbose_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            Ok(config)
@@ -0,0 +1,49 @@
+                } else if arg == "--print-config" {
+                    self.print_config = true;
+                } else if arg == "--check-config" {
+                    self.check_config = true;
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--port", &arg, &mut iter) {
+                    let port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--port"), ArgParseError::FieldPort))?;
+
+                    self.port = Some(port);
+                    self.port_source = Some(super::Source::Argument("--port"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--bind-addr", &arg, &mut iter) {
+                    let bind_addr = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--bind-addr"), ArgParseError::FieldBindAddr))?;
+
+                    self.bind_addr = Some(bind_addr);
+                    self.bind_addr_source = Some(super::Source::Argument("--bind-addr"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--password", &arg, &mut iter) {
+                    let password = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--password"), ArgParseError::FieldPassword))?;
+
+                    self.password = Some(password);
+                    self.password_source = Some(super::Source::Argument("--password"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--password-file", &arg, &mut iter) {
+                    let file_path: std::path::PathBuf = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--password-file"), |never| match never {}))?;
+                    let password = ::configure_me::secret::read_file(&file_path).map_err(|err| ArgParseError::ReadSecretFile(err, file_path))?;
+                    let password = <String as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(password).map_err(ArgParseError::FieldPassword)?;
+
+                    self.password = Some(password);
+                    self.password_source = Some(super::Source::Argument("--password-file"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--timeout", &arg, &mut iter) {
+                    let timeout = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--timeout"), ArgParseError::FieldTimeout))?;
+
+                    self.timeout = Some(timeout);
+                    self.timeout_source = Some(super::Source::Argument("--timeout"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--mode", &arg, &mut iter) {
+                    let mode = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--mode"), ArgParseError::FieldMode))?;
+
+                    self.mode = Some(mode);
+                    self.mode_source = Some(super::Source::Argument("--mode"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--peer", &arg, &mut iter) {
+                    let peer = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--peer"), ArgParseError::FieldPeer))?;
+
+                    self.peer.get_or_insert_with(Vec::new).push(peer);
+                    self.peer_source = Some(super::Source::Argument("--peer"));
+                } else if arg == *"--verbose" {
+                    self.verbose = Some(true);
+                    self.verbose_source = Some(super::Source::Argument("--verbose"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--network-max-peers", &arg, &mut iter) {
+                    let network_max_peers = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--network-max-peers"), ArgParseError::FieldNetworkMaxPeers))?;
+
+                    self.network.max_peers = Some(network_max_peers);
+                    self.network.max_peers_source = Some(super::Source::Argument("--network-max-peers"));
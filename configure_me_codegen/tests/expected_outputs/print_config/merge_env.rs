@@ -0,0 +1,56 @@
+        if let Some(val) = ::std::env::var_os("TEST_PRINT_CONFIG_PORT") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldPort)?;
+            self.port = Some(val);
+            self.port_source = Some(super::Source::EnvVar("TEST_PRINT_CONFIG_PORT"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_PRINT_CONFIG_BIND_ADDR") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldBindAddr)?;
+            self.bind_addr = Some(val);
+            self.bind_addr_source = Some(super::Source::EnvVar("TEST_PRINT_CONFIG_BIND_ADDR"));
+        }
+        if let Some(file_path) = ::std::env::var_os("TEST_PRINT_CONFIG_PASSWORD_FILE") {
+            let file_path = ::std::path::PathBuf::from(file_path);
+            let val = ::configure_me::secret::read_file(&file_path).map_err(|err| super::EnvParseError::ReadSecretFile(err, file_path))?;
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldPassword)?;
+            self.password = Some(val);
+            self.password_source = Some(super::Source::EnvVar("TEST_PRINT_CONFIG_PASSWORD_FILE"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_PRINT_CONFIG_PASSWORD") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldPassword)?;
+            self.password = Some(val);
+            self.password_source = Some(super::Source::EnvVar("TEST_PRINT_CONFIG_PASSWORD"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_PRINT_CONFIG_TIMEOUT") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldTimeout)?;
+            self.timeout = Some(val);
+            self.timeout_source = Some(super::Source::EnvVar("TEST_PRINT_CONFIG_TIMEOUT"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_PRINT_CONFIG_MODE") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldMode)?;
+            self.mode = Some(val);
+            self.mode_source = Some(super::Source::EnvVar("TEST_PRINT_CONFIG_MODE"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_PRINT_CONFIG_PEER") {
+            let val = match val.into_string() {
+                Ok(val) => val.split(",").map(|item| <String as ::configure_me::parse_arg::ParseArg>::parse_arg(item.as_ref())).collect::<Result<Vec<_>, _>>(),
+                // Values that aren't valid UTF-8 can't be split
+                Err(val) => <String as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(val).map(|item| vec![item]),
+            }.map_err(super::EnvParseError::FieldPeer)?;
+            self.peer = Some(val);
+            self.peer_source = Some(super::Source::EnvVar("TEST_PRINT_CONFIG_PEER"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_PRINT_CONFIG_NETWORK_MAX_PEERS") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldNetworkMaxPeers)?;
+            self.network.max_peers = Some(val);
+            self.network.max_peers_source = Some(super::Source::EnvVar("TEST_PRINT_CONFIG_NETWORK_MAX_PEERS"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_PRINT_CONFIG_VERBOSE") {
+            if val == *"1" || val == *"true" {
+                self.verbose = Some(true);
+            } else if val == *"0" || val == *"false" {
+                self.verbose = Some(false);
+            } else {
+                return Err(super::EnvParseError::FieldVerbose(val).into());
+            }
+            self.verbose_source = Some(super::Source::EnvVar("TEST_PRINT_CONFIG_VERBOSE"));
+        }
@@ -0,0 +1,45 @@
+            if other.port.is_some() {
+                self.port = other.port;
+            }
+            if other.port_source.is_some() {
+                self.port_source = other.port_source;
+            }
+            if other.bind_addr.is_some() {
+                self.bind_addr = other.bind_addr;
+            }
+            if other.bind_addr_source.is_some() {
+                self.bind_addr_source = other.bind_addr_source;
+            }
+            if other.password.is_some() {
+                self.password = other.password;
+            }
+            if other.password_source.is_some() {
+                self.password_source = other.password_source;
+            }
+            if other.timeout.is_some() {
+                self.timeout = other.timeout;
+            }
+            if other.timeout_source.is_some() {
+                self.timeout_source = other.timeout_source;
+            }
+            if other.mode.is_some() {
+                self.mode = other.mode;
+            }
+            if other.mode_source.is_some() {
+                self.mode_source = other.mode_source;
+            }
+            if other.peer.is_some() {
+                self.peer = other.peer;
+            }
+            if other.peer_source.is_some() {
+                self.peer_source = other.peer_source;
+            }
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
+            if other.verbose_source.is_some() {
+                self.verbose_source = other.verbose_source;
+            }
+            self.network.merge_in(other.network);
+            self.print_config |= other.print_config;
+            self.check_config |= other.check_config;
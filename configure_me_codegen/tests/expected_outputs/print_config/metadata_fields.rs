@@ -0,0 +1,2 @@
+    /// Where the value of each parameter and switch came from
+    pub sources: Sources,
@@ -0,0 +1,8 @@
+        if config.check_config {
+            config.validate()?;
+            return Err(Error::ConfigValid);
+        }
+        if config.print_config {
+            let config = config.validate()?;
+            return Err(::configure_me::toml::to_string(&config).map_or_else(Error::SerializingConfig, Error::PrintConfig));
+        }
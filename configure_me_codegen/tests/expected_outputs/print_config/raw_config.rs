@@ -0,0 +1,28 @@
+        port: Option<u16>,
+        #[serde(rename = "bind-addr")]
+        bind_addr: Option<String>,
+        password: Option<String>,
+        timeout: Option<::configure_me::types::Duration>,
+        mode: Option<Mode>,
+        peer: Option<Vec<String>>,
+        verbose: Option<bool>,
+        #[serde(skip)]
+        pub port_source: Option<super::Source>,
+        #[serde(skip)]
+        pub bind_addr_source: Option<super::Source>,
+        #[serde(skip)]
+        pub password_source: Option<super::Source>,
+        #[serde(skip)]
+        pub timeout_source: Option<super::Source>,
+        #[serde(skip)]
+        pub mode_source: Option<super::Source>,
+        #[serde(skip)]
+        pub peer_source: Option<super::Source>,
+        #[serde(skip)]
+        pub verbose_source: Option<super::Source>,
+        #[serde(default)]
+        pub network: NetworkSection,
+        #[serde(skip)]
+        pub print_config: bool,
+        #[serde(skip)]
+        pub check_config: bool,
@@ -0,0 +1,7 @@
+/// Configuration of the `network` section
+#[derive(Serialize)]
+#[serde(crate = "crate::configure_me::serde")]
+pub struct NetworkSection {
+    pub max_peers: u32,
+}
+
@@ -0,0 +1,21 @@
+            let port = self.port.unwrap_or_else(|| { 8080 });
+            if port < 1 {
+                return Err(ValidationError::OutOfRange { field: "port", source: self.port_source.unwrap_or(super::Source::Default), constraint: "at least 1" });
+            }
+            let bind_addr = self.bind_addr;
+            let password = self.password;
+            let timeout = self.timeout.unwrap_or_else(|| { ::std::time::Duration::from_secs(30).into() });
+            let mode = self.mode.unwrap_or_else(|| { Mode::Fast });
+            let peer = self.peer.unwrap_or_default();
+            let network = self.network.validate()?;
+
+            Ok(super::Config {
+                port,
+                bind_addr,
+                password: password.map(::configure_me::secret::Secret::new),
+                timeout,
+                mode,
+                peer,
+                verbose: self.verbose.unwrap_or(false),
+                network,
+            })
@@ -0,0 +1 @@
+    OutOfRange { field: &'static str, source: Source, constraint: &'static str },
@@ -0,0 +1,63 @@
+/// Error returned when parsing a value that is not allowed
+#[derive(Debug, Clone)]
+pub struct InvalidValue(String);
+
+#[automatically_derived]
+impl ::std::fmt::Display for InvalidValue {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "invalid value '{}'", self.0)
+    }
+}
+
+/// Possible values of the `mode` parameter
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Mode {
+    Fast,
+    Slow,
+}
+
+#[automatically_derived]
+impl ::std::str::FromStr for Mode {
+    type Err = InvalidValue;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fast" => Ok(Mode::Fast),
+            "slow" => Ok(Mode::Slow),
+            _ => Err(InvalidValue(s.to_owned())),
+        }
+    }
+}
+
+#[automatically_derived]
+impl ::configure_me::parse_arg::ParseArgFromStr for Mode {
+    fn describe_type<W: ::std::fmt::Write>(mut writer: W) -> ::std::fmt::Result {
+        write!(writer, "one of `fast`, `slow`")
+    }
+}
+
+#[automatically_derived]
+impl ::std::fmt::Display for Mode {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match self {
+            Mode::Fast => f.write_str("fast"),
+            Mode::Slow => f.write_str("slow"),
+        }
+    }
+}
+
+#[automatically_derived]
+impl<'de> ::configure_me::serde::Deserialize<'de> for Mode {
+    fn deserialize<D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = <String as ::configure_me::serde::Deserialize>::deserialize(deserializer)?;
+        value.parse().map_err(|_| <D::Error as ::configure_me::serde::de::Error>::unknown_variant(&value, &["fast", "slow"]))
+    }
+}
+
+#[automatically_derived]
+impl ::configure_me::serde::Serialize for Mode {
+    fn serialize<S: ::configure_me::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
@@ -0,0 +1,3 @@
+    FieldPort(<u16 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldLogLevel(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldLimitsMaxPeers(<u32 as ::configure_me::parse_arg::ParseArg>::Error),
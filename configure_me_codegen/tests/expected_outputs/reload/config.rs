@@ -0,0 +1,4 @@
+    pub port: u16,
+    pub log_level: String,
+    pub verbose: bool,
+    pub limits: LimitsSection,
@@ -0,0 +1,2 @@
+    ConfigParsing { file: ::std::path::PathBuf, error: ::configure_me::toml::de::Error },
+    NotReloadable(&'static str),
@@ -0,0 +1,6 @@
+            sources: Sources {
+                port: config.port_source.clone().unwrap_or(Source::Default),
+                log_level: config.log_level_source.clone().unwrap_or(Source::Default),
+                limits_max_peers: config.limits.max_peers_source.clone().unwrap_or(Source::Default),
+                verbose: config.verbose_source.clone().unwrap_or(Source::Default),
+            },
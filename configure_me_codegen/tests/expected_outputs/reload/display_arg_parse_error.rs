@@ -0,0 +1,20 @@
+            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &["--help", "--port", "--log-level", "--limits-max-peers", "--verbose"]) {
+                Some(known) => write!(f, "An unknown argument '{}' was specified, did you mean '{}'?", arg, known),
+                None => write!(f, "An unknown argument '{}' was specified.", arg),
+            },
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [ARGUMENTS...]\n\nArguments:\n        --port                Port to listen on\n        --log-level           Logging level\n        --verbose             Print more information\n\nArguments in section limits:\n        --limits-max-peers    Maximum number of peers", program_name),
+        ArgParseError::FieldPort(err) => {
+            write!(f, "Failed to parse argument '--port': {}.\n\nHint: the value must be ", err)?;
+            <u16 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldLogLevel(err) => {
+            write!(f, "Failed to parse argument '--log-level': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldLimitsMaxPeers(err) => {
+            write!(f, "Failed to parse argument '--limits-max-peers': {}.\n\nHint: the value must be ", err)?;
+            <u32 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
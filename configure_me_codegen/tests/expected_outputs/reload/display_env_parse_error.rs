@@ -0,0 +1,18 @@
+        EnvParseError::FieldPort(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_RELOAD_PORT': {}.\n\nHint: the value must be ", err)?;
+            <u16 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldLogLevel(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_RELOAD_LOG_LEVEL': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldLimitsMaxPeers(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_RELOAD_LIMITS_MAX_PEERS': {}.\n\nHint: the value must be ", err)?;
+            <u32 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldVerbose(ref err) => {
+            write!(f, "Invalid value '{:?}' for 'TEST_RELOAD_VERBOSE'.\n\nHint: the allowed values are 0, false, 1, true.", err)
+        },
@@ -0,0 +1,4 @@
+    FieldPort(<u16 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldLogLevel(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldVerbose(::std::ffi::OsString),
+    FieldLimitsMaxPeers(<u32 as ::configure_me::parse_arg::ParseArg>::Error),
@@ -0,0 +1,14 @@
+            let mut config: Self = ::configure_me::toml::from_slice(&config_content).map_err(|error| super::Error::ConfigParsing { file: config_file_name.as_ref().into(), error })?;
+            if config.port.is_some() {
+                config.port_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.log_level.is_some() {
+                config.log_level_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.limits.max_peers.is_some() {
+                config.limits.max_peers_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.verbose.is_some() {
+                config.verbose_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            Ok(config)
@@ -0,0 +1,18 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--port", &arg, &mut iter) {
+                    let port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--port"), ArgParseError::FieldPort))?;
+
+                    self.port = Some(port);
+                    self.port_source = Some(super::Source::Argument("--port"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--log-level", &arg, &mut iter) {
+                    let log_level = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--log-level"), ArgParseError::FieldLogLevel))?;
+
+                    self.log_level = Some(log_level);
+                    self.log_level_source = Some(super::Source::Argument("--log-level"));
+                } else if arg == *"--verbose" {
+                    self.verbose = Some(true);
+                    self.verbose_source = Some(super::Source::Argument("--verbose"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--limits-max-peers", &arg, &mut iter) {
+                    let limits_max_peers = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--limits-max-peers"), ArgParseError::FieldLimitsMaxPeers))?;
+
+                    self.limits.max_peers = Some(limits_max_peers);
+                    self.limits.max_peers_source = Some(super::Source::Argument("--limits-max-peers"));
@@ -0,0 +1,25 @@
+        if let Some(val) = ::std::env::var_os("TEST_RELOAD_PORT") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldPort)?;
+            self.port = Some(val);
+            self.port_source = Some(super::Source::EnvVar("TEST_RELOAD_PORT"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_RELOAD_LOG_LEVEL") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldLogLevel)?;
+            self.log_level = Some(val);
+            self.log_level_source = Some(super::Source::EnvVar("TEST_RELOAD_LOG_LEVEL"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_RELOAD_LIMITS_MAX_PEERS") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldLimitsMaxPeers)?;
+            self.limits.max_peers = Some(val);
+            self.limits.max_peers_source = Some(super::Source::EnvVar("TEST_RELOAD_LIMITS_MAX_PEERS"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_RELOAD_VERBOSE") {
+            if val == *"1" || val == *"true" {
+                self.verbose = Some(true);
+            } else if val == *"0" || val == *"false" {
+                self.verbose = Some(false);
+            } else {
+                return Err(super::EnvParseError::FieldVerbose(val).into());
+            }
+            self.verbose_source = Some(super::Source::EnvVar("TEST_RELOAD_VERBOSE"));
+        }
@@ -0,0 +1,19 @@
+            if other.port.is_some() {
+                self.port = other.port;
+            }
+            if other.port_source.is_some() {
+                self.port_source = other.port_source;
+            }
+            if other.log_level.is_some() {
+                self.log_level = other.log_level;
+            }
+            if other.log_level_source.is_some() {
+                self.log_level_source = other.log_level_source;
+            }
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
+            if other.verbose_source.is_some() {
+                self.verbose_source = other.verbose_source;
+            }
+            self.limits.merge_in(other.limits);
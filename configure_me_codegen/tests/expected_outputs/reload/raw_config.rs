@@ -0,0 +1,11 @@
+        port: Option<u16>,
+        log_level: Option<String>,
+        verbose: Option<bool>,
+        #[serde(skip)]
+        pub port_source: Option<super::Source>,
+        #[serde(skip)]
+        pub log_level_source: Option<super::Source>,
+        #[serde(skip)]
+        pub verbose_source: Option<super::Source>,
+        #[serde(default)]
+        pub limits: LimitsSection,
@@ -0,0 +1,28 @@
+
+    #[derive(Deserialize, Default)]
+    #[serde(crate = "crate::configure_me::serde")]
+    pub struct LimitsSection {
+        max_peers: Option<u32>,
+        #[serde(skip)]
+        pub max_peers_source: Option<super::Source>,
+    }
+
+    #[automatically_derived]
+    impl LimitsSection {
+        pub fn validate(self) -> Result<super::LimitsSection, ValidationError> {
+            let max_peers = self.max_peers.unwrap_or_else(|| { 8 });
+
+            Ok(super::LimitsSection {
+                max_peers,
+            })
+        }
+
+        pub fn merge_in(&mut self, other: Self) {
+            if other.max_peers.is_some() {
+                self.max_peers = other.max_peers;
+            }
+            if other.max_peers_source.is_some() {
+                self.max_peers_source = other.max_peers_source;
+            }
+        }
+    }
@@ -0,0 +1,29 @@
+
+    /// Loads the configuration again using the arguments of the program and returns the names of changed fields.
+    pub fn reload<I>(&self, config_files: I) -> Result<(Self, Vec<&'static str>, Metadata), Error> where I: IntoIterator, I::Item: AsRef<::std::path::Path> {
+        self.reload_custom_args(::std::env::args_os(), config_files)
+    }
+
+    /// Loads the configuration again and returns the names of changed fields.
+    ///
+    /// `args` should be the same as those used to load `self`, so that only config files and env vars can change.
+    pub fn reload_custom_args<A, I>(&self, args: A, config_files: I) -> Result<(Self, Vec<&'static str>, Metadata), Error> where
+        A: IntoIterator, A::Item: Into<::std::ffi::OsString>,
+        I: IntoIterator, I::Item: AsRef<::std::path::Path> {
+
+        let (config, _, metadata) = Self::custom_args_and_optional_files(args, config_files)?;
+        let mut changed = Vec::new();
+        if self.port != config.port {
+            return Err(Error::NotReloadable("port"));
+        }
+        if self.log_level != config.log_level {
+            changed.push("log_level");
+        }
+        if self.limits.max_peers != config.limits.max_peers {
+            changed.push("limits.max_peers");
+        }
+        if self.verbose != config.verbose {
+            changed.push("verbose");
+        }
+        Ok((config, changed, metadata))
+    }
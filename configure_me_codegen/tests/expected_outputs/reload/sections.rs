@@ -0,0 +1,5 @@
+/// Configuration of the `limits` section
+pub struct LimitsSection {
+    pub max_peers: u32,
+}
+
@@ -0,0 +1,10 @@
+            let port = self.port.unwrap_or_else(|| { 8080 });
+            let log_level = self.log_level.unwrap_or_else(|| { "info".to_owned() });
+            let limits = self.limits.validate()?;
+
+            Ok(super::Config {
+                port,
+                log_level,
+                verbose: self.verbose.unwrap_or(false),
+                limits,
+            })
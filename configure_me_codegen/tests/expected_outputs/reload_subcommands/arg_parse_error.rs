@@ -0,0 +1,4 @@
+    FieldLogLevel(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    UnknownSubcommand(String),
+    SubcommandServe(ServeArgParseError),
+    SubcommandCheck(CheckArgParseError),
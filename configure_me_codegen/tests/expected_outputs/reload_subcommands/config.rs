@@ -0,0 +1,2 @@
+    pub log_level: String,
+    pub subcommand: Subcommand,
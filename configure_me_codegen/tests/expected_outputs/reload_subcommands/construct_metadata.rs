@@ -0,0 +1,12 @@
+            sources: Sources {
+                log_level: config.log_level_source.clone().unwrap_or(Source::Default),
+                subcommand: match &config.subcommand {
+                    Some(raw::Subcommand::Serve(config)) => SubcommandSources::Serve(ServeSources {
+                        port: config.port_source.clone().unwrap_or(Source::Default),
+                        workers: config.workers_source.clone().unwrap_or(Source::Default),
+                    }),
+                    Some(raw::Subcommand::Check(config)) => SubcommandSources::Check(CheckSources {
+                    }),
+                    None => return Err(ValidationError::MissingSubcommand.into()),
+                },
+            },
@@ -0,0 +1,13 @@
+            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &["--help", "--log-level"]) {
+                Some(known) => write!(f, "An unknown argument '{}' was specified, did you mean '{}'?", arg, known),
+                None => write!(f, "An unknown argument '{}' was specified.", arg),
+            },
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--log-level LOG_LEVEL] SUBCOMMAND\n\nArguments:\n        --log-level    Logging level\n\nSubcommands:\n        serve\n        check    Checks the configuration", program_name),
+        ArgParseError::FieldLogLevel(err) => {
+            write!(f, "Failed to parse argument '--log-level': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::UnknownSubcommand(subcommand) => write!(f, "An unknown subcommand '{}' was specified.", subcommand),
+        ArgParseError::SubcommandServe(err) => ::std::fmt::Display::fmt(err, f),
+        ArgParseError::SubcommandCheck(err) => ::std::fmt::Display::fmt(err, f),
@@ -0,0 +1 @@
+            Error::NotReloadable(field) => write!(f, "Configuration parameter '{}' can't be changed without restarting.", field),
@@ -0,0 +1,5 @@
+        EnvParseError::FieldLogLevel(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_RELOAD_SUBCOMMANDS_LOG_LEVEL': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
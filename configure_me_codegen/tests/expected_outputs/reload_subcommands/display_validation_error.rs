@@ -0,0 +1 @@
+            ValidationError::MissingSubcommand => write!(f, "Missing subcommand."),
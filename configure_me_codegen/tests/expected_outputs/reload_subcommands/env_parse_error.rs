@@ -0,0 +1 @@
+    FieldLogLevel(<String as ::configure_me::parse_arg::ParseArg>::Error),
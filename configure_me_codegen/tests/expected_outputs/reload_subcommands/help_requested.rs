@@ -0,0 +1,2 @@
+            Err(err @ Error::Arguments(ArgParseError::SubcommandServe(ServeArgParseError::HelpRequested(_)))) |
+            Err(err @ Error::Arguments(ArgParseError::SubcommandCheck(CheckArgParseError::HelpRequested(_)))) |
@@ -0,0 +1,5 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--log-level", &arg, &mut iter) {
+                    let log_level = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--log-level"), ArgParseError::FieldLogLevel))?;
+
+                    self.log_level = Some(log_level);
+                    self.log_level_source = Some(super::Source::Argument("--log-level"));
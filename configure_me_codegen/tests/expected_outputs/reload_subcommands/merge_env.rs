@@ -0,0 +1,5 @@
+        if let Some(val) = ::std::env::var_os("TEST_RELOAD_SUBCOMMANDS_LOG_LEVEL") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldLogLevel)?;
+            self.log_level = Some(val);
+            self.log_level_source = Some(super::Source::EnvVar("TEST_RELOAD_SUBCOMMANDS_LOG_LEVEL"));
+        }
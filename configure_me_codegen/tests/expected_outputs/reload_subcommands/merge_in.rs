@@ -0,0 +1,9 @@
+            if other.log_level.is_some() {
+                self.log_level = other.log_level;
+            }
+            if other.log_level_source.is_some() {
+                self.log_level_source = other.log_level_source;
+            }
+            if other.subcommand.is_some() {
+                self.subcommand = other.subcommand;
+            }
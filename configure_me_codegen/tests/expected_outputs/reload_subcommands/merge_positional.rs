@@ -0,0 +1,12 @@
+                } else if arg == *"serve" {
+                    let mut subcommand = super::raw_serve::Config::default();
+                    let first_remaining = subcommand.merge_args(&program_path, &mut iter)?;
+                    self.subcommand = Some(Subcommand::Serve(subcommand));
+                    return Ok((program_path, first_remaining.into_iter().chain(iter)));
+                } else if arg == *"check" {
+                    let mut subcommand = super::raw_check::Config::default();
+                    let first_remaining = subcommand.merge_args(&program_path, &mut iter)?;
+                    self.subcommand = Some(Subcommand::Check(subcommand));
+                    return Ok((program_path, first_remaining.into_iter().chain(iter)));
+                } else {
+                    return Err(ArgParseError::UnknownSubcommand(arg.to_string_lossy().into_owned()).into());
@@ -0,0 +1,5 @@
+        log_level: Option<String>,
+        #[serde(skip)]
+        pub log_level_source: Option<super::Source>,
+        #[serde(skip)]
+        pub subcommand: Option<Subcommand>,
@@ -0,0 +1,15 @@
+
+    pub enum Subcommand {
+        Serve(super::raw_serve::Config),
+        Check(super::raw_check::Config),
+    }
+
+    #[automatically_derived]
+    impl Subcommand {
+        pub fn validate(self) -> Result<super::Subcommand, ValidationError> {
+            match self {
+                Subcommand::Serve(config) => config.validate().map(super::Subcommand::Serve),
+                Subcommand::Check(config) => config.validate().map(super::Subcommand::Check),
+            }
+        }
+    }
@@ -0,0 +1,32 @@
+
+    /// Loads the configuration again using the arguments of the program and returns the names of changed fields.
+    pub fn reload<I>(&self, config_files: I) -> Result<(Self, Vec<&'static str>, Metadata), Error> where I: IntoIterator, I::Item: AsRef<::std::path::Path> {
+        self.reload_custom_args(::std::env::args_os(), config_files)
+    }
+
+    /// Loads the configuration again and returns the names of changed fields.
+    ///
+    /// `args` should be the same as those used to load `self`, so that only config files and env vars can change.
+    pub fn reload_custom_args<A, I>(&self, args: A, config_files: I) -> Result<(Self, Vec<&'static str>, Metadata), Error> where
+        A: IntoIterator, A::Item: Into<::std::ffi::OsString>,
+        I: IntoIterator, I::Item: AsRef<::std::path::Path> {
+
+        let (config, _, metadata) = Self::custom_args_and_optional_files(args, config_files)?;
+        let mut changed = Vec::new();
+        if self.log_level != config.log_level {
+            changed.push("log_level");
+        }
+        match (&self.subcommand, &config.subcommand) {
+            (Subcommand::Serve(old), Subcommand::Serve(new)) => {
+                if old.port != new.port {
+                    return Err(Error::NotReloadable("serve.port"));
+                }
+                if old.workers != new.workers {
+                    changed.push("serve.workers");
+                }
+            },
+            (Subcommand::Check(_), Subcommand::Check(_)) => (),
+            _ => return Err(Error::NotReloadable("subcommand")),
+        }
+        Ok((config, changed, metadata))
+    }
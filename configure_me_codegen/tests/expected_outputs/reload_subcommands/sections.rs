@@ -0,0 +1,209 @@
+/// Subcommand selected on the command line
+pub enum Subcommand {
+    Serve(ServeConfig),
+    /// Checks the configuration
+    Check(CheckConfig),
+}
+
+pub enum ServeArgParseError {
+    MissingArgument(&'static str),
+    UnknownArgument(String),
+    HelpRequested(String),
+
+    FieldPort(<u16 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldWorkers(<u32 as ::configure_me::parse_arg::ParseArg>::Error),
+}
+
+#[automatically_derived]
+impl ::std::fmt::Display for ServeArgParseError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use self::ServeArgParseError as ArgParseError;
+
+        match self {
+            ArgParseError::MissingArgument(arg) => write!(f, "A value to argument '{}' is missing.", arg),
+            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &["--help", "--port", "--workers"]) {
+                Some(known) => write!(f, "An unknown argument '{}' was specified, did you mean '{}'?", arg, known),
+                None => write!(f, "An unknown argument '{}' was specified.", arg),
+            },
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--port PORT] [--workers WORKERS]\n\nArguments:\n        --port       Port to listen on\n        --workers    Number of workers", program_name),
+        ArgParseError::FieldPort(err) => {
+            write!(f, "Failed to parse argument '--port': {}.\n\nHint: the value must be ", err)?;
+            <u16 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldWorkers(err) => {
+            write!(f, "Failed to parse argument '--workers': {}.\n\nHint: the value must be ", err)?;
+            <u32 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        }
+    }
+}
+
+#[automatically_derived]
+impl ::std::fmt::Debug for ServeArgParseError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::std::fmt::Display::fmt(self, f)
+    }
+}
+
+#[automatically_derived]
+impl From<ServeArgParseError> for Error {
+    fn from(err: ServeArgParseError) -> Self {
+        Error::Arguments(ArgParseError::SubcommandServe(err))
+    }
+}
+
+mod raw_serve {
+    use super::{ServeArgParseError as ArgParseError, ValidationError};
+
+    #[derive(Default)]
+    pub struct Config {
+        port: Option<u16>,
+        workers: Option<u32>,
+        pub port_source: Option<super::Source>,
+        pub workers_source: Option<super::Source>,
+    }
+
+    #[automatically_derived]
+    impl Config {
+        pub fn validate(self) -> Result<super::ServeConfig, ValidationError> {
+            let port = self.port.unwrap_or_else(|| { 8080 });
+            let workers = self.workers.unwrap_or_else(|| { 4 });
+
+            Ok(super::ServeConfig {
+                port,
+                workers,
+            })
+        }
+
+        pub fn merge_args<I: Iterator<Item=::std::ffi::OsString>>(&mut self, program_path: &Option<std::path::PathBuf>, mut iter: I) -> Result<Option<::std::ffi::OsString>, super::Error> {
+            while let Some(arg) = iter.next() {
+                if arg == *"--" {
+                    return Ok(None);
+                } else if (arg == *"--help") || (arg == *"-h") {
+                    return Err(ArgParseError::HelpRequested(format!("{} serve", program_path.as_ref().unwrap().to_string_lossy())).into());
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--port", &arg, &mut iter) {
+                    let port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--port"), ArgParseError::FieldPort))?;
+
+                    self.port = Some(port);
+                    self.port_source = Some(super::Source::Argument("--port"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--workers", &arg, &mut iter) {
+                    let workers = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--workers"), ArgParseError::FieldWorkers))?;
+
+                    self.workers = Some(workers);
+                    self.workers_source = Some(super::Source::Argument("--workers"));
+                } else if let Some(mut shorts) = ::configure_me::parse_arg::iter_short(&arg) {
+                    for short in &mut shorts {
+                        if short == 'h' {
+                            return Err(ArgParseError::HelpRequested(format!("{} serve", program_path.as_ref().unwrap().to_string_lossy())).into())
+                        } else {
+                            let mut arg = String::with_capacity(2);
+                            arg.push('-');
+                            arg.push(short);
+                            return Err(ArgParseError::UnknownArgument(arg).into());
+                        }
+                    }
+                } else if arg.to_str().unwrap_or("").starts_with("--") {
+                    return Err(ArgParseError::UnknownArgument(arg.into_string().unwrap()).into());
+                } else {
+                    return Ok(Some(arg));
+                }
+            }
+
+            Ok(None)
+        }
+    }
+}
+
+/// Configuration of the `serve` subcommand
+pub struct ServeConfig {
+    pub port: u16,
+    pub workers: u32,
+}
+
+pub enum CheckArgParseError {
+    MissingArgument(&'static str),
+    UnknownArgument(String),
+    HelpRequested(String),
+
+}
+
+#[automatically_derived]
+impl ::std::fmt::Display for CheckArgParseError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use self::CheckArgParseError as ArgParseError;
+
+        match self {
+            ArgParseError::MissingArgument(arg) => write!(f, "A value to argument '{}' is missing.", arg),
+            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &["--help"]) {
+                Some(known) => write!(f, "An unknown argument '{}' was specified, did you mean '{}'?", arg, known),
+                None => write!(f, "An unknown argument '{}' was specified.", arg),
+            },
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {}", program_name),
+        }
+    }
+}
+
+#[automatically_derived]
+impl ::std::fmt::Debug for CheckArgParseError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::std::fmt::Display::fmt(self, f)
+    }
+}
+
+#[automatically_derived]
+impl From<CheckArgParseError> for Error {
+    fn from(err: CheckArgParseError) -> Self {
+        Error::Arguments(ArgParseError::SubcommandCheck(err))
+    }
+}
+
+mod raw_check {
+    use super::{CheckArgParseError as ArgParseError, ValidationError};
+
+    #[derive(Default)]
+    pub struct Config {
+    }
+
+    #[automatically_derived]
+    impl Config {
+        pub fn validate(self) -> Result<super::CheckConfig, ValidationError> {
+
+            Ok(super::CheckConfig {
+            })
+        }
+
+        pub fn merge_args<I: Iterator<Item=::std::ffi::OsString>>(&mut self, program_path: &Option<std::path::PathBuf>, mut iter: I) -> Result<Option<::std::ffi::OsString>, super::Error> {
+            while let Some(arg) = iter.next() {
+                if arg == *"--" {
+                    return Ok(None);
+                } else if (arg == *"--help") || (arg == *"-h") {
+                    return Err(ArgParseError::HelpRequested(format!("{} check", program_path.as_ref().unwrap().to_string_lossy())).into());
+                } else if let Some(mut shorts) = ::configure_me::parse_arg::iter_short(&arg) {
+                    for short in &mut shorts {
+                        if short == 'h' {
+                            return Err(ArgParseError::HelpRequested(format!("{} check", program_path.as_ref().unwrap().to_string_lossy())).into())
+                        } else {
+                            let mut arg = String::with_capacity(2);
+                            arg.push('-');
+                            arg.push(short);
+                            return Err(ArgParseError::UnknownArgument(arg).into());
+                        }
+                    }
+                } else if arg.to_str().unwrap_or("").starts_with("--") {
+                    return Err(ArgParseError::UnknownArgument(arg.into_string().unwrap()).into());
+                } else {
+                    return Ok(Some(arg));
+                }
+            }
+
+            Ok(None)
+        }
+    }
+}
+
+/// Configuration of the `check` subcommand
+pub struct CheckConfig {
+}
+
@@ -0,0 +1,7 @@
+            let log_level = self.log_level.unwrap_or_else(|| { "info".to_owned() });
+            let subcommand = self.subcommand.ok_or(ValidationError::MissingSubcommand)?.validate()?;
+
+            Ok(super::Config {
+                log_level,
+                subcommand,
+            })
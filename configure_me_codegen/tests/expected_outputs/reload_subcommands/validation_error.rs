@@ -0,0 +1 @@
+    MissingSubcommand,
@@ -1 +1,3 @@
             program_name,
+            sources: Sources {
+            },
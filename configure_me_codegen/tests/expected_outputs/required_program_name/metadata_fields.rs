@@ -1 +1,3 @@
     pub program_name: std::path::PathBuf,
+    /// Where the value of each parameter and switch came from
+    pub sources: Sources,
@@ -0,0 +1,4 @@
+    pub user: String,
+    pub password: Option<::configure_me::secret::Secret<String>>,
+    pub pin: ::configure_me::secret::Secret<u32>,
+    pub api_key: Vec<::configure_me::secret::Secret<String>>,
@@ -0,0 +1,6 @@
+            sources: Sources {
+                user: config.user_source.clone().unwrap_or(Source::Default),
+                password: config.password_source.clone(),
+                pin: config.pin_source.clone().unwrap_or(Source::Default),
+                api_key: config.api_key_source.clone().unwrap_or(Source::Default),
+            },
@@ -0,0 +1,26 @@
+            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &["--help", "--user", "--password", "--password-file", "--pin", "--pin-file", "--api-key", "--api-key-file"]) {
+                Some(known) => write!(f, "An unknown argument '{}' was specified, did you mean '{}'?", arg, known),
+                None => write!(f, "An unknown argument '{}' was specified.", arg),
+            },
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--user USER] [--password PASSWORD] [--pin PIN] [--api-key API_KEY]...\n\nArguments:\n        --password    Password used to authenticate (sensitive, can be read from\n                      a file using --password-file)\n        --pin         Sensitive, can be read from a file using --pin-file\n        --api-key     Sensitive, can be read from a file using --api-key-file", program_name),
+        ArgParseError::FieldUser(err) => {
+            write!(f, "Failed to parse argument '--user': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldPassword(_) => {
+            write!(f, "Failed to parse argument '--password'.\n\nHint: the value must be ")?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldPin(_) => {
+            write!(f, "Failed to parse argument '--pin'.\n\nHint: the value must be ")?;
+            <u32 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldApiKey(_) => {
+            write!(f, "Failed to parse argument '--api-key'.\n\nHint: the value must be ")?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::ReadSecretFile(err, file) => write!(f, "Failed to read secret file {}: {}", file.display(), err),
@@ -0,0 +1,21 @@
+        EnvParseError::FieldUser(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_SECRET_USER': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldPassword(_) => {
+            write!(f, "Failed to parse environment variable 'TEST_SECRET_PASSWORD'.\n\nHint: the value must be ")?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldPin(_) => {
+            write!(f, "Failed to parse environment variable 'TEST_SECRET_PIN'.\n\nHint: the value must be ")?;
+            <u32 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldApiKey(_) => {
+            write!(f, "Failed to parse environment variable 'TEST_SECRET_API_KEY'.\n\nHint: the value must be ")?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::ReadSecretFile(ref err, ref file) => write!(f, "Failed to read secret file {}: {}", file.display(), err),
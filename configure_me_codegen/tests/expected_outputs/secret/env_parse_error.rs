@@ -0,0 +1,5 @@
+    FieldUser(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldPassword(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldPin(<u32 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldApiKey(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    ReadSecretFile(std::io::Error, std::path::PathBuf),
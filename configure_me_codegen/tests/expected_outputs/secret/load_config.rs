@@ -0,0 +1,14 @@
+            let mut config: Self = ::configure_me::toml::from_slice(&config_content).map_err(|error| super::Error::ConfigParsing { file: config_file_name.as_ref().into(), error })?;
+            if config.user.is_some() {
+                config.user_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.password.is_some() {
+                config.password_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.pin.is_some() {
+                config.pin_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.api_key.is_some() {
+                config.api_key_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            Ok(config)
@@ -0,0 +1,41 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--user", &arg, &mut iter) {
+                    let user = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--user"), ArgParseError::FieldUser))?;
+
+                    self.user = Some(user);
+                    self.user_source = Some(super::Source::Argument("--user"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--password", &arg, &mut iter) {
+                    let password = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--password"), ArgParseError::FieldPassword))?;
+
+                    self.password = Some(password);
+                    self.password_source = Some(super::Source::Argument("--password"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--password-file", &arg, &mut iter) {
+                    let file_path: std::path::PathBuf = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--password-file"), |never| match never {}))?;
+                    let password = ::configure_me::secret::read_file(&file_path).map_err(|err| ArgParseError::ReadSecretFile(err, file_path))?;
+                    let password = <String as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(password).map_err(ArgParseError::FieldPassword)?;
+
+                    self.password = Some(password);
+                    self.password_source = Some(super::Source::Argument("--password-file"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--pin", &arg, &mut iter) {
+                    let pin = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--pin"), ArgParseError::FieldPin))?;
+
+                    self.pin = Some(pin);
+                    self.pin_source = Some(super::Source::Argument("--pin"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--pin-file", &arg, &mut iter) {
+                    let file_path: std::path::PathBuf = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--pin-file"), |never| match never {}))?;
+                    let pin = ::configure_me::secret::read_file(&file_path).map_err(|err| ArgParseError::ReadSecretFile(err, file_path))?;
+                    let pin = <u32 as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(pin).map_err(ArgParseError::FieldPin)?;
+
+                    self.pin = Some(pin);
+                    self.pin_source = Some(super::Source::Argument("--pin-file"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--api-key", &arg, &mut iter) {
+                    let api_key = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--api-key"), ArgParseError::FieldApiKey))?;
+
+                    self.api_key.get_or_insert_with(Vec::new).push(api_key);
+                    self.api_key_source = Some(super::Source::Argument("--api-key"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--api-key-file", &arg, &mut iter) {
+                    let file_path: std::path::PathBuf = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--api-key-file"), |never| match never {}))?;
+                    let api_key = ::configure_me::secret::read_file(&file_path).map_err(|err| ArgParseError::ReadSecretFile(err, file_path))?;
+                    let api_key = <String as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(api_key).map_err(ArgParseError::FieldApiKey)?;
+
+                    self.api_key.get_or_insert_with(Vec::new).push(api_key);
+                    self.api_key_source = Some(super::Source::Argument("--api-key-file"));
@@ -0,0 +1,49 @@
+        if let Some(val) = ::std::env::var_os("TEST_SECRET_USER") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldUser)?;
+            self.user = Some(val);
+            self.user_source = Some(super::Source::EnvVar("TEST_SECRET_USER"));
+        }
+        if let Some(file_path) = ::std::env::var_os("TEST_SECRET_PASSWORD_FILE") {
+            let file_path = ::std::path::PathBuf::from(file_path);
+            let val = ::configure_me::secret::read_file(&file_path).map_err(|err| super::EnvParseError::ReadSecretFile(err, file_path))?;
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldPassword)?;
+            self.password = Some(val);
+            self.password_source = Some(super::Source::EnvVar("TEST_SECRET_PASSWORD_FILE"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_SECRET_PASSWORD") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldPassword)?;
+            self.password = Some(val);
+            self.password_source = Some(super::Source::EnvVar("TEST_SECRET_PASSWORD"));
+        }
+        if let Some(file_path) = ::std::env::var_os("TEST_SECRET_PIN_FILE") {
+            let file_path = ::std::path::PathBuf::from(file_path);
+            let val = ::configure_me::secret::read_file(&file_path).map_err(|err| super::EnvParseError::ReadSecretFile(err, file_path))?;
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldPin)?;
+            self.pin = Some(val);
+            self.pin_source = Some(super::Source::EnvVar("TEST_SECRET_PIN_FILE"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_SECRET_PIN") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldPin)?;
+            self.pin = Some(val);
+            self.pin_source = Some(super::Source::EnvVar("TEST_SECRET_PIN"));
+        }
+        if let Some(file_path) = ::std::env::var_os("TEST_SECRET_API_KEY_FILE") {
+            let file_path = ::std::path::PathBuf::from(file_path);
+            let val = ::configure_me::secret::read_file(&file_path).map_err(|err| super::EnvParseError::ReadSecretFile(err, file_path))?;
+            let val = match val.into_string() {
+                Ok(val) => val.split(",").map(|item| <String as ::configure_me::parse_arg::ParseArg>::parse_arg(item.as_ref())).collect::<Result<Vec<_>, _>>(),
+                // Values that aren't valid UTF-8 can't be split
+                Err(val) => <String as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(val).map(|item| vec![item]),
+            }.map_err(super::EnvParseError::FieldApiKey)?;
+            self.api_key = Some(val);
+            self.api_key_source = Some(super::Source::EnvVar("TEST_SECRET_API_KEY_FILE"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_SECRET_API_KEY") {
+            let val = match val.into_string() {
+                Ok(val) => val.split(",").map(|item| <String as ::configure_me::parse_arg::ParseArg>::parse_arg(item.as_ref())).collect::<Result<Vec<_>, _>>(),
+                // Values that aren't valid UTF-8 can't be split
+                Err(val) => <String as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(val).map(|item| vec![item]),
+            }.map_err(super::EnvParseError::FieldApiKey)?;
+            self.api_key = Some(val);
+            self.api_key_source = Some(super::Source::EnvVar("TEST_SECRET_API_KEY"));
+        }
@@ -0,0 +1,24 @@
+            if other.user.is_some() {
+                self.user = other.user;
+            }
+            if other.user_source.is_some() {
+                self.user_source = other.user_source;
+            }
+            if other.password.is_some() {
+                self.password = other.password;
+            }
+            if other.password_source.is_some() {
+                self.password_source = other.password_source;
+            }
+            if other.pin.is_some() {
+                self.pin = other.pin;
+            }
+            if other.pin_source.is_some() {
+                self.pin_source = other.pin_source;
+            }
+            if other.api_key.is_some() {
+                self.api_key = other.api_key;
+            }
+            if other.api_key_source.is_some() {
+                self.api_key_source = other.api_key_source;
+            }
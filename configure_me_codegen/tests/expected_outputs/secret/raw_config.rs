@@ -0,0 +1,12 @@
+        user: Option<String>,
+        password: Option<String>,
+        pin: Option<u32>,
+        api_key: Option<Vec<String>>,
+        #[serde(skip)]
+        pub user_source: Option<super::Source>,
+        #[serde(skip)]
+        pub password_source: Option<super::Source>,
+        #[serde(skip)]
+        pub pin_source: Option<super::Source>,
+        #[serde(skip)]
+        pub api_key_source: Option<super::Source>,
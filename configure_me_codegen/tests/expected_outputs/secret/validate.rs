@@ -0,0 +1,11 @@
+            let user = self.user.unwrap_or_else(|| { "admin".to_owned() });
+            let password = self.password;
+            let pin = self.pin.unwrap_or_else(|| { 1234 });
+            let api_key = self.api_key.unwrap_or_default();
+
+            Ok(super::Config {
+                user,
+                password: password.map(::configure_me::secret::Secret::new),
+                pin: ::configure_me::secret::Secret::new(pin),
+                api_key: api_key.into_iter().map(::configure_me::secret::Secret::new).collect(),
+            })
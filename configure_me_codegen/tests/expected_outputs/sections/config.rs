@@ -0,0 +1,3 @@
+    pub name: Option<String>,
+    pub network: NetworkSection,
+    pub storage: StorageSection,
@@ -0,0 +1,7 @@
+            sources: Sources {
+                name: config.name_source.clone(),
+                network_bind_addr: config.network.bind_addr_source.clone().unwrap_or(Source::Default),
+                network_port: config.network.port_source.clone().unwrap_or(Source::Default),
+                storage_mode: config.storage.mode_source.clone().unwrap_or(Source::Default),
+                network_ipv6: config.network.ipv6_source.clone().unwrap_or(Source::Default),
+            },
@@ -0,0 +1,25 @@
+            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &["--help", "--name", "--network-bind-addr", "--network-port", "--storage-mode", "--network-ipv6"]) {
+                Some(known) => write!(f, "An unknown argument '{}' was specified, did you mean '{}'?", arg, known),
+                None => write!(f, "An unknown argument '{}' was specified.", arg),
+            },
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [ARGUMENTS...]\n\nArguments:\n        --name                     Name of the node\n\nArguments in section network:\n        -b, --network-bind-addr    Address to bind to\n        --network-port\n\n        --network-ipv6             Use IPv6\n\nArguments in section storage:\n        --storage-mode             Possible values: memory, disk", program_name),
+        ArgParseError::FieldName(err) => {
+            write!(f, "Failed to parse argument '--name': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldNetworkBindAddr(err) => {
+            write!(f, "Failed to parse argument '--network-bind-addr': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldNetworkPort(err) => {
+            write!(f, "Failed to parse argument '--network-port': {}.\n\nHint: the value must be ", err)?;
+            <u16 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldStorageMode(err) => {
+            write!(f, "Failed to parse argument '--storage-mode': {}.\n\nHint: the value must be ", err)?;
+            <StorageMode as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
@@ -0,0 +1,23 @@
+        EnvParseError::FieldName(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_SECTIONS_NAME': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldNetworkBindAddr(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_SECTIONS_NETWORK_BIND_ADDR': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldNetworkPort(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_SECTIONS_NETWORK_PORT': {}.\n\nHint: the value must be ", err)?;
+            <u16 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldStorageMode(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_SECTIONS_STORAGE_MODE': {}.\n\nHint: the value must be ", err)?;
+            <StorageMode as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldNetworkIpv6(ref err) => {
+            write!(f, "Invalid value '{:?}' for 'TEST_SECTIONS_NETWORK_IPV6'.\n\nHint: the allowed values are 0, false, 1, true.", err)
+        },
@@ -0,0 +1,5 @@
+    FieldName(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldNetworkBindAddr(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldNetworkPort(<u16 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldNetworkIpv6(::std::ffi::OsString),
+    FieldStorageMode(<StorageMode as ::configure_me::parse_arg::ParseArg>::Error),
@@ -0,0 +1,17 @@
+            let mut config: Self = ::configure_me::toml::from_slice(&config_content).map_err(|error| super::Error::ConfigParsing { file: config_file_name.as_ref().into(), error })?;
+            if config.name.is_some() {
+                config.name_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.network.bind_addr.is_some() {
+                config.network.bind_addr_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.network.port.is_some() {
+                config.network.port_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.storage.mode.is_some() {
+                config.storage.mode_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.network.ipv6.is_some() {
+                config.network.ipv6_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            Ok(config)
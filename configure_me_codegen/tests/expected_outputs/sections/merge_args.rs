@@ -0,0 +1,23 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--name", &arg, &mut iter) {
+                    let name = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--name"), ArgParseError::FieldName))?;
+
+                    self.name = Some(name);
+                    self.name_source = Some(super::Source::Argument("--name"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--network-bind-addr", &arg, &mut iter) {
+                    let network_bind_addr = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--network-bind-addr"), ArgParseError::FieldNetworkBindAddr))?;
+
+                    self.network.bind_addr = Some(network_bind_addr);
+                    self.network.bind_addr_source = Some(super::Source::Argument("--network-bind-addr"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--network-port", &arg, &mut iter) {
+                    let network_port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--network-port"), ArgParseError::FieldNetworkPort))?;
+
+                    self.network.port = Some(network_port);
+                    self.network.port_source = Some(super::Source::Argument("--network-port"));
+                } else if arg == *"--network-ipv6" {
+                    self.network.ipv6 = Some(true);
+                    self.network.ipv6_source = Some(super::Source::Argument("--network-ipv6"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--storage-mode", &arg, &mut iter) {
+                    let storage_mode = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--storage-mode"), ArgParseError::FieldStorageMode))?;
+
+                    self.storage.mode = Some(storage_mode);
+                    self.storage.mode_source = Some(super::Source::Argument("--storage-mode"));
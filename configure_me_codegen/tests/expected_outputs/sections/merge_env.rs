@@ -0,0 +1,30 @@
+        if let Some(val) = ::std::env::var_os("TEST_SECTIONS_NAME") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldName)?;
+            self.name = Some(val);
+            self.name_source = Some(super::Source::EnvVar("TEST_SECTIONS_NAME"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_SECTIONS_NETWORK_BIND_ADDR") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldNetworkBindAddr)?;
+            self.network.bind_addr = Some(val);
+            self.network.bind_addr_source = Some(super::Source::EnvVar("TEST_SECTIONS_NETWORK_BIND_ADDR"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_SECTIONS_NETWORK_PORT") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldNetworkPort)?;
+            self.network.port = Some(val);
+            self.network.port_source = Some(super::Source::EnvVar("TEST_SECTIONS_NETWORK_PORT"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_SECTIONS_STORAGE_MODE") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldStorageMode)?;
+            self.storage.mode = Some(val);
+            self.storage.mode_source = Some(super::Source::EnvVar("TEST_SECTIONS_STORAGE_MODE"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_SECTIONS_NETWORK_IPV6") {
+            if val == *"1" || val == *"true" {
+                self.network.ipv6 = Some(true);
+            } else if val == *"0" || val == *"false" {
+                self.network.ipv6 = Some(false);
+            } else {
+                return Err(super::EnvParseError::FieldNetworkIpv6(val).into());
+            }
+            self.network.ipv6_source = Some(super::Source::EnvVar("TEST_SECTIONS_NETWORK_IPV6"));
+        }
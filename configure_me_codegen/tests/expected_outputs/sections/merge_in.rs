@@ -0,0 +1,8 @@
+            if other.name.is_some() {
+                self.name = other.name;
+            }
+            if other.name_source.is_some() {
+                self.name_source = other.name_source;
+            }
+            self.network.merge_in(other.network);
+            self.storage.merge_in(other.storage);
@@ -0,0 +1,4 @@
+                        } else if short == 'b' {
+                            self.network.bind_addr = Some(shorts.parse_remaining(&mut iter).map_err(|err| err.map_or(ArgParseError::MissingArgument("-b"), ArgParseError::FieldNetworkBindAddr))?);
+                            self.network.bind_addr_source = Some(super::Source::Argument("-b"));
+                            break;
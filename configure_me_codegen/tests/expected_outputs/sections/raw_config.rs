@@ -0,0 +1,7 @@
+        name: Option<String>,
+        #[serde(skip)]
+        pub name_source: Option<super::Source>,
+        #[serde(default)]
+        pub network: NetworkSection,
+        #[serde(default)]
+        pub storage: StorageSection,
@@ -0,0 +1 @@
+    use super::StorageMode;
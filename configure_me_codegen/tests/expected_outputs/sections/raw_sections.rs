@@ -0,0 +1,77 @@
+
+    #[derive(Deserialize, Default)]
+    #[serde(crate = "crate::configure_me::serde")]
+    pub struct NetworkSection {
+        bind_addr: Option<String>,
+        port: Option<u16>,
+        ipv6: Option<bool>,
+        #[serde(skip)]
+        pub bind_addr_source: Option<super::Source>,
+        #[serde(skip)]
+        pub port_source: Option<super::Source>,
+        #[serde(skip)]
+        pub ipv6_source: Option<super::Source>,
+    }
+
+    #[automatically_derived]
+    impl NetworkSection {
+        pub fn validate(self) -> Result<super::NetworkSection, ValidationError> {
+            let bind_addr = self.bind_addr.ok_or(ValidationError::MissingField("network.bind_addr"))?;
+            let port = self.port.unwrap_or_else(|| { 8080 });
+
+            Ok(super::NetworkSection {
+                bind_addr,
+                port,
+                ipv6: self.ipv6.unwrap_or(false),
+            })
+        }
+
+        pub fn merge_in(&mut self, other: Self) {
+            if other.bind_addr.is_some() {
+                self.bind_addr = other.bind_addr;
+            }
+            if other.bind_addr_source.is_some() {
+                self.bind_addr_source = other.bind_addr_source;
+            }
+            if other.port.is_some() {
+                self.port = other.port;
+            }
+            if other.port_source.is_some() {
+                self.port_source = other.port_source;
+            }
+            if other.ipv6.is_some() {
+                self.ipv6 = other.ipv6;
+            }
+            if other.ipv6_source.is_some() {
+                self.ipv6_source = other.ipv6_source;
+            }
+        }
+    }
+
+    #[derive(Deserialize, Default)]
+    #[serde(crate = "crate::configure_me::serde")]
+    pub struct StorageSection {
+        mode: Option<StorageMode>,
+        #[serde(skip)]
+        pub mode_source: Option<super::Source>,
+    }
+
+    #[automatically_derived]
+    impl StorageSection {
+        pub fn validate(self) -> Result<super::StorageSection, ValidationError> {
+            let mode = self.mode.unwrap_or_else(|| { StorageMode::Disk });
+
+            Ok(super::StorageSection {
+                mode,
+            })
+        }
+
+        pub fn merge_in(&mut self, other: Self) {
+            if other.mode.is_some() {
+                self.mode = other.mode;
+            }
+            if other.mode_source.is_some() {
+                self.mode_source = other.mode_source;
+            }
+        }
+    }
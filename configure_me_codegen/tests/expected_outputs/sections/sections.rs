@@ -0,0 +1,12 @@
+/// Network settings
+pub struct NetworkSection {
+    pub bind_addr: String,
+    pub port: u16,
+    pub ipv6: bool,
+}
+
+/// Configuration of the `storage` section
+pub struct StorageSection {
+    pub mode: StorageMode,
+}
+
@@ -0,0 +1,9 @@
+            let name = self.name;
+            let network = self.network.validate()?;
+            let storage = self.storage.validate()?;
+
+            Ok(super::Config {
+                name,
+                network,
+                storage,
+            })
@@ -0,0 +1 @@
+    MissingField(&'static str),
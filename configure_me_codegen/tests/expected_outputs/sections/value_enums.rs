@@ -0,0 +1,56 @@
+/// Error returned when parsing a value that is not allowed
+#[derive(Debug, Clone)]
+pub struct InvalidValue(String);
+
+#[automatically_derived]
+impl ::std::fmt::Display for InvalidValue {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "invalid value '{}'", self.0)
+    }
+}
+
+/// Possible values of the `storage.mode` parameter
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum StorageMode {
+    Memory,
+    Disk,
+}
+
+#[automatically_derived]
+impl ::std::str::FromStr for StorageMode {
+    type Err = InvalidValue;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "memory" => Ok(StorageMode::Memory),
+            "disk" => Ok(StorageMode::Disk),
+            _ => Err(InvalidValue(s.to_owned())),
+        }
+    }
+}
+
+#[automatically_derived]
+impl ::configure_me::parse_arg::ParseArgFromStr for StorageMode {
+    fn describe_type<W: ::std::fmt::Write>(mut writer: W) -> ::std::fmt::Result {
+        write!(writer, "one of `memory`, `disk`")
+    }
+}
+
+#[automatically_derived]
+impl ::std::fmt::Display for StorageMode {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match self {
+            StorageMode::Memory => f.write_str("memory"),
+            StorageMode::Disk => f.write_str("disk"),
+        }
+    }
+}
+
+#[automatically_derived]
+impl<'de> ::configure_me::serde::Deserialize<'de> for StorageMode {
+    fn deserialize<D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = <String as ::configure_me::serde::Deserialize>::deserialize(deserializer)?;
+        value.parse().map_err(|_| <D::Error as ::configure_me::serde::de::Error>::unknown_variant(&value, &["memory", "disk"]))
+    }
+}
+
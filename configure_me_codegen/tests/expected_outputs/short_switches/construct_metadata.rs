@@ -0,0 +1,8 @@
+            sources: Sources {
+                d: config.d_source.clone(),
+                e: config.e_source.clone(),
+                a: config.a_source.clone().unwrap_or(Source::Default),
+                b: config.b_source.clone().unwrap_or(Source::Default),
+                c: config.c_source.clone().unwrap_or(Source::Default),
+                foo_bar: config.foo_bar_source.clone().unwrap_or(Source::Default),
+            },
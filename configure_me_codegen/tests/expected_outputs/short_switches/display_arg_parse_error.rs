@@ -1,3 +1,7 @@
+            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &["--help", "--d", "--e", "--a", "--b", "--c", "--foo-bar"]) {
+                Some(known) => write!(f, "An unknown argument '{}' was specified, did you mean '{}'?", arg, known),
+                None => write!(f, "An unknown argument '{}' was specified.", arg),
+            },
         ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [-d D|--d D] [-e E|--e E] [-a|--a] [-b|--b] [-c|--c ...] [-f|--foo-bar]\n\nArguments:\n        -a, --a    test", program_name),
         ArgParseError::FieldD(err) => {
             write!(f, "Failed to parse argument '--d': {}.\n\nHint: the value must be ", err)?;
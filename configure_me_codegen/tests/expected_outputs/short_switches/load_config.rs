@@ -0,0 +1,20 @@
+            let mut config: Self = ::configure_me::toml::from_slice(&config_content).map_err(|error| super::Error::ConfigParsing { file: config_file_name.as_ref().into(), error })?;
+            if config.d.is_some() {
+                config.d_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.e.is_some() {
+                config.e_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.a.is_some() {
+                config.a_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.b.is_some() {
+                config.b_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.c.is_some() {
+                config.c_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.foo_bar.is_some() {
+                config.foo_bar_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            Ok(config)
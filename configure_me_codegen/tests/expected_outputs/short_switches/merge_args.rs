@@ -2,15 +2,21 @@
                     let d = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--d"), ArgParseError::FieldD))?;
 
                     self.d = Some(d);
+                    self.d_source = Some(super::Source::Argument("--d"));
                 } else if let Some(value) = ::configure_me::parse_arg::match_arg("--e", &arg, &mut iter) {
                     let e = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--e"), ArgParseError::FieldE))?;
 
                     self.e = Some(e);
+                    self.e_source = Some(super::Source::Argument("--e"));
                 } else if arg == *"--a" {
                     self.a = Some(true);
+                    self.a_source = Some(super::Source::Argument("--a"));
                 } else if arg == *"--b" {
                     self.b = Some(true);
+                    self.b_source = Some(super::Source::Argument("--b"));
                 } else if arg == *"--c" {
                     *(self.c.get_or_insert(0)) += 1;
+                    self.c_source = Some(super::Source::Argument("--c"));
                 } else if arg == *"--foo-bar" {
                     self.foo_bar = Some(true);
+                    self.foo_bar_source = Some(super::Source::Argument("--foo-bar"));
@@ -1,18 +1,36 @@
             if other.d.is_some() {
                 self.d = other.d;
             }
+            if other.d_source.is_some() {
+                self.d_source = other.d_source;
+            }
             if other.e.is_some() {
                 self.e = other.e;
             }
+            if other.e_source.is_some() {
+                self.e_source = other.e_source;
+            }
             if other.a.is_some() {
                 self.a = other.a;
             }
+            if other.a_source.is_some() {
+                self.a_source = other.a_source;
+            }
             if other.b.is_some() {
                 self.b = other.b;
             }
+            if other.b_source.is_some() {
+                self.b_source = other.b_source;
+            }
             if other.c.is_some() {
                 self.c = other.c;
             }
+            if other.c_source.is_some() {
+                self.c_source = other.c_source;
+            }
             if other.foo_bar.is_some() {
                 self.foo_bar = other.foo_bar;
             }
+            if other.foo_bar_source.is_some() {
+                self.foo_bar_source = other.foo_bar_source;
+            }
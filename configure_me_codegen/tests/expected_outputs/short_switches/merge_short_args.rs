@@ -1,14 +1,20 @@
                         } else if short == 'd' {
                             self.d = Some(shorts.parse_remaining(&mut iter).map_err(|err| err.map_or(ArgParseError::MissingArgument("-d"), ArgParseError::FieldD))?);
+                            self.d_source = Some(super::Source::Argument("-d"));
                             break;
                         } else if short == 'e' {
                             self.e = Some(shorts.parse_remaining(&mut iter).map_err(|err| err.map_or(ArgParseError::MissingArgument("-e"), ArgParseError::FieldE))?);
+                            self.e_source = Some(super::Source::Argument("-e"));
                             break;
                         } else if short == 'a' {
                             self.a = Some(true);
+                            self.a_source = Some(super::Source::Argument("-a"));
                         } else if short == 'b' {
                             self.b = Some(true);
+                            self.b_source = Some(super::Source::Argument("-b"));
                         } else if short == 'c' {
                             *(self.c.get_or_insert(0)) += 1;
+                            self.c_source = Some(super::Source::Argument("-c"));
                         } else if short == 'f' {
                             self.foo_bar = Some(true);
+                            self.foo_bar_source = Some(super::Source::Argument("-f"));
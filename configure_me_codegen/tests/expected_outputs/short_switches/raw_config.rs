@@ -4,3 +4,15 @@
         b: Option<bool>,
         c: Option<u32>,
         foo_bar: Option<bool>,
+        #[serde(skip)]
+        pub d_source: Option<super::Source>,
+        #[serde(skip)]
+        pub e_source: Option<super::Source>,
+        #[serde(skip)]
+        pub a_source: Option<super::Source>,
+        #[serde(skip)]
+        pub b_source: Option<super::Source>,
+        #[serde(skip)]
+        pub c_source: Option<super::Source>,
+        #[serde(skip)]
+        pub foo_bar_source: Option<super::Source>,
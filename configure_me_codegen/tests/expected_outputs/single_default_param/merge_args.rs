@@ -2,3 +2,4 @@
                     let foo = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--foo"), ArgParseError::FieldFoo))?;
 
                     self.foo = Some(foo);
+                    self.foo_source = Some(super::Source::Argument("--foo"));
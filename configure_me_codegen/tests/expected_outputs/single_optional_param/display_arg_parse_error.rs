@@ -1,3 +1,7 @@
+            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &["--help", "--foo"]) {
+                Some(known) => write!(f, "An unknown argument '{}' was specified, did you mean '{}'?", arg, known),
+                None => write!(f, "An unknown argument '{}' was specified.", arg),
+            },
         ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--foo FOO]", program_name),
         ArgParseError::FieldFoo(err) => {
             write!(f, "Failed to parse argument '--foo': {}.\n\nHint: the value must be ", err)?;
@@ -1,3 +1,6 @@
             if other.foo.is_some() {
                 self.foo = other.foo;
             }
+            if other.foo_source.is_some() {
+                self.foo_source = other.foo_source;
+            }
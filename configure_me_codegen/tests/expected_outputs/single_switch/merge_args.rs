@@ -1,2 +1,3 @@
                 } else if arg == *"--foo" {
                     self.foo = Some(true);
+                    self.foo_source = Some(super::Source::Argument("--foo"));
@@ -6,4 +6,5 @@
             } else {
                 return Err(super::EnvParseError::FieldFoo(val).into());
             }
+            self.foo_source = Some(super::Source::EnvVar("TEST_APP_FOO"));
         }
@@ -1 +1,3 @@
         foo: Option<bool>,
+        #[serde(skip)]
+        pub foo_source: Option<super::Source>,
@@ -0,0 +1,4 @@
+    FieldFoo(<u32 as ::configure_me::parse_arg::ParseArg>::Error),
+    UnknownSubcommand(String),
+    SubcommandRun(RunArgParseError),
+    SubcommandRunAll(RunAllArgParseError),
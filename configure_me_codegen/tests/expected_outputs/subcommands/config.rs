@@ -0,0 +1,3 @@
+    pub foo: Option<u32>,
+    pub verbose: bool,
+    pub subcommand: Subcommand,
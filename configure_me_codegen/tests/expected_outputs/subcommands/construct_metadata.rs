@@ -0,0 +1,14 @@
+            sources: Sources {
+                foo: config.foo_source.clone(),
+                verbose: config.verbose_source.clone().unwrap_or(Source::Default),
+                subcommand: match &config.subcommand {
+                    Some(raw::Subcommand::Run(config)) => SubcommandSources::Run(RunSources {
+                        bar: config.bar_source.clone().unwrap_or(Source::Default),
+                        fast: config.fast_source.clone().unwrap_or(Source::Default),
+                    }),
+                    Some(raw::Subcommand::RunAll(config)) => SubcommandSources::RunAll(RunAllSources {
+                        foo: config.foo_source.clone().unwrap_or(Source::Default),
+                    }),
+                    None => return Err(ValidationError::MissingSubcommand.into()),
+                },
+            },
@@ -0,0 +1,13 @@
+            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &["--help", "--foo", "--verbose"]) {
+                Some(known) => write!(f, "An unknown argument '{}' was specified, did you mean '{}'?", arg, known),
+                None => write!(f, "An unknown argument '{}' was specified.", arg),
+            },
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--foo FOO] [-v|--verbose] SUBCOMMAND\n\nArguments:\n        --foo    A foo\n\nSubcommands:\n        run        Runs the program\n        run-all", program_name),
+        ArgParseError::FieldFoo(err) => {
+            write!(f, "Failed to parse argument '--foo': {}.\n\nHint: the value must be ", err)?;
+            <u32 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::UnknownSubcommand(subcommand) => write!(f, "An unknown subcommand '{}' was specified.", subcommand),
+        ArgParseError::SubcommandRun(err) => ::std::fmt::Display::fmt(err, f),
+        ArgParseError::SubcommandRunAll(err) => ::std::fmt::Display::fmt(err, f),
@@ -0,0 +1,2 @@
+    FieldFoo(<u32 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldVerbose(::std::ffi::OsString),
@@ -0,0 +1,2 @@
+            Err(err @ Error::Arguments(ArgParseError::SubcommandRun(RunArgParseError::HelpRequested(_)))) |
+            Err(err @ Error::Arguments(ArgParseError::SubcommandRunAll(RunAllArgParseError::HelpRequested(_)))) |
@@ -0,0 +1,15 @@
+        if let Some(val) = ::std::env::var_os("TEST_APP_FOO") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldFoo)?;
+            self.foo = Some(val);
+            self.foo_source = Some(super::Source::EnvVar("TEST_APP_FOO"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_APP_VERBOSE") {
+            if val == *"1" || val == *"true" {
+                self.verbose = Some(true);
+            } else if val == *"0" || val == *"false" {
+                self.verbose = Some(false);
+            } else {
+                return Err(super::EnvParseError::FieldVerbose(val).into());
+            }
+            self.verbose_source = Some(super::Source::EnvVar("TEST_APP_VERBOSE"));
+        }
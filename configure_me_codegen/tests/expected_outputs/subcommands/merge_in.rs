@@ -0,0 +1,15 @@
+            if other.foo.is_some() {
+                self.foo = other.foo;
+            }
+            if other.foo_source.is_some() {
+                self.foo_source = other.foo_source;
+            }
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
+            if other.verbose_source.is_some() {
+                self.verbose_source = other.verbose_source;
+            }
+            if other.subcommand.is_some() {
+                self.subcommand = other.subcommand;
+            }
@@ -0,0 +1,3 @@
+                        } else if short == 'v' {
+                            self.verbose = Some(true);
+                            self.verbose_source = Some(super::Source::Argument("-v"));
@@ -0,0 +1,8 @@
+        foo: Option<u32>,
+        verbose: Option<bool>,
+        #[serde(skip)]
+        pub foo_source: Option<super::Source>,
+        #[serde(skip)]
+        pub verbose_source: Option<super::Source>,
+        #[serde(skip)]
+        pub subcommand: Option<Subcommand>,
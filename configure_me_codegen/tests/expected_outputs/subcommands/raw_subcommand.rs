@@ -0,0 +1,15 @@
+
+    pub enum Subcommand {
+        Run(super::raw_run::Config),
+        RunAll(super::raw_run_all::Config),
+    }
+
+    #[automatically_derived]
+    impl Subcommand {
+        pub fn validate(self) -> Result<super::Subcommand, ValidationError> {
+            match self {
+                Subcommand::Run(config) => config.validate().map(super::Subcommand::Run),
+                Subcommand::RunAll(config) => config.validate().map(super::Subcommand::RunAll),
+            }
+        }
+    }
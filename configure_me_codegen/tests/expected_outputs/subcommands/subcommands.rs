@@ -0,0 +1,223 @@
+/// Subcommand selected on the command line
+pub enum Subcommand {
+    /// Runs the program
+    Run(RunConfig),
+    RunAll(RunAllConfig),
+}
+
+pub enum RunArgParseError {
+    MissingArgument(&'static str),
+    UnknownArgument(String),
+    HelpRequested(String),
+
+    FieldBar(<String as ::configure_me::parse_arg::ParseArg>::Error),
+}
+
+#[automatically_derived]
+impl ::std::fmt::Display for RunArgParseError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use self::RunArgParseError as ArgParseError;
+
+        match self {
+            ArgParseError::MissingArgument(arg) => write!(f, "A value to argument '{}' is missing.", arg),
+            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &["--help", "--bar", "--fast"]) {
+                Some(known) => write!(f, "An unknown argument '{}' was specified, did you mean '{}'?", arg, known),
+                None => write!(f, "An unknown argument '{}' was specified.", arg),
+            },
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [-b BAR|--bar BAR] [-f|--fast]\n\nArguments:\n        -b, --bar    A bar", program_name),
+        ArgParseError::FieldBar(err) => {
+            write!(f, "Failed to parse argument '--bar': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        }
+    }
+}
+
+#[automatically_derived]
+impl ::std::fmt::Debug for RunArgParseError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::std::fmt::Display::fmt(self, f)
+    }
+}
+
+#[automatically_derived]
+impl From<RunArgParseError> for Error {
+    fn from(err: RunArgParseError) -> Self {
+        Error::Arguments(ArgParseError::SubcommandRun(err))
+    }
+}
+
+mod raw_run {
+    use super::{RunArgParseError as ArgParseError, ValidationError};
+
+    #[derive(Default)]
+    pub struct Config {
+        bar: Option<String>,
+        fast: Option<bool>,
+        pub bar_source: Option<super::Source>,
+        pub fast_source: Option<super::Source>,
+    }
+
+    #[automatically_derived]
+    impl Config {
+        pub fn validate(self) -> Result<super::RunConfig, ValidationError> {
+            let bar = self.bar.ok_or(ValidationError::MissingField("bar"))?;
+
+            Ok(super::RunConfig {
+                bar,
+                fast: self.fast.unwrap_or(false),
+            })
+        }
+
+        pub fn merge_args<I: Iterator<Item=::std::ffi::OsString>>(&mut self, program_path: &Option<std::path::PathBuf>, mut iter: I) -> Result<Option<::std::ffi::OsString>, super::Error> {
+            while let Some(arg) = iter.next() {
+                if arg == *"--" {
+                    return Ok(None);
+                } else if (arg == *"--help") || (arg == *"-h") {
+                    return Err(ArgParseError::HelpRequested(format!("{} run", program_path.as_ref().unwrap().to_string_lossy())).into());
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--bar", &arg, &mut iter) {
+                    let bar = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--bar"), ArgParseError::FieldBar))?;
+
+                    self.bar = Some(bar);
+                    self.bar_source = Some(super::Source::Argument("--bar"));
+                } else if arg == *"--fast" {
+                    self.fast = Some(true);
+                    self.fast_source = Some(super::Source::Argument("--fast"));
+                } else if let Some(mut shorts) = ::configure_me::parse_arg::iter_short(&arg) {
+                    for short in &mut shorts {
+                        if short == 'h' {
+                            return Err(ArgParseError::HelpRequested(format!("{} run", program_path.as_ref().unwrap().to_string_lossy())).into())
+                        } else if short == 'b' {
+                            self.bar = Some(shorts.parse_remaining(&mut iter).map_err(|err| err.map_or(ArgParseError::MissingArgument("-b"), ArgParseError::FieldBar))?);
+                            self.bar_source = Some(super::Source::Argument("-b"));
+                            break;
+                        } else if short == 'f' {
+                            self.fast = Some(true);
+                            self.fast_source = Some(super::Source::Argument("-f"));
+                        } else {
+                            let mut arg = String::with_capacity(2);
+                            arg.push('-');
+                            arg.push(short);
+                            return Err(ArgParseError::UnknownArgument(arg).into());
+                        }
+                    }
+                } else if arg.to_str().unwrap_or("").starts_with("--") {
+                    return Err(ArgParseError::UnknownArgument(arg.into_string().unwrap()).into());
+                } else {
+                    return Ok(Some(arg));
+                }
+            }
+
+            Ok(None)
+        }
+    }
+}
+
+/// Configuration of the `run` subcommand
+pub struct RunConfig {
+    pub bar: String,
+    pub fast: bool,
+}
+
+pub enum RunAllArgParseError {
+    MissingArgument(&'static str),
+    UnknownArgument(String),
+    HelpRequested(String),
+
+    FieldFoo(<u32 as ::configure_me::parse_arg::ParseArg>::Error),
+}
+
+#[automatically_derived]
+impl ::std::fmt::Display for RunAllArgParseError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use self::RunAllArgParseError as ArgParseError;
+
+        match self {
+            ArgParseError::MissingArgument(arg) => write!(f, "A value to argument '{}' is missing.", arg),
+            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &["--help", "--foo"]) {
+                Some(known) => write!(f, "An unknown argument '{}' was specified, did you mean '{}'?", arg, known),
+                None => write!(f, "An unknown argument '{}' was specified.", arg),
+            },
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--foo FOO]", program_name),
+        ArgParseError::FieldFoo(err) => {
+            write!(f, "Failed to parse argument '--foo': {}.\n\nHint: the value must be ", err)?;
+            <u32 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        }
+    }
+}
+
+#[automatically_derived]
+impl ::std::fmt::Debug for RunAllArgParseError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::std::fmt::Display::fmt(self, f)
+    }
+}
+
+#[automatically_derived]
+impl From<RunAllArgParseError> for Error {
+    fn from(err: RunAllArgParseError) -> Self {
+        Error::Arguments(ArgParseError::SubcommandRunAll(err))
+    }
+}
+
+mod raw_run_all {
+    use super::{RunAllArgParseError as ArgParseError, ValidationError};
+
+    #[derive(Default)]
+    pub struct Config {
+        foo: Option<u32>,
+        pub foo_source: Option<super::Source>,
+    }
+
+    #[automatically_derived]
+    impl Config {
+        pub fn validate(self) -> Result<super::RunAllConfig, ValidationError> {
+            let foo = self.foo.unwrap_or_else(|| { 42 });
+
+            Ok(super::RunAllConfig {
+                foo,
+            })
+        }
+
+        pub fn merge_args<I: Iterator<Item=::std::ffi::OsString>>(&mut self, program_path: &Option<std::path::PathBuf>, mut iter: I) -> Result<Option<::std::ffi::OsString>, super::Error> {
+            while let Some(arg) = iter.next() {
+                if arg == *"--" {
+                    return Ok(None);
+                } else if (arg == *"--help") || (arg == *"-h") {
+                    return Err(ArgParseError::HelpRequested(format!("{} run-all", program_path.as_ref().unwrap().to_string_lossy())).into());
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--foo", &arg, &mut iter) {
+                    let foo = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--foo"), ArgParseError::FieldFoo))?;
+
+                    self.foo = Some(foo);
+                    self.foo_source = Some(super::Source::Argument("--foo"));
+                } else if let Some(mut shorts) = ::configure_me::parse_arg::iter_short(&arg) {
+                    for short in &mut shorts {
+                        if short == 'h' {
+                            return Err(ArgParseError::HelpRequested(format!("{} run-all", program_path.as_ref().unwrap().to_string_lossy())).into())
+                        } else {
+                            let mut arg = String::with_capacity(2);
+                            arg.push('-');
+                            arg.push(short);
+                            return Err(ArgParseError::UnknownArgument(arg).into());
+                        }
+                    }
+                } else if arg.to_str().unwrap_or("").starts_with("--") {
+                    return Err(ArgParseError::UnknownArgument(arg.into_string().unwrap()).into());
+                } else {
+                    return Ok(Some(arg));
+                }
+            }
+
+            Ok(None)
+        }
+    }
+}
+
+/// Configuration of the `run-all` subcommand
+pub struct RunAllConfig {
+    pub foo: u32,
+}
+
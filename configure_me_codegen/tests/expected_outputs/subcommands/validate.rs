@@ -0,0 +1,8 @@
+            let foo = self.foo;
+            let subcommand = self.subcommand.ok_or(ValidationError::MissingSubcommand)?.validate()?;
+
+            Ok(super::Config {
+                foo,
+                verbose: self.verbose.unwrap_or(false),
+                subcommand,
+            })
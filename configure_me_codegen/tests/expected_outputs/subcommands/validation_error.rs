@@ -0,0 +1,2 @@
+    MissingField(&'static str),
+    MissingSubcommand,
@@ -0,0 +1,2 @@
+    pub timeout: ::configure_me::types::Duration,
+    pub cache_size: Option<::configure_me::types::ByteSize>,
@@ -0,0 +1,4 @@
+            sources: Sources {
+                timeout: config.timeout_source.clone().unwrap_or(Source::Default),
+                cache_size: config.cache_size_source.clone(),
+            },
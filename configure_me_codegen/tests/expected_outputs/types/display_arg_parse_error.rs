@@ -0,0 +1,15 @@
+            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &["--help", "--timeout", "--cache-size"]) {
+                Some(known) => write!(f, "An unknown argument '{}' was specified, did you mean '{}'?", arg, known),
+                None => write!(f, "An unknown argument '{}' was specified.", arg),
+            },
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--timeout TIMEOUT] [--cache-size CACHE_SIZE]\n\nArguments:\n        --timeout       How long to wait for a response (a duration with units \n                        ms, s, m, h or d, e.g. 1h30m)\n        --cache-size    Maximum size of the cache (a size with units B, K/KiB, \n                        kB, M/MiB, MB, G/GiB, GB, T/TiB or TB, e.g. 64MiB)", program_name),
+        ArgParseError::FieldTimeout(err) => {
+            write!(f, "Failed to parse argument '--timeout': {}.\n\nHint: the value must be ", err)?;
+            <::configure_me::types::Duration as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldCacheSize(err) => {
+            write!(f, "Failed to parse argument '--cache-size': {}.\n\nHint: the value must be ", err)?;
+            <::configure_me::types::ByteSize as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
@@ -0,0 +1,10 @@
+        EnvParseError::FieldTimeout(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_TYPES_TIMEOUT': {}.\n\nHint: the value must be ", err)?;
+            <::configure_me::types::Duration as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldCacheSize(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_TYPES_CACHE_SIZE': {}.\n\nHint: the value must be ", err)?;
+            <::configure_me::types::ByteSize as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
@@ -0,0 +1,2 @@
+    FieldTimeout(<::configure_me::types::Duration as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldCacheSize(<::configure_me::types::ByteSize as ::configure_me::parse_arg::ParseArg>::Error),
@@ -0,0 +1,8 @@
+            let mut config: Self = ::configure_me::toml::from_slice(&config_content).map_err(|error| super::Error::ConfigParsing { file: config_file_name.as_ref().into(), error })?;
+            if config.timeout.is_some() {
+                config.timeout_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.cache_size.is_some() {
+                config.cache_size_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            Ok(config)
@@ -0,0 +1,10 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--timeout", &arg, &mut iter) {
+                    let timeout = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--timeout"), ArgParseError::FieldTimeout))?;
+
+                    self.timeout = Some(timeout);
+                    self.timeout_source = Some(super::Source::Argument("--timeout"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--cache-size", &arg, &mut iter) {
+                    let cache_size = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--cache-size"), ArgParseError::FieldCacheSize))?;
+
+                    self.cache_size = Some(cache_size);
+                    self.cache_size_source = Some(super::Source::Argument("--cache-size"));
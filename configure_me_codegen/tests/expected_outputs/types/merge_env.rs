@@ -0,0 +1,10 @@
+        if let Some(val) = ::std::env::var_os("TEST_TYPES_TIMEOUT") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldTimeout)?;
+            self.timeout = Some(val);
+            self.timeout_source = Some(super::Source::EnvVar("TEST_TYPES_TIMEOUT"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_TYPES_CACHE_SIZE") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldCacheSize)?;
+            self.cache_size = Some(val);
+            self.cache_size_source = Some(super::Source::EnvVar("TEST_TYPES_CACHE_SIZE"));
+        }
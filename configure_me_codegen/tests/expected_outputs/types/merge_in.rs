@@ -0,0 +1,12 @@
+            if other.timeout.is_some() {
+                self.timeout = other.timeout;
+            }
+            if other.timeout_source.is_some() {
+                self.timeout_source = other.timeout_source;
+            }
+            if other.cache_size.is_some() {
+                self.cache_size = other.cache_size;
+            }
+            if other.cache_size_source.is_some() {
+                self.cache_size_source = other.cache_size_source;
+            }
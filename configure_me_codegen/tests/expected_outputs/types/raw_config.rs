@@ -0,0 +1,6 @@
+        timeout: Option<::configure_me::types::Duration>,
+        cache_size: Option<::configure_me::types::ByteSize>,
+        #[serde(skip)]
+        pub timeout_source: Option<super::Source>,
+        #[serde(skip)]
+        pub cache_size_source: Option<super::Source>,
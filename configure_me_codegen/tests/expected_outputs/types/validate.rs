@@ -0,0 +1,7 @@
+            let timeout = self.timeout.unwrap_or_else(|| { ::std::time::Duration::from_secs(30).into() });
+            let cache_size = self.cache_size;
+
+            Ok(super::Config {
+                timeout,
+                cache_size,
+            })
@@ -0,0 +1,3 @@
+    FieldBindAddr(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldMaxPeers(<u32 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldNetworkPort(<u16 as ::configure_me::parse_arg::ParseArg>::Error),
@@ -0,0 +1,4 @@
+    pub bind_addr: Option<String>,
+    pub max_peers: Option<u32>,
+    pub verbose: bool,
+    pub network: NetworkSection,
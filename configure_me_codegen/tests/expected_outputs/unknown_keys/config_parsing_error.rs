@@ -0,0 +1,2 @@
+    ConfigParsing { file: ::std::path::PathBuf, error: ::configure_me::toml::de::Error },
+    UnknownKey { file: ::std::path::PathBuf, key: String, suggestion: Option<&'static str> },
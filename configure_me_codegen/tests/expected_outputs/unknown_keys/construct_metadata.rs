@@ -0,0 +1,7 @@
+            warnings: ::std::mem::take(&mut config.warnings),
+            sources: Sources {
+                bind_addr: config.bind_addr_source.clone(),
+                max_peers: config.max_peers_source.clone(),
+                network_port: config.network.port_source.clone(),
+                verbose: config.verbose_source.clone().unwrap_or(Source::Default),
+            },
@@ -0,0 +1,20 @@
+            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &["--help", "--bind-addr", "--listen", "--max-peers", "--network-port", "--verbose"]) {
+                Some(known) => write!(f, "An unknown argument '{}' was specified, did you mean '{}'?", arg, known),
+                None => write!(f, "An unknown argument '{}' was specified.", arg),
+            },
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [ARGUMENTS...]\n\nArguments:\n        --bind-addr       Address to bind to\n        --max-peers       Maximum number of peers\n        --verbose         Log more details\n\nArguments in section network:\n        --network-port    Port to listen on", program_name),
+        ArgParseError::FieldBindAddr(err) => {
+            write!(f, "Failed to parse argument '--bind-addr': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldMaxPeers(err) => {
+            write!(f, "Failed to parse argument '--max-peers': {}.\n\nHint: the value must be ", err)?;
+            <u32 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldNetworkPort(err) => {
+            write!(f, "Failed to parse argument '--network-port': {}.\n\nHint: the value must be ", err)?;
+            <u16 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
@@ -0,0 +1,2 @@
+            Error::UnknownKey { file, key, suggestion: Some(suggestion) } => write!(f, "Unknown key '{}' in configuration file {}, did you mean '{}'?", key, file.display(), suggestion),
+            Error::UnknownKey { file, key, suggestion: None } => write!(f, "Unknown key '{}' in configuration file {}.", key, file.display()),
@@ -0,0 +1,18 @@
+        EnvParseError::FieldBindAddr(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_UNKNOWN_KEYS_BIND_ADDR': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldMaxPeers(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_UNKNOWN_KEYS_MAX_PEERS': {}.\n\nHint: the value must be ", err)?;
+            <u32 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldNetworkPort(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_UNKNOWN_KEYS_NETWORK_PORT': {}.\n\nHint: the value must be ", err)?;
+            <u16 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldVerbose(ref err) => {
+            write!(f, "Invalid value '{:?}' for 'TEST_UNKNOWN_KEYS_VERBOSE'.\n\nHint: the allowed values are 0, false, 1, true.", err)
+        },
@@ -0,0 +1,4 @@
+    FieldBindAddr(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldMaxPeers(<u32 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldVerbose(::std::ffi::OsString),
+    FieldNetworkPort(<u16 as ::configure_me::parse_arg::ParseArg>::Error),
@@ -0,0 +1,25 @@
+            let mut config: Self = ::configure_me::toml::from_slice(&config_content).map_err(|error| super::Error::ConfigParsing { file: config_file_name.as_ref().into(), error })?;
+            let keys: ::configure_me::keys::Keys = ::configure_me::toml::from_slice(&config_content).map_err(|error| super::Error::ConfigParsing { file: config_file_name.as_ref().into(), error })?;
+            let unknown_keys = keys.unknown(&["bind_addr", "listen", "maxpeers", "network.port", "verbose"]);
+            if let Some(unknown) = unknown_keys.into_iter().next() {
+                return Err(super::Error::UnknownKey { file: config_file_name.as_ref().into(), key: unknown.key, suggestion: unknown.suggestion });
+            }
+            if let Some(value) = config.listen.take() {
+                config.warnings.push(super::Warning::Alias { alias: "listen", name: "bind_addr", source: super::Source::ConfigFile(config_file_name.as_ref().into()) });
+                if config.bind_addr.is_none() {
+                    config.bind_addr = Some(value);
+                }
+            }
+            if config.bind_addr.is_some() {
+                config.bind_addr_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.max_peers.is_some() {
+                config.max_peers_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.network.port.is_some() {
+                config.network.port_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.verbose.is_some() {
+                config.verbose_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            Ok(config)
@@ -0,0 +1,24 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--bind-addr", &arg, &mut iter) {
+                    let bind_addr = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--bind-addr"), ArgParseError::FieldBindAddr))?;
+
+                    self.bind_addr = Some(bind_addr);
+                    self.bind_addr_source = Some(super::Source::Argument("--bind-addr"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--listen", &arg, &mut iter) {
+                    let bind_addr = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--listen"), ArgParseError::FieldBindAddr))?;
+
+                    self.warnings.push(super::Warning::Alias { alias: "listen", name: "bind_addr", source: super::Source::Argument("--listen") });
+                    self.bind_addr = Some(bind_addr);
+                    self.bind_addr_source = Some(super::Source::Argument("--listen"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--max-peers", &arg, &mut iter) {
+                    let max_peers = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--max-peers"), ArgParseError::FieldMaxPeers))?;
+
+                    self.max_peers = Some(max_peers);
+                    self.max_peers_source = Some(super::Source::Argument("--max-peers"));
+                } else if arg == *"--verbose" {
+                    self.verbose = Some(true);
+                    self.verbose_source = Some(super::Source::Argument("--verbose"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--network-port", &arg, &mut iter) {
+                    let network_port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--network-port"), ArgParseError::FieldNetworkPort))?;
+
+                    self.network.port = Some(network_port);
+                    self.network.port_source = Some(super::Source::Argument("--network-port"));
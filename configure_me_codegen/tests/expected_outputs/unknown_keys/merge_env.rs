@@ -0,0 +1,31 @@
+        if let Some(val) = ::std::env::var_os("TEST_UNKNOWN_KEYS_LISTEN") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldBindAddr)?;
+            self.warnings.push(super::Warning::Alias { alias: "listen", name: "bind_addr", source: super::Source::EnvVar("TEST_UNKNOWN_KEYS_LISTEN") });
+            self.bind_addr = Some(val);
+            self.bind_addr_source = Some(super::Source::EnvVar("TEST_UNKNOWN_KEYS_LISTEN"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_UNKNOWN_KEYS_BIND_ADDR") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldBindAddr)?;
+            self.bind_addr = Some(val);
+            self.bind_addr_source = Some(super::Source::EnvVar("TEST_UNKNOWN_KEYS_BIND_ADDR"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_UNKNOWN_KEYS_MAX_PEERS") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldMaxPeers)?;
+            self.max_peers = Some(val);
+            self.max_peers_source = Some(super::Source::EnvVar("TEST_UNKNOWN_KEYS_MAX_PEERS"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_UNKNOWN_KEYS_NETWORK_PORT") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldNetworkPort)?;
+            self.network.port = Some(val);
+            self.network.port_source = Some(super::Source::EnvVar("TEST_UNKNOWN_KEYS_NETWORK_PORT"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_UNKNOWN_KEYS_VERBOSE") {
+            if val == *"1" || val == *"true" {
+                self.verbose = Some(true);
+            } else if val == *"0" || val == *"false" {
+                self.verbose = Some(false);
+            } else {
+                return Err(super::EnvParseError::FieldVerbose(val).into());
+            }
+            self.verbose_source = Some(super::Source::EnvVar("TEST_UNKNOWN_KEYS_VERBOSE"));
+        }
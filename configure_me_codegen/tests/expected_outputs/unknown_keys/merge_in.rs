@@ -0,0 +1,20 @@
+            if other.bind_addr.is_some() {
+                self.bind_addr = other.bind_addr;
+            }
+            if other.bind_addr_source.is_some() {
+                self.bind_addr_source = other.bind_addr_source;
+            }
+            if other.max_peers.is_some() {
+                self.max_peers = other.max_peers;
+            }
+            if other.max_peers_source.is_some() {
+                self.max_peers_source = other.max_peers_source;
+            }
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
+            if other.verbose_source.is_some() {
+                self.verbose_source = other.verbose_source;
+            }
+            self.network.merge_in(other.network);
+            self.warnings.extend(other.warnings);
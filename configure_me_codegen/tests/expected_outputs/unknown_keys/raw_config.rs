@@ -0,0 +1,15 @@
+        bind_addr: Option<String>,
+        #[serde(rename = "maxpeers")]
+        max_peers: Option<u32>,
+        verbose: Option<bool>,
+        listen: Option<String>,
+        #[serde(skip)]
+        pub bind_addr_source: Option<super::Source>,
+        #[serde(skip)]
+        pub max_peers_source: Option<super::Source>,
+        #[serde(skip)]
+        pub verbose_source: Option<super::Source>,
+        #[serde(default)]
+        pub network: NetworkSection,
+        #[serde(skip)]
+        pub warnings: Vec<super::Warning>,
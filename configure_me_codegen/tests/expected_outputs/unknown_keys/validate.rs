@@ -0,0 +1,10 @@
+            let bind_addr = self.bind_addr;
+            let max_peers = self.max_peers;
+            let network = self.network.validate()?;
+
+            Ok(super::Config {
+                bind_addr,
+                max_peers,
+                verbose: self.verbose.unwrap_or(false),
+                network,
+            })
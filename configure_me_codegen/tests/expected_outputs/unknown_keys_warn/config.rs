@@ -0,0 +1 @@
+    pub bind_addr: Option<String>,
@@ -0,0 +1 @@
+    ConfigParsing { file: ::std::path::PathBuf, error: ::configure_me::toml::de::Error },
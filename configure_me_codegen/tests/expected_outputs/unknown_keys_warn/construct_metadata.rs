@@ -0,0 +1,4 @@
+            warnings: ::std::mem::take(&mut config.warnings),
+            sources: Sources {
+                bind_addr: config.bind_addr_source.clone(),
+            },
@@ -0,0 +1,5 @@
+        EnvParseError::FieldBindAddr(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_UNKNOWN_KEYS_WARN_BIND_ADDR': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
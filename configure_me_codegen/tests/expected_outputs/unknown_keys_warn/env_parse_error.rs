@@ -0,0 +1 @@
+    FieldBindAddr(<String as ::configure_me::parse_arg::ParseArg>::Error),
@@ -0,0 +1,10 @@
+            let mut config: Self = ::configure_me::toml::from_slice(&config_content).map_err(|error| super::Error::ConfigParsing { file: config_file_name.as_ref().into(), error })?;
+            let keys: ::configure_me::keys::Keys = ::configure_me::toml::from_slice(&config_content).map_err(|error| super::Error::ConfigParsing { file: config_file_name.as_ref().into(), error })?;
+            let unknown_keys = keys.unknown(&["bind_addr"]);
+            for unknown in unknown_keys {
+                config.warnings.push(super::Warning::UnknownKey { file: config_file_name.as_ref().into(), key: unknown.key, suggestion: unknown.suggestion });
+            }
+            if config.bind_addr.is_some() {
+                config.bind_addr_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            Ok(config)
@@ -0,0 +1,5 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--bind-addr", &arg, &mut iter) {
+                    let bind_addr = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--bind-addr"), ArgParseError::FieldBindAddr))?;
+
+                    self.bind_addr = Some(bind_addr);
+                    self.bind_addr_source = Some(super::Source::Argument("--bind-addr"));
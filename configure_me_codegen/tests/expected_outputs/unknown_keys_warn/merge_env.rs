@@ -0,0 +1,5 @@
+        if let Some(val) = ::std::env::var_os("TEST_UNKNOWN_KEYS_WARN_BIND_ADDR") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldBindAddr)?;
+            self.bind_addr = Some(val);
+            self.bind_addr_source = Some(super::Source::EnvVar("TEST_UNKNOWN_KEYS_WARN_BIND_ADDR"));
+        }
@@ -0,0 +1,5 @@
+        bind_addr: Option<String>,
+        #[serde(skip)]
+        pub bind_addr_source: Option<super::Source>,
+        #[serde(skip)]
+        pub warnings: Vec<super::Warning>,
@@ -0,0 +1,5 @@
+            let bind_addr = self.bind_addr;
+
+            Ok(super::Config {
+                bind_addr,
+            })
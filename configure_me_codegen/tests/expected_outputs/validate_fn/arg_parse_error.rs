@@ -0,0 +1,3 @@
+    FieldPort(<u16 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldName(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldPeer(<String as ::configure_me::parse_arg::ParseArg>::Error),
@@ -0,0 +1,4 @@
+    pub port: u16,
+    pub name: Option<String>,
+    pub peer: Vec<String>,
+    pub tls: bool,
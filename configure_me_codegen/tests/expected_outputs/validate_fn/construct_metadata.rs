@@ -0,0 +1,6 @@
+            sources: Sources {
+                port: config.port_source.clone().unwrap_or(Source::Default),
+                name: config.name_source.clone(),
+                peer: config.peer_source.clone().unwrap_or(Source::Default),
+                tls: config.tls_source.clone().unwrap_or(Source::Default),
+            },
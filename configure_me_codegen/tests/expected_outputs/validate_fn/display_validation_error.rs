@@ -0,0 +1,3 @@
+            ValidationError::Custom { field: Some(field), source: Some(ref source), ref message } => write!(f, "Value of configuration parameter '{}' from {} is invalid: {}", field, source, message),
+            ValidationError::Custom { field: Some(field), source: None, ref message } => write!(f, "Configuration parameter '{}' is invalid: {}", field, message),
+            ValidationError::Custom { field: None, ref message, .. } => write!(f, "{}", message),
@@ -0,0 +1,18 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--port", &arg, &mut iter) {
+                    let port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--port"), ArgParseError::FieldPort))?;
+
+                    self.port = Some(port);
+                    self.port_source = Some(super::Source::Argument("--port"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--name", &arg, &mut iter) {
+                    let name = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--name"), ArgParseError::FieldName))?;
+
+                    self.name = Some(name);
+                    self.name_source = Some(super::Source::Argument("--name"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--peer", &arg, &mut iter) {
+                    let peer = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--peer"), ArgParseError::FieldPeer))?;
+
+                    self.peer.get_or_insert_with(Vec::new).push(peer);
+                    self.peer_source = Some(super::Source::Argument("--peer"));
+                } else if arg == *"--tls" {
+                    self.tls = Some(true);
+                    self.tls_source = Some(super::Source::Argument("--tls"));
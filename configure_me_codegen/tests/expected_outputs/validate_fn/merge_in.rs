@@ -0,0 +1,24 @@
+            if other.port.is_some() {
+                self.port = other.port;
+            }
+            if other.port_source.is_some() {
+                self.port_source = other.port_source;
+            }
+            if other.name.is_some() {
+                self.name = other.name;
+            }
+            if other.name_source.is_some() {
+                self.name_source = other.name_source;
+            }
+            if other.peer.is_some() {
+                self.peer = other.peer;
+            }
+            if other.peer_source.is_some() {
+                self.peer_source = other.peer_source;
+            }
+            if other.tls.is_some() {
+                self.tls = other.tls;
+            }
+            if other.tls_source.is_some() {
+                self.tls_source = other.tls_source;
+            }
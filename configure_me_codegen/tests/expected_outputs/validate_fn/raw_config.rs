@@ -0,0 +1,12 @@
+        port: Option<u16>,
+        name: Option<String>,
+        peer: Option<Vec<String>>,
+        tls: Option<bool>,
+        #[serde(skip)]
+        pub port_source: Option<super::Source>,
+        #[serde(skip)]
+        pub name_source: Option<super::Source>,
+        #[serde(skip)]
+        pub peer_source: Option<super::Source>,
+        #[serde(skip)]
+        pub tls_source: Option<super::Source>,
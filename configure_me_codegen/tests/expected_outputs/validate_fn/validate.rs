@@ -0,0 +1,26 @@
+            let port = self.port.unwrap_or_else(|| { 8080 });
+            if let Err(error) = crate::check_port(&port) {
+                return Err(ValidationError::Custom { field: Some("port"), source: Some(self.port_source.unwrap_or(super::Source::Default)), message: error.to_string() });
+            }
+            let name = self.name;
+            if let Some(name) = &name {
+                if let Err(error) = crate::check_name(name) {
+                    return Err(ValidationError::Custom { field: Some("name"), source: Some(self.name_source.unwrap_or(super::Source::Default)), message: error.to_string() });
+                }
+            }
+            let peer = self.peer.unwrap_or_default();
+            for peer in &peer {
+                if let Err(error) = crate::check_name(peer) {
+                    return Err(ValidationError::Custom { field: Some("peer"), source: Some(self.peer_source.unwrap_or(super::Source::Default)), message: error.to_string() });
+                }
+            }
+
+            let config = super::Config {
+                port,
+                name,
+                peer,
+                tls: self.tls.unwrap_or(false),
+            };
+            crate::check_config(&config).map_err(|error| ValidationError::Custom { field: None, source: None, message: error.to_string() })?;
+
+            Ok(config)
@@ -0,0 +1 @@
+    Custom { field: Option<&'static str>, source: Option<Source>, message: String },
@@ -0,0 +1,4 @@
+    pub port: u16,
+    pub ratio: Option<f64>,
+    pub user: String,
+    pub tag: Vec<String>,
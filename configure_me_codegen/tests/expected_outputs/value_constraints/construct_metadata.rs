@@ -0,0 +1,6 @@
+            sources: Sources {
+                port: config.port_source.clone().unwrap_or(Source::Default),
+                ratio: config.ratio_source.clone(),
+                user: config.user_source.clone().unwrap_or(Source::Default),
+                tag: config.tag_source.clone().unwrap_or(Source::Default),
+            },
@@ -0,0 +1,25 @@
+            ArgParseError::UnknownArgument(arg) => match ::configure_me::suggest::closest_argument(arg, &["--help", "--port", "--ratio", "--user", "--tag"]) {
+                Some(known) => write!(f, "An unknown argument '{}' was specified, did you mean '{}'?", arg, known),
+                None => write!(f, "An unknown argument '{}' was specified.", arg),
+            },
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--port PORT] [--ratio RATIO] [--user USER] [-t TAG|--tag TAG]...\n\nArguments:\n        --port       Port to listen on (must be at least 1024)\n        --ratio      Must be at least 0, at most 1\n        --user       Must be at least 3 characters long, at most 16 characters \n                     long, matching `^[a-z]+$`\n        -t, --tag    Must be at most 8 characters long", program_name),
+        ArgParseError::FieldPort(err) => {
+            write!(f, "Failed to parse argument '--port': {}.\n\nHint: the value must be ", err)?;
+            <u16 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldRatio(err) => {
+            write!(f, "Failed to parse argument '--ratio': {}.\n\nHint: the value must be ", err)?;
+            <f64 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldUser(err) => {
+            write!(f, "Failed to parse argument '--user': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        ArgParseError::FieldTag(err) => {
+            write!(f, "Failed to parse argument '--tag': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
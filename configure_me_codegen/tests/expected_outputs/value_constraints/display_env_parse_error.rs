@@ -0,0 +1,20 @@
+        EnvParseError::FieldPort(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_VALUE_CONSTRAINTS_PORT': {}.\n\nHint: the value must be ", err)?;
+            <u16 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldRatio(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_VALUE_CONSTRAINTS_RATIO': {}.\n\nHint: the value must be ", err)?;
+            <f64 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldUser(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_VALUE_CONSTRAINTS_USER': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldTag(ref err) => {
+            write!(f, "Failed to parse environment variable 'TEST_VALUE_CONSTRAINTS_TAG': {}.\n\nHint: the value must be ", err)?;
+            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
@@ -0,0 +1,3 @@
+            ValidationError::MissingField(field) => write!(f, "Configuration parameter '{}' not specified.", field),
+            ValidationError::OutOfRange { field, ref source, constraint } => write!(f, "Value of configuration parameter '{}' from {} must be {}.", field, source, constraint),
+            ValidationError::PatternMismatch { field, ref source, pattern } => write!(f, "Value of configuration parameter '{}' from {} must match the pattern `{}`.", field, source, pattern),
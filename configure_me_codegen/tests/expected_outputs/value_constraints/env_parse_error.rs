@@ -0,0 +1,4 @@
+    FieldPort(<u16 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldRatio(<f64 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldUser(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldTag(<String as ::configure_me::parse_arg::ParseArg>::Error),
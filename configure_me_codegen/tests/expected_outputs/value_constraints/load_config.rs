@@ -0,0 +1,14 @@
+            let mut config: Self = ::configure_me::toml::from_slice(&config_content).map_err(|error| super::Error::ConfigParsing { file: config_file_name.as_ref().into(), error })?;
+            if config.port.is_some() {
+                config.port_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.ratio.is_some() {
+                config.ratio_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.user.is_some() {
+                config.user_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.tag.is_some() {
+                config.tag_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            Ok(config)
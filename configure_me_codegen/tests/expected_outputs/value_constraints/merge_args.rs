@@ -0,0 +1,20 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--port", &arg, &mut iter) {
+                    let port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--port"), ArgParseError::FieldPort))?;
+
+                    self.port = Some(port);
+                    self.port_source = Some(super::Source::Argument("--port"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--ratio", &arg, &mut iter) {
+                    let ratio = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--ratio"), ArgParseError::FieldRatio))?;
+
+                    self.ratio = Some(ratio);
+                    self.ratio_source = Some(super::Source::Argument("--ratio"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--user", &arg, &mut iter) {
+                    let user = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--user"), ArgParseError::FieldUser))?;
+
+                    self.user = Some(user);
+                    self.user_source = Some(super::Source::Argument("--user"));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--tag", &arg, &mut iter) {
+                    let tag = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--tag"), ArgParseError::FieldTag))?;
+
+                    self.tag.get_or_insert_with(Vec::new).push(tag);
+                    self.tag_source = Some(super::Source::Argument("--tag"));
@@ -0,0 +1,24 @@
+        if let Some(val) = ::std::env::var_os("TEST_VALUE_CONSTRAINTS_PORT") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldPort)?;
+            self.port = Some(val);
+            self.port_source = Some(super::Source::EnvVar("TEST_VALUE_CONSTRAINTS_PORT"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_VALUE_CONSTRAINTS_RATIO") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldRatio)?;
+            self.ratio = Some(val);
+            self.ratio_source = Some(super::Source::EnvVar("TEST_VALUE_CONSTRAINTS_RATIO"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_VALUE_CONSTRAINTS_USER") {
+            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldUser)?;
+            self.user = Some(val);
+            self.user_source = Some(super::Source::EnvVar("TEST_VALUE_CONSTRAINTS_USER"));
+        }
+        if let Some(val) = ::std::env::var_os("TEST_VALUE_CONSTRAINTS_TAG") {
+            let val = match val.into_string() {
+                Ok(val) => val.split(",").map(|item| <String as ::configure_me::parse_arg::ParseArg>::parse_arg(item.as_ref())).collect::<Result<Vec<_>, _>>(),
+                // Values that aren't valid UTF-8 can't be split
+                Err(val) => <String as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(val).map(|item| vec![item]),
+            }.map_err(super::EnvParseError::FieldTag)?;
+            self.tag = Some(val);
+            self.tag_source = Some(super::Source::EnvVar("TEST_VALUE_CONSTRAINTS_TAG"));
+        }
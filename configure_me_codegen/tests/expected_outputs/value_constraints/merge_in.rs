@@ -0,0 +1,24 @@
+            if other.port.is_some() {
+                self.port = other.port;
+            }
+            if other.port_source.is_some() {
+                self.port_source = other.port_source;
+            }
+            if other.ratio.is_some() {
+                self.ratio = other.ratio;
+            }
+            if other.ratio_source.is_some() {
+                self.ratio_source = other.ratio_source;
+            }
+            if other.user.is_some() {
+                self.user = other.user;
+            }
+            if other.user_source.is_some() {
+                self.user_source = other.user_source;
+            }
+            if other.tag.is_some() {
+                self.tag = other.tag;
+            }
+            if other.tag_source.is_some() {
+                self.tag_source = other.tag_source;
+            }
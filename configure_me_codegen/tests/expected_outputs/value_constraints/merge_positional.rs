@@ -0,0 +1,2 @@
+                } else {
+                    return Ok((program_path, Some(arg).into_iter().chain(iter)))
@@ -0,0 +1,4 @@
+                        } else if short == 't' {
+                            self.tag.get_or_insert_with(Vec::new).push(shorts.parse_remaining(&mut iter).map_err(|err| err.map_or(ArgParseError::MissingArgument("-t"), ArgParseError::FieldTag))?);
+                            self.tag_source = Some(super::Source::Argument("-t"));
+                            break;
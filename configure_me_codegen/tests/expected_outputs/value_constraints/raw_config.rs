@@ -0,0 +1,12 @@
+        port: Option<u16>,
+        ratio: Option<f64>,
+        user: Option<String>,
+        tag: Option<Vec<String>>,
+        #[serde(skip)]
+        pub port_source: Option<super::Source>,
+        #[serde(skip)]
+        pub ratio_source: Option<super::Source>,
+        #[serde(skip)]
+        pub user_source: Option<super::Source>,
+        #[serde(skip)]
+        pub tag_source: Option<super::Source>,
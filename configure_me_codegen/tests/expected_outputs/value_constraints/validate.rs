@@ -0,0 +1,36 @@
+            let port = self.port.unwrap_or_else(|| { 8080 });
+            if port < 1024 {
+                return Err(ValidationError::OutOfRange { field: "port", source: self.port_source.unwrap_or(super::Source::Default), constraint: "at least 1024" });
+            }
+            let ratio = self.ratio;
+            if let Some(ratio) = &ratio {
+                if *ratio < 0.0 {
+                    return Err(ValidationError::OutOfRange { field: "ratio", source: self.ratio_source.unwrap_or(super::Source::Default), constraint: "at least 0" });
+                }
+                if *ratio > 1.0 {
+                    return Err(ValidationError::OutOfRange { field: "ratio", source: self.ratio_source.unwrap_or(super::Source::Default), constraint: "at most 1" });
+                }
+            }
+            let user = self.user.ok_or(ValidationError::MissingField("user"))?;
+            if user.chars().count() < 3 {
+                return Err(ValidationError::OutOfRange { field: "user", source: self.user_source.unwrap_or(super::Source::Default), constraint: "at least 3 characters long" });
+            }
+            if user.chars().count() > 16 {
+                return Err(ValidationError::OutOfRange { field: "user", source: self.user_source.unwrap_or(super::Source::Default), constraint: "at most 16 characters long" });
+            }
+            if !::configure_me::regex::Regex::new("^[a-z]+$").expect("the pattern was checked by configure_me_codegen").is_match(&user) {
+                return Err(ValidationError::PatternMismatch { field: "user", source: self.user_source.unwrap_or(super::Source::Default), pattern: "^[a-z]+$" });
+            }
+            let tag = self.tag.unwrap_or_default();
+            for tag in &tag {
+                if tag.chars().count() > 8 {
+                    return Err(ValidationError::OutOfRange { field: "tag", source: self.tag_source.unwrap_or(super::Source::Default), constraint: "at most 8 characters long" });
+                }
+            }
+
+            Ok(super::Config {
+                port,
+                ratio,
+                user,
+                tag,
+            })
@@ -0,0 +1,3 @@
+    MissingField(&'static str),
+    OutOfRange { field: &'static str, source: Source, constraint: &'static str },
+    PatternMismatch { field: &'static str, source: Source, pattern: &'static str },
@@ -0,0 +1,4 @@
+            sources: Sources {
+                foo: config.foo_source.clone(),
+                bar: config.bar_source.clone(),
+            },
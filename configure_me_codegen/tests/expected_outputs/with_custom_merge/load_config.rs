@@ -0,0 +1,8 @@
+            let mut config: Self = ::configure_me::toml::from_slice(&config_content).map_err(|error| super::Error::ConfigParsing { file: config_file_name.as_ref().into(), error })?;
+            if config.foo.is_some() {
+                config.foo_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            if config.bar.is_some() {
+                config.bar_source = Some(super::Source::ConfigFile(config_file_name.as_ref().into()));
+            }
+            Ok(config)
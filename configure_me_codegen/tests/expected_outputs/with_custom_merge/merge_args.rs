@@ -6,6 +6,7 @@
                     } else {
                         self.foo = Some(foo);
                     }
+                    self.foo_source = Some(super::Source::Argument("--foo"));
                 } else if let Some(value) = ::configure_me::parse_arg::match_arg("--bar", &arg, &mut iter) {
                     let bar = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--bar"), ArgParseError::FieldBar))?;
 
@@ -14,3 +15,4 @@
                     } else {
                         self.bar = Some(bar);
                     }
+                    self.bar_source = Some(super::Source::Argument("--bar"));
@@ -5,6 +5,7 @@
             } else {
                 self.foo = Some(val);
             }
+            self.foo_source = Some(super::Source::EnvVar("TEST_APP_FOO"));
         }
         if let Some(val) = ::std::env::var_os("TEST_APP_BAR") {
             let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldBar)?;
@@ -13,4 +14,5 @@
             } else {
                 self.bar = Some(val);
             }
+            self.bar_source = Some(super::Source::EnvVar("TEST_APP_BAR"));
         }
@@ -5,6 +5,9 @@
                     self.foo = Some(foo);
                 }
             }
+            if other.foo_source.is_some() {
+                self.foo_source = other.foo_source;
+            }
             if let Some(bar) = other.bar {
                 if let Some(bar_old) = &mut self.bar {
                     (|a: &mut String, b: String| a.push_str(&b))(bar_old, bar);
@@ -12,3 +15,6 @@
                     self.bar = Some(bar);
                 }
             }
+            if other.bar_source.is_some() {
+                self.bar_source = other.bar_source;
+            }
@@ -1,2 +1,6 @@
         foo: Option<u32>,
         bar: Option<String>,
+        #[serde(skip)]
+        pub foo_source: Option<super::Source>,
+        #[serde(skip)]
+        pub bar_source: Option<super::Source>,
@@ -0,0 +1,22 @@
+macro_rules! test_name { () => { "hidden" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+
+#[test]
+fn hidden_args_are_parsed() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["hidden", "--fail-after", "3", "--debug-dump"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.fail_after, Some(3));
+    assert!(config.debug_dump);
+}
+
+#[test]
+fn help_groups_categories() {
+    let result = config::Config::custom_args_and_optional_files(&["hidden", "--help"], iter::empty::<PathBuf>());
+    match result {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => assert_eq!(err.to_string(), "Usage: hidden [ARGUMENTS...]\n\nArguments:\n        --log-filter       Which messages to log\n        -v, --verbose      Print more details\n\nArguments in section cache:\n        --cache-disable    Disable caching\n\nNetworking:\n        --host             Host to connect to\n        --port             Port to connect to\n        --ipv6             Prefer IPv6\n\nCaching:\n        --cache-size       Maximum number of cached entries"),
+    }
+}
@@ -0,0 +1,51 @@
+macro_rules! test_name { () => { "multiple_values" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+
+#[test]
+fn repeated_args() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["multiple_values", "--peer", "alice", "-p", "bob", "--port=80", "--port", "443", "--level", "7"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.peer, ["alice", "bob"]);
+    assert_eq!(config.port, [80, 443]);
+    assert_eq!(config.level, [7]);
+}
+
+#[test]
+fn config_file() {
+    let config_files = [concat!(env!("CARGO_MANIFEST_DIR"), "/tests/config_files/peers.toml")];
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["multiple_values", "--level", "3"], &config_files).unwrap();
+    assert_eq!(config.peer, ["alice", "bob"]);
+    assert_eq!(config.port, [80, 443]);
+    assert_eq!(config.level, [3]);
+}
+
+#[test]
+fn args_override_config_file() {
+    let config_files = [concat!(env!("CARGO_MANIFEST_DIR"), "/tests/config_files/peers.toml")];
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["multiple_values", "--peer", "carol"], &config_files).unwrap();
+    assert_eq!(config.peer, ["carol"]);
+    assert_eq!(config.port, [80, 443]);
+}
+
+// Other tests always override the level so setting the env var doesn't affect them.
+#[test]
+fn env_var() {
+    std::env::set_var("TEST_MULTIPLE_VALUES_LEVEL", "4,5");
+    let result = config::Config::custom_args_and_optional_files(&["multiple_values", "--port", "80"], iter::empty::<PathBuf>());
+    std::env::remove_var("TEST_MULTIPLE_VALUES_LEVEL");
+    let (config, _remaining, _metadata) = result.unwrap();
+    assert_eq!(config.level, [4, 5]);
+    assert!(config.peer.is_empty());
+}
+
+#[test]
+fn print_help() {
+    let result = config::Config::custom_args_and_optional_files(&["multiple_values", "--help"], iter::empty::<PathBuf>());
+    match result {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => assert_eq!(err.to_string(), "Usage: multiple_values [-p PEER|--peer PEER]... [--port PORT]... [--level LEVEL]...\n\nArguments:\n        -p, --peer    A peer to connect to"),
+    }
+}
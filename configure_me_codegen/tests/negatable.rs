@@ -0,0 +1,53 @@
+macro_rules! test_name { () => { "negatable" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+
+#[test]
+fn defaults() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["negatable"], iter::empty::<PathBuf>()).unwrap();
+    assert!(!config.verbose);
+    assert!(config.color);
+    assert!(!config.cache.enabled);
+}
+
+#[test]
+fn both_forms() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["negatable", "--verbose", "--no-color", "--cache-enabled"], iter::empty::<PathBuf>()).unwrap();
+    assert!(config.verbose);
+    assert!(!config.color);
+    assert!(config.cache.enabled);
+}
+
+#[test]
+fn last_one_wins() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["negatable", "--no-verbose", "-v", "--no-color", "--color"], iter::empty::<PathBuf>()).unwrap();
+    assert!(config.verbose);
+    assert!(config.color);
+
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["negatable", "-v", "--no-verbose"], iter::empty::<PathBuf>()).unwrap();
+    assert!(!config.verbose);
+}
+
+#[test]
+fn overrides_config_file() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["negatable"], iter::once("tests/config_files/negatable.toml")).unwrap();
+    assert!(config.verbose);
+    assert!(!config.color);
+    assert!(config.cache.enabled);
+
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["negatable", "--no-verbose", "--color", "--no-cache-on"], iter::once("tests/config_files/negatable.toml")).unwrap();
+    assert!(!config.verbose);
+    assert!(config.color);
+    assert!(!config.cache.enabled);
+}
+
+#[test]
+fn not_negatable() {
+    match config::Config::custom_args_and_optional_files(&["negatable", "--no-fast"], iter::empty::<PathBuf>()) {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => assert_eq!(err.to_string(), "An unknown argument '--no-fast' was specified, did you mean '--fast'?"),
+    }
+}
@@ -0,0 +1,46 @@
+macro_rules! test_name { () => { "optional_value" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+
+#[test]
+fn default_without_arg() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["optional_value"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.color, "auto");
+    assert_eq!(config.log, None);
+}
+
+#[test]
+fn without_value() {
+    let (config, remaining, _metadata) = config::Config::custom_args_and_optional_files(&["optional_value", "--color", "--log", "positional"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.color, "always");
+    assert_eq!(config.log, Some(3));
+    assert_eq!(remaining.collect::<Vec<_>>(), ["positional"]);
+}
+
+#[test]
+fn with_value() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["optional_value", "--color=never", "--log=5", "--name", "foo"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.color, "never");
+    assert_eq!(config.log, Some(5));
+    assert_eq!(config.name.as_ref().map(String::as_str), Some("foo"));
+}
+
+#[test]
+fn short() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["optional_value", "-l"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.log, Some(3));
+
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["optional_value", "-l1"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.log, Some(1));
+}
+
+#[test]
+fn invalid_value() {
+    match config::Config::custom_args_and_optional_files(&["optional_value", "--log=loud"], iter::empty::<PathBuf>()) {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => assert!(err.to_string().starts_with("Failed to parse argument '--log'")),
+    }
+}
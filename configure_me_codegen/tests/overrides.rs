@@ -0,0 +1,59 @@
+macro_rules! test_name { () => { "overrides" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+
+fn error(args: &[&str]) -> String {
+    match config::Config::custom_args_and_optional_files(args, iter::empty::<PathBuf>()) {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => err.to_string(),
+    }
+}
+
+#[test]
+fn long() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["overrides", "--listen-address", "0.0.0.0:80", "--plain", "--database-url", "postgres://localhost"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.listen, "0.0.0.0:80");
+    assert!(config.no_color);
+    assert_eq!(config.db.url.as_ref().map(String::as_str), Some("postgres://localhost"));
+}
+
+#[test]
+fn derived_long_rejected() {
+    let err = error(&["overrides", "--listen", "0.0.0.0:80"]);
+    assert!(err.starts_with("An unknown argument '--listen' was specified."));
+}
+
+#[test]
+fn file_key() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["overrides"], iter::once("tests/config_files/overrides.toml")).unwrap();
+    assert_eq!(config.listen, "0.0.0.0:80");
+}
+
+#[test]
+fn env_fallback() {
+    std::env::set_var("TEST_OVERRIDES_HTTP_PROXY", "http://fallback");
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["overrides"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.proxy.as_ref().map(String::as_str), Some("http://fallback"));
+    std::env::set_var("TEST_OVERRIDES_PROXY", "http://primary");
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["overrides"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.proxy.as_ref().map(String::as_str), Some("http://primary"));
+}
+
+#[test]
+fn env_single() {
+    std::env::set_var("TEST_OVERRIDES_LOG", "debug");
+    std::env::set_var("TEST_OVERRIDES_LOG_FILTER", "trace");
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["overrides"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.log_filter.as_ref().map(String::as_str), Some("debug"));
+}
+
+#[test]
+fn help() {
+    let err = error(&["overrides", "--help"]);
+    assert!(err.contains("--listen-address"));
+    assert!(err.contains("--plain"));
+    assert!(err.contains("--database-url"));
+}
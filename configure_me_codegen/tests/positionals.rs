@@ -0,0 +1,70 @@
+macro_rules! test_name { () => { "positionals" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+
+#[test]
+fn mandatory_only() {
+    let (config, mut remaining, _metadata) = config::Config::custom_args_and_optional_files(&["positionals", "in"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.input, "in");
+    assert_eq!(config.output, None);
+    assert!(config.numbers.is_empty());
+    assert_eq!(remaining.next(), None);
+}
+
+#[test]
+fn all_positionals() {
+    let (config, mut remaining, _metadata) = config::Config::custom_args_and_optional_files(&["positionals", "in", "out", "1", "2"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.input, "in");
+    assert_eq!(config.output, Some("out".to_owned()));
+    assert_eq!(config.numbers, [1, 2]);
+    assert_eq!(remaining.next(), None);
+}
+
+#[test]
+fn interleaved_with_options() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["positionals", "in", "--foo", "42", "out", "-v", "1"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.input, "in");
+    assert_eq!(config.output, Some("out".to_owned()));
+    assert_eq!(config.foo, Some(42));
+    assert!(config.verbose);
+    assert_eq!(config.numbers, [1]);
+}
+
+#[test]
+fn after_two_dashes() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["positionals", "--", "--foo", "-v"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.input, "--foo");
+    assert_eq!(config.output, Some("-v".to_owned()));
+    assert_eq!(config.foo, None);
+    assert!(!config.verbose);
+}
+
+#[test]
+fn missing_positional() {
+    let result = config::Config::custom_args_and_optional_files(&["positionals", "--foo", "42"], iter::empty::<PathBuf>());
+    match result {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => assert_eq!(err.to_string(), "Invalid configuration: Positional argument 'INPUT' not specified."),
+    }
+}
+
+#[test]
+fn parse_fail() {
+    let result = config::Config::custom_args_and_optional_files(&["positionals", "in", "out", "one"], iter::empty::<PathBuf>());
+    match result {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => assert_eq!(err.to_string(), "Failed to parse positional argument 'NUMBERS': invalid digit found in string.\n\nHint: the value must be a non-negative integer up to 4294967295."),
+    }
+}
+
+#[test]
+fn print_help() {
+    let result = config::Config::custom_args_and_optional_files(&["positionals", "--help"], iter::empty::<PathBuf>());
+    match result {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => assert_eq!(err.to_string(), "Usage: positionals [--foo FOO] [-v|--verbose] INPUT [OUTPUT] [NUMBERS...]\n\nPositional arguments:\n        INPUT    The input"),
+    }
+}
@@ -0,0 +1,62 @@
+macro_rules! test_name { () => { "print_config" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+
+fn error(args: &[&str]) -> config::Error {
+    match config::Config::custom_args_and_optional_files(args, iter::empty::<PathBuf>()) {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => err,
+    }
+}
+
+#[test]
+fn print_config() {
+    let err = error(&["print_config", "--print-config", "--bind-addr", "0.0.0.0", "--password", "hunter2", "--peer", "a", "--peer", "b", "--timeout", "90s", "--network-max-peers", "16"]);
+    match err {
+        config::Error::PrintConfig(config) => assert_eq!(config, "port = 8080\nbind-addr = \"0.0.0.0\"\npassword = \"[redacted]\"\ntimeout = \"1m30s\"\nmode = \"fast\"\npeer = [\"a\", \"b\"]\nverbose = false\n\n[network]\nmax_peers = 16\n"),
+        err => panic!("Unexpected error: {}", err),
+    }
+}
+
+#[test]
+fn print_config_skips_missing_values() {
+    let err = error(&["print_config", "--print-config", "--verbose"]);
+    assert_eq!(err.to_string(), "port = 8080\ntimeout = \"30s\"\nmode = \"fast\"\npeer = []\nverbose = true\n\n[network]\nmax_peers = 8");
+}
+
+#[test]
+fn print_invalid_config() {
+    let err = error(&["print_config", "--print-config", "--port", "0"]);
+    assert_eq!(err.to_string(), "Invalid configuration: Value of configuration parameter 'port' from argument --port must be at least 1.");
+}
+
+#[test]
+fn check_config() {
+    let err = error(&["print_config", "--check-config"]);
+    assert!(matches!(err, config::Error::ConfigValid));
+    assert_eq!(err.to_string(), "The configuration is valid.");
+
+    let err = error(&["print_config", "--check-config", "--port", "0"]);
+    assert!(matches!(err, config::Error::Validation(_)));
+}
+
+#[test]
+fn suggest_general_switch() {
+    let err = error(&["print_config", "--print-confg"]);
+    assert_eq!(err.to_string(), "An unknown argument '--print-confg' was specified, did you mean '--print-config'?");
+
+    let err = error(&["print_config", "--pasword-file", "secret"]);
+    assert_eq!(err.to_string(), "An unknown argument '--pasword-file' was specified, did you mean '--password-file'?");
+}
+
+#[test]
+fn serialize() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["print_config", "--mode", "slow"], iter::empty::<PathBuf>()).unwrap();
+    let serialized = configure_me::toml::to_string(&config.network).unwrap();
+    assert_eq!(serialized, "max_peers = 8\n");
+    let serialized = configure_me::toml::to_string(&config).unwrap();
+    assert!(serialized.contains("mode = \"slow\"\n"));
+}
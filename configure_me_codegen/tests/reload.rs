@@ -0,0 +1,40 @@
+macro_rules! test_name { () => { "reload" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+
+fn load() -> config::Config {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["reload", "--verbose"], iter::empty::<PathBuf>()).unwrap();
+    config
+}
+
+#[test]
+fn unchanged() {
+    let config = load();
+    let (config, changed, _metadata) = config.reload_custom_args(&["reload", "--verbose"], iter::empty::<PathBuf>()).unwrap();
+    assert!(changed.is_empty());
+    assert!(config.verbose);
+}
+
+#[test]
+fn changed() {
+    let config = load();
+    let config_files = [concat!(env!("CARGO_MANIFEST_DIR"), "/tests/config_files/reload.toml")];
+    let (config, changed, _metadata) = config.reload_custom_args(&["reload", "--verbose"], &config_files).unwrap();
+    assert_eq!(changed, ["log_level", "limits.max_peers"]);
+    assert_eq!(config.log_level, "debug");
+    assert_eq!(config.limits.max_peers, 16);
+}
+
+#[test]
+fn not_reloadable() {
+    let config = load();
+    let config_files = [concat!(env!("CARGO_MANIFEST_DIR"), "/tests/config_files/reload_port.toml")];
+    match config.reload_custom_args(&["reload", "--verbose"], &config_files) {
+        Err(err @ config::Error::NotReloadable("port")) => assert_eq!(err.to_string(), "Configuration parameter 'port' can't be changed without restarting."),
+        Err(err) => panic!("Unexpected error: {}", err),
+        Ok(_) => panic!("This shouldn't succeed"),
+    }
+}
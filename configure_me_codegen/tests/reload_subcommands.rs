@@ -0,0 +1,39 @@
+macro_rules! test_name { () => { "reload_subcommands" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+
+fn load() -> config::Config {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["reload_subcommands", "serve", "--workers", "4"], iter::empty::<PathBuf>()).unwrap();
+    config
+}
+
+fn reload_error(config: &config::Config, args: &[&str]) -> config::Error {
+    match config.reload_custom_args(args, iter::empty::<PathBuf>()) {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => err,
+    }
+}
+
+#[test]
+fn changed() {
+    let config = load();
+    let (config, changed, _metadata) = config.reload_custom_args(&["reload_subcommands", "serve", "--workers", "8"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(changed, ["serve.workers"]);
+    match config.subcommand {
+        config::Subcommand::Serve(serve) => assert_eq!(serve.workers, 8),
+        config::Subcommand::Check(_) => panic!("Wrong subcommand"),
+    }
+}
+
+#[test]
+fn not_reloadable() {
+    let config = load();
+    let err = reload_error(&config, &["reload_subcommands", "serve", "--workers", "4", "--port", "9000"]);
+    assert!(matches!(err, config::Error::NotReloadable("serve.port")));
+
+    let err = reload_error(&config, &["reload_subcommands", "check"]);
+    assert!(matches!(err, config::Error::NotReloadable("subcommand")));
+}
@@ -0,0 +1,64 @@
+macro_rules! test_name { () => { "secret" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+
+fn error(args: &[&str]) -> String {
+    match config::Config::custom_args_and_optional_files(args, iter::empty::<PathBuf>()) {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => err.to_string(),
+    }
+}
+
+#[test]
+fn args() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["secret", "--password", "hunter2", "--pin", "42"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.user, "admin");
+    assert_eq!(config.password.as_ref().map(|password| password.as_str()), Some("hunter2"));
+    assert_eq!(*config.pin, 42);
+}
+
+#[test]
+fn debug_redacted() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["secret", "--password", "hunter2"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(format!("{:?}", config.password), "Some([redacted])");
+    assert_eq!(format!("{:?}", config.pin), "[redacted]");
+}
+
+#[test]
+fn file_arg() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["secret", "--password-file", "tests/config_files/secret_password.txt"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.password.map(|password| password.into_inner()), Some("hunter2".to_owned()));
+}
+
+#[test]
+fn file_env() {
+    std::env::set_var("TEST_SECRET_API_KEY_FILE", "tests/config_files/secret_api_keys.txt");
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["secret"], iter::empty::<PathBuf>()).unwrap();
+    let api_key = config.api_key.into_iter().map(|key| key.into_inner()).collect::<Vec<_>>();
+    assert_eq!(api_key, ["key1", "key2"]);
+}
+
+#[test]
+fn value_not_in_errors() {
+    let err = error(&["secret", "--pin", "hunter2"]);
+    assert!(!err.contains("hunter2"));
+    assert!(err.starts_with("Failed to parse argument '--pin'.\n\nHint: the value must be "));
+    let err = error(&["secret", "--pin-file", "tests/config_files/secret_invalid_pin.txt"]);
+    assert!(!err.contains("not a number"));
+}
+
+#[test]
+fn missing_file() {
+    let err = error(&["secret", "--password-file", "tests/config_files/nonexistent.txt"]);
+    assert!(err.starts_with("Failed to read secret file tests/config_files/nonexistent.txt: "));
+}
+
+#[test]
+fn help() {
+    let help = error(&["secret", "--help"]);
+    assert!(help.contains("Password used to authenticate (sensitive, can be read from\n                      a file using --password-file)"));
+    assert!(help.contains("--pin         Sensitive, can be read from a file using --pin-file"));
+}
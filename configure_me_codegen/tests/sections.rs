@@ -0,0 +1,90 @@
+macro_rules! test_name { () => { "sections" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+
+#[test]
+fn args() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["sections", "--name", "node", "--network-bind-addr", "0.0.0.0", "--network-port", "1234", "--network-ipv6", "--storage-mode", "memory"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.name, Some("node".to_owned()));
+    assert_eq!(config.network.bind_addr, "0.0.0.0");
+    assert_eq!(config.network.port, 1234);
+    assert!(config.network.ipv6);
+    assert_eq!(config.storage.mode, config::StorageMode::Memory);
+}
+
+#[test]
+fn short_and_defaults() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["sections", "-b", "::1", "--network-port", "8080"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.network.bind_addr, "::1");
+    assert!(!config.network.ipv6);
+    assert_eq!(config.storage.mode, config::StorageMode::Disk);
+}
+
+#[test]
+fn config_file() {
+    let config_files = [concat!(env!("CARGO_MANIFEST_DIR"), "/tests/config_files/network.toml")];
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["sections", "--network-ipv6"], &config_files).unwrap();
+    assert_eq!(config.name, Some("node".to_owned()));
+    assert_eq!(config.network.bind_addr, "127.0.0.1");
+    assert_eq!(config.network.port, 9000);
+    assert!(config.network.ipv6);
+    assert_eq!(config.storage.mode, config::StorageMode::Memory);
+}
+
+#[test]
+fn args_override_config_file() {
+    let config_files = [concat!(env!("CARGO_MANIFEST_DIR"), "/tests/config_files/network.toml")];
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["sections", "--network-port", "9001"], &config_files).unwrap();
+    assert_eq!(config.network.bind_addr, "127.0.0.1");
+    assert_eq!(config.network.port, 9001);
+}
+
+#[test]
+fn sources() {
+    let config_files = [concat!(env!("CARGO_MANIFEST_DIR"), "/tests/config_files/network.toml")];
+    let (_config, _remaining, metadata) = config::Config::custom_args_and_optional_files(&["sections", "--network-port", "9001", "--network-ipv6"], &config_files).unwrap();
+    let sources = metadata.sources;
+    assert!(matches!(sources.name, Some(config::Source::ConfigFile(ref file)) if file == std::path::Path::new(config_files[0])));
+    assert!(matches!(sources.network_port, config::Source::Argument("--network-port")));
+    assert!(matches!(sources.network_ipv6, config::Source::Argument("--network-ipv6")));
+    assert!(matches!(sources.storage_mode, config::Source::ConfigFile(_)));
+
+    let (_config, _remaining, metadata) = config::Config::custom_args_and_optional_files(&["sections", "-b", "::1"], iter::empty::<PathBuf>()).unwrap();
+    assert!(matches!(metadata.sources.network_bind_addr, config::Source::Argument("-b")));
+    assert!(matches!(metadata.sources.network_port, config::Source::Default));
+    assert_eq!(metadata.sources.network_port.to_string(), "the default value");
+    // name is optional without a default value
+    assert!(metadata.sources.name.is_none());
+}
+
+// Other tests always pass the bind address on the command line or in a config file and don't check
+// where it came from so the env var doesn't affect them.
+#[test]
+fn env_var() {
+    std::env::set_var("TEST_SECTIONS_NETWORK_BIND_ADDR", "10.0.0.1");
+    let result = config::Config::custom_args_and_optional_files(&["sections"], iter::empty::<PathBuf>());
+    std::env::remove_var("TEST_SECTIONS_NETWORK_BIND_ADDR");
+    let (config, _remaining, _metadata) = result.unwrap();
+    assert_eq!(config.network.bind_addr, "10.0.0.1");
+}
+
+#[test]
+fn invalid_arg() {
+    let result = config::Config::custom_args_and_optional_files(&["sections", "-b", "::1", "--network-port", "foo"], iter::empty::<PathBuf>());
+    match result {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => assert!(err.to_string().starts_with("Failed to parse argument '--network-port'")),
+    }
+}
+
+#[test]
+fn print_help() {
+    let result = config::Config::custom_args_and_optional_files(&["sections", "--help"], iter::empty::<PathBuf>());
+    match result {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => assert_eq!(err.to_string(), "Usage: sections [ARGUMENTS...]\n\nArguments:\n        --name                     Name of the node\n\nArguments in section network:\n        -b, --network-bind-addr    Address to bind to\n        --network-port\n\n        --network-ipv6             Use IPv6\n\nArguments in section storage:\n        --storage-mode             Possible values: memory, disk"),
+    }
+}
@@ -0,0 +1,101 @@
+macro_rules! test_name { () => { "subcommands" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+
+#[test]
+fn subcommand_args() {
+    let (config, mut remaining, _metadata) = config::Config::custom_args_and_optional_files(&["subcommands", "-v", "--foo", "1", "run", "-fb", "bar", "remaining_arg"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.foo, Some(1));
+    assert!(config.verbose);
+    match config.subcommand {
+        config::Subcommand::Run(run) => {
+            assert_eq!(run.bar, "bar");
+            assert!(run.fast);
+        },
+        config::Subcommand::RunAll(_) => panic!("Wrong subcommand"),
+    }
+    assert_eq!(remaining.next(), Some("remaining_arg".into()));
+    assert_eq!(remaining.next(), None);
+}
+
+#[test]
+fn subcommand_default() {
+    let (config, mut remaining, _metadata) = config::Config::custom_args_and_optional_files(&["subcommands", "run-all", "--", "--foo"], iter::empty::<PathBuf>()).unwrap();
+    match config.subcommand {
+        config::Subcommand::RunAll(run_all) => assert_eq!(run_all.foo, 42),
+        config::Subcommand::Run(_) => panic!("Wrong subcommand"),
+    }
+    assert_eq!(remaining.next(), Some("--foo".into()));
+    assert_eq!(remaining.next(), None);
+}
+
+#[test]
+fn sources() {
+    let (_config, _remaining, metadata) = config::Config::custom_args_and_optional_files(&["subcommands", "-v", "run", "--bar", "bar"], iter::empty::<PathBuf>()).unwrap();
+    assert!(metadata.sources.foo.is_none());
+    assert!(matches!(metadata.sources.verbose, config::Source::Argument("-v")));
+    match metadata.sources.subcommand {
+        config::SubcommandSources::Run(run) => {
+            assert!(matches!(run.bar, config::Source::Argument("--bar")));
+            assert!(matches!(run.fast, config::Source::Default));
+        },
+        config::SubcommandSources::RunAll(_) => panic!("Wrong subcommand"),
+    }
+}
+
+#[test]
+fn missing_subcommand() {
+    let result = config::Config::custom_args_and_optional_files(&["subcommands", "--foo", "1"], iter::empty::<PathBuf>());
+    match result {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => assert_eq!(err.to_string(), "Invalid configuration: Missing subcommand."),
+    }
+}
+
+#[test]
+fn unknown_subcommand() {
+    let result = config::Config::custom_args_and_optional_files(&["subcommands", "walk"], iter::empty::<PathBuf>());
+    match result {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => assert_eq!(err.to_string(), "An unknown subcommand 'walk' was specified."),
+    }
+}
+
+#[test]
+fn missing_subcommand_param() {
+    let result = config::Config::custom_args_and_optional_files(&["subcommands", "run"], iter::empty::<PathBuf>());
+    match result {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => assert_eq!(err.to_string(), "Invalid configuration: Configuration parameter 'bar' not specified."),
+    }
+}
+
+#[test]
+fn unknown_subcommand_arg() {
+    let result = config::Config::custom_args_and_optional_files(&["subcommands", "run", "--verbose"], iter::empty::<PathBuf>());
+    match result {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => assert_eq!(err.to_string(), "An unknown argument '--verbose' was specified."),
+    }
+}
+
+#[test]
+fn print_help() {
+    let result = config::Config::custom_args_and_optional_files(&["subcommands", "--help"], iter::empty::<PathBuf>());
+    match result {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => assert_eq!(err.to_string(), "Usage: subcommands [--foo FOO] [-v|--verbose] SUBCOMMAND\n\nArguments:\n        --foo    A foo\n\nSubcommands:\n        run        Runs the program\n        run-all"),
+    }
+}
+
+#[test]
+fn print_subcommand_help() {
+    let result = config::Config::custom_args_and_optional_files(&["subcommands", "run", "-h"], iter::empty::<PathBuf>());
+    match result {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => assert_eq!(err.to_string(), "Usage: subcommands run [-b BAR|--bar BAR] [-f|--fast]\n\nArguments:\n        -b, --bar    A bar"),
+    }
+}
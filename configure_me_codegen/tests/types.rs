@@ -0,0 +1,52 @@
+macro_rules! test_name { () => { "types" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+use std::time::Duration;
+
+fn error(args: &[&str]) -> String {
+    match config::Config::custom_args_and_optional_files(args, iter::empty::<PathBuf>()) {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => err.to_string(),
+    }
+}
+
+#[test]
+fn args() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["types", "--timeout", "5m", "--cache-size", "64MiB"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(*config.timeout, Duration::from_secs(300));
+    assert_eq!(config.cache_size.map(|size| size.bytes()), Some(64 * 1024 * 1024));
+}
+
+#[test]
+fn default() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["types"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(*config.timeout, Duration::from_secs(30));
+    assert!(config.cache_size.is_none());
+}
+
+#[test]
+fn config_file() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["types"], iter::once("tests/config_files/types.toml")).unwrap();
+    assert_eq!(*config.timeout, Duration::from_secs(90 * 60));
+    assert_eq!(config.cache_size.map(|size| size.to_string()), Some("1MiB".to_owned()));
+}
+
+#[test]
+fn invalid_config_file() {
+    assert!(config::Config::custom_args_and_optional_files(&["types"], iter::once("tests/config_files/invalid_timeout.toml")).is_err());
+}
+
+#[test]
+fn invalid_arg() {
+    let err = error(&["types", "--timeout", "5y"]);
+    assert!(err.starts_with("Failed to parse argument '--timeout': unknown unit 'y'.\n\nHint: the value must be a duration such as 30s"));
+}
+
+#[test]
+fn help() {
+    let err = error(&["types", "--help"]);
+    assert!(err.contains("ms, s, m, h or d, e.g. 1h30m"));
+}
@@ -0,0 +1,50 @@
+macro_rules! test_name { () => { "unknown_keys" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+
+fn error(file: &str) -> String {
+    match config::Config::custom_args_and_optional_files(&["unknown_keys"], iter::once(file)) {
+        Ok(_) => panic!("the unknown key was accepted"),
+        Err(error) => error.to_string(),
+    }
+}
+
+#[test]
+fn known_keys() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["unknown_keys"], iter::once("tests/config_files/unknown_keys.toml")).unwrap();
+    assert_eq!(config.bind_addr.as_ref().map(String::as_str), Some("127.0.0.1"));
+    assert_eq!(config.max_peers, Some(8));
+    assert!(config.verbose);
+    assert_eq!(config.network.port, Some(8333));
+}
+
+#[test]
+fn unknown_key_with_suggestion() {
+    assert_eq!(error("tests/config_files/unknown_keys_typo.toml"), "Unknown key 'bind_adr' in configuration file tests/config_files/unknown_keys_typo.toml, did you mean 'bind_addr'?");
+}
+
+#[test]
+fn unknown_key_in_section() {
+    assert_eq!(error("tests/config_files/unknown_keys_section.toml"), "Unknown key 'network.prot' in configuration file tests/config_files/unknown_keys_section.toml, did you mean 'network.port'?");
+}
+
+#[test]
+fn unknown_key_without_suggestion() {
+    assert_eq!(error("tests/config_files/unknown_keys_other.toml"), "Unknown key 'color' in configuration file tests/config_files/unknown_keys_other.toml.");
+}
+
+#[test]
+fn unknown_argument() {
+    let error = match config::Config::custom_args_and_optional_files(&["unknown_keys", "--bind-adr=127.0.0.1"], iter::empty::<&str>()) {
+        Ok(_) => panic!("the unknown argument was accepted"),
+        Err(error) => error.to_string(),
+    };
+    assert_eq!(error, "An unknown argument '--bind-adr=127.0.0.1' was specified, did you mean '--bind-addr'?");
+    let error = match config::Config::custom_args_and_optional_files(&["unknown_keys", "--color"], iter::empty::<&str>()) {
+        Ok(_) => panic!("the unknown argument was accepted"),
+        Err(error) => error.to_string(),
+    };
+    assert_eq!(error, "An unknown argument '--color' was specified.");
+}
@@ -0,0 +1,17 @@
+macro_rules! test_name { () => { "unknown_keys_warn" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+
+#[test]
+fn unknown_key_warning() {
+    let (config, _remaining, metadata) = config::Config::custom_args_and_optional_files(&["unknown_keys_warn"], iter::once("tests/config_files/unknown_keys.toml")).unwrap();
+    assert_eq!(config.bind_addr.as_ref().map(String::as_str), Some("127.0.0.1"));
+    let warnings = metadata.warnings.iter().map(ToString::to_string).collect::<Vec<_>>();
+    assert_eq!(warnings, [
+        "Unknown key 'maxpeers' in configuration file tests/config_files/unknown_keys.toml.",
+        "Unknown key 'verbose' in configuration file tests/config_files/unknown_keys.toml.",
+        "Unknown key 'network' in configuration file tests/config_files/unknown_keys.toml.",
+    ]);
+}
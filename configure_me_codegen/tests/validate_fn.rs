@@ -0,0 +1,79 @@
+macro_rules! test_name { () => { "validate_fn" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+
+fn check_port(port: &u16) -> Result<(), String> {
+    if *port == 0 {
+        Err("the port must not be zero".to_owned())
+    } else {
+        Ok(())
+    }
+}
+
+fn check_name(name: &str) -> Result<(), &'static str> {
+    if name.contains(' ') {
+        Err("spaces are not allowed")
+    } else {
+        Ok(())
+    }
+}
+
+fn check_config(config: &config::Config) -> Result<(), String> {
+    if config.port == 443 && !config.tls {
+        Err(format!("Port {} requires TLS to be enabled.", config.port))
+    } else {
+        Ok(())
+    }
+}
+
+fn validation_error(args: &[&str]) -> String {
+    match config::Config::custom_args_and_optional_files(args, iter::empty::<PathBuf>()) {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => err.to_string(),
+    }
+}
+
+#[test]
+fn valid() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["validate_fn", "--port", "443", "--tls", "--name", "satoshi", "--peer", "a", "--peer", "b"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.port, 443);
+    assert_eq!(config.name.as_ref().map(AsRef::as_ref), Some("satoshi"));
+    assert_eq!(config.peer, ["a", "b"]);
+    assert!(config.tls);
+}
+
+#[test]
+fn param() {
+    let err = validation_error(&["validate_fn", "--port", "0"]);
+    assert_eq!(err, "Invalid configuration: Value of configuration parameter 'port' from argument --port is invalid: the port must not be zero");
+    let err = validation_error(&["validate_fn", "--name", "Satoshi Nakamoto"]);
+    assert_eq!(err, "Invalid configuration: Value of configuration parameter 'name' from argument --name is invalid: spaces are not allowed");
+}
+
+#[test]
+fn multiple() {
+    let err = validation_error(&["validate_fn", "--peer", "a", "--peer", "b c"]);
+    assert_eq!(err, "Invalid configuration: Value of configuration parameter 'peer' from argument --peer is invalid: spaces are not allowed");
+}
+
+#[test]
+fn whole_config() {
+    let err = validation_error(&["validate_fn", "--port", "443"]);
+    assert_eq!(err, "Invalid configuration: Port 443 requires TLS to be enabled.");
+}
+
+#[test]
+fn error_variant() {
+    match config::Config::custom_args_and_optional_files(&["validate_fn", "--port", "0"], iter::empty::<PathBuf>()) {
+        Err(config::Error::Validation(config::ValidationError::Custom { field, source, message })) => {
+            assert_eq!(field, Some("port"));
+            assert!(matches!(source, Some(config::Source::Argument("--port"))));
+            assert_eq!(message, "the port must not be zero");
+        },
+        Err(err) => panic!("Unexpected error: {}", err),
+        Ok(_) => panic!("This shouldn't succeed"),
+    }
+}
@@ -0,0 +1,72 @@
+macro_rules! test_name { () => { "value_constraints" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+
+fn validation_error(args: &[&str]) -> String {
+    match config::Config::custom_args_and_optional_files(args, iter::empty::<PathBuf>()) {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => err.to_string(),
+    }
+}
+
+#[test]
+fn valid() {
+    let (config, _remaining, _metadata) = config::Config::custom_args_and_optional_files(&["value_constraints", "--user", "satoshi", "--port", "1024", "--ratio", "0.5", "-t", "a", "--tag", "abcdefgh"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.port, 1024);
+    assert_eq!(config.ratio, Some(0.5));
+    assert_eq!(config.user, "satoshi");
+    assert_eq!(config.tag, ["a", "abcdefgh"]);
+}
+
+#[test]
+fn min_max() {
+    let err = validation_error(&["value_constraints", "--user", "satoshi", "--port", "80"]);
+    assert_eq!(err, "Invalid configuration: Value of configuration parameter 'port' from argument --port must be at least 1024.");
+    let err = validation_error(&["value_constraints", "--user", "satoshi", "--ratio", "1.5"]);
+    assert_eq!(err, "Invalid configuration: Value of configuration parameter 'ratio' from argument --ratio must be at most 1.");
+}
+
+#[test]
+fn length() {
+    let err = validation_error(&["value_constraints", "--user", "ab"]);
+    assert_eq!(err, "Invalid configuration: Value of configuration parameter 'user' from argument --user must be at least 3 characters long.");
+    let err = validation_error(&["value_constraints", "--user", "satoshi", "--tag", "short", "-t", "too_long_tag"]);
+    assert_eq!(err, "Invalid configuration: Value of configuration parameter 'tag' from argument -t must be at most 8 characters long.");
+}
+
+#[test]
+fn pattern() {
+    let err = validation_error(&["value_constraints", "--user", "Satoshi"]);
+    assert_eq!(err, "Invalid configuration: Value of configuration parameter 'user' from argument --user must match the pattern `^[a-z]+$`.");
+}
+
+#[test]
+fn config_file_source() {
+    let config_files = [concat!(env!("CARGO_MANIFEST_DIR"), "/tests/config_files/low_port.toml")];
+    let result = config::Config::custom_args_and_optional_files(&["value_constraints", "--user", "satoshi"], &config_files);
+    match result {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => assert_eq!(err.to_string(), format!("Invalid configuration: Value of configuration parameter 'port' from config file {} must be at least 1024.", config_files[0])),
+    }
+}
+
+// Other tests always pass the user on the command line so the env var doesn't affect them.
+#[test]
+fn env_var_source() {
+    std::env::set_var("TEST_VALUE_CONSTRAINTS_USER", "x");
+    let result = config::Config::custom_args_and_optional_files(&["value_constraints"], iter::empty::<PathBuf>());
+    std::env::remove_var("TEST_VALUE_CONSTRAINTS_USER");
+    match result {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => assert_eq!(err.to_string(), "Invalid configuration: Value of configuration parameter 'user' from env var TEST_VALUE_CONSTRAINTS_USER must be at least 3 characters long."),
+    }
+}
+
+#[test]
+fn print_help() {
+    let err = validation_error(&["value_constraints", "--help"]);
+    assert_eq!(err, "Usage: value_constraints [--port PORT] [--ratio RATIO] [--user USER] [-t TAG|--tag TAG]...\n\nArguments:\n        --port       Port to listen on (must be at least 1024)\n        --ratio      Must be at least 0, at most 1\n        --user       Must be at least 3 characters long, at most 16 characters \n                     long, matching `^[a-z]+$`\n        -t, --tag    Must be at most 8 characters long");
+}